@@ -0,0 +1,344 @@
+use anyhow::anyhow;
+use fuel_core_interfaces::{
+    block_importer::{
+        BlockImporterDb,
+        ImportBlockBroadcast,
+    },
+    common::fuel_tx::Bytes32,
+    executor::{
+        ExecutionMode,
+        Executor,
+    },
+    model::{
+        FuelBlock,
+        FuelBlockConsensus,
+        SealedFuelBlock,
+    },
+};
+use std::sync::Arc;
+use tokio::sync::broadcast;
+
+pub struct Importer {
+    db: Arc<dyn BlockImporterDb>,
+    executor: Arc<dyn Executor>,
+    broadcast: broadcast::Sender<ImportBlockBroadcast>,
+    /// Block created by this node that is validated but waits to be sealed.
+    pending: Option<Arc<FuelBlock>>,
+}
+
+impl Importer {
+    pub fn new(
+        db: Arc<dyn BlockImporterDb>,
+        executor: Arc<dyn Executor>,
+        broadcast: broadcast::Sender<ImportBlockBroadcast>,
+    ) -> Self {
+        Self {
+            db,
+            executor,
+            broadcast,
+            pending: None,
+        }
+    }
+
+    /// Validate and commit a block that was sealed by another node.
+    pub async fn import_sealed_block(
+        &mut self,
+        block: Arc<SealedFuelBlock>,
+    ) -> anyhow::Result<()> {
+        self.commit(block, false).await
+    }
+
+    /// Validate a block created by this node without committing it. The block stays
+    /// pending until it is sealed with `seal_block`.
+    pub async fn import_block(&mut self, block: Arc<FuelBlock>) -> anyhow::Result<()> {
+        self.validate_block(&block).await?;
+
+        self.pending = Some(block.clone());
+        let _ = self
+            .broadcast
+            .send(ImportBlockBroadcast::PendingFuelBlockImported { block });
+        Ok(())
+    }
+
+    /// Check that the block executes on top of the chain tip without committing it.
+    pub async fn validate_block(&self, block: &FuelBlock) -> anyhow::Result<()> {
+        self.check_block_connects_to_tip(block)?;
+        let mut validated = block.clone();
+        self.executor
+            .dry_run(&mut validated, ExecutionMode::Validation)
+            .await
+    }
+
+    /// Commit the pending block with the given id.
+    pub async fn seal_block(
+        &mut self,
+        consensus: FuelBlockConsensus,
+        block_id: Bytes32,
+    ) -> anyhow::Result<()> {
+        let block = match self.pending.take() {
+            Some(block) if block.id() == block_id => block,
+            pending => {
+                self.pending = pending;
+                return Err(anyhow!("There is no pending block with id {}", block_id))
+            }
+        };
+        let sealed = SealedFuelBlock {
+            block: block.as_ref().clone(),
+            consensus,
+        };
+        self.commit(Arc::new(sealed), true).await
+    }
+
+    async fn commit(
+        &mut self,
+        block: Arc<SealedFuelBlock>,
+        is_created_by_self: bool,
+    ) -> anyhow::Result<()> {
+        self.check_block_connects_to_tip(&block)?;
+        // executor commits all changes of the block in one database transaction
+        let mut validated = block.block.clone();
+        self.executor
+            .execute(&mut validated, ExecutionMode::Validation)
+            .await?;
+
+        // any pending block is now built on top of an outdated tip
+        self.pending = None;
+        let _ = self
+            .broadcast
+            .send(ImportBlockBroadcast::SealedFuelBlockImported {
+                block,
+                is_created_by_self,
+            });
+        Ok(())
+    }
+
+    fn check_block_connects_to_tip(&self, block: &FuelBlock) -> anyhow::Result<()> {
+        let current_height = self.db.current_height()?;
+        if block.header.height != current_height + 1u32.into() {
+            return Err(anyhow!(
+                "Block at height {} can't be imported on top of height {}",
+                block.header.height,
+                current_height
+            ))
+        }
+        let tip_id = self.db.block_id(current_height)?.unwrap_or_default();
+        if block.header.parent_hash != tip_id {
+            return Err(anyhow!(
+                "Block parent {} doesn't match current tip {}",
+                block.header.parent_hash,
+                tip_id
+            ))
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use async_trait::async_trait;
+    use fuel_core_interfaces::{
+        db::ChainDb,
+        model::{
+            BlockHeight,
+            DaBlockHeight,
+            FuelBlockHeader,
+        },
+    };
+    use parking_lot::Mutex;
+
+    #[derive(Default)]
+    struct MockDb {
+        block_ids: Mutex<Vec<Bytes32>>,
+    }
+
+    impl ChainDb for MockDb {
+        fn current_height(&self) -> anyhow::Result<BlockHeight> {
+            Ok(self.block_ids.lock().len().into())
+        }
+
+        fn block_id(&self, height: BlockHeight) -> anyhow::Result<Option<Bytes32>> {
+            Ok(self
+                .block_ids
+                .lock()
+                .get(height.as_usize().wrapping_sub(1))
+                .copied())
+        }
+
+        fn finalized_da_height(&self) -> anyhow::Result<DaBlockHeight> {
+            Ok(0)
+        }
+    }
+
+    impl BlockImporterDb for MockDb {}
+
+    /// Commits blocks by appending their ids to the database.
+    struct MockExecutor {
+        db: Arc<MockDb>,
+    }
+
+    #[async_trait]
+    impl Executor for MockExecutor {
+        async fn execute(
+            &self,
+            block: &mut FuelBlock,
+            _mode: ExecutionMode,
+        ) -> anyhow::Result<()> {
+            self.db.block_ids.lock().push(block.id());
+            Ok(())
+        }
+
+        async fn dry_run(
+            &self,
+            _block: &mut FuelBlock,
+            _mode: ExecutionMode,
+        ) -> anyhow::Result<()> {
+            Ok(())
+        }
+    }
+
+    fn importer() -> (
+        Importer,
+        Arc<MockDb>,
+        broadcast::Receiver<ImportBlockBroadcast>,
+    ) {
+        let db = Arc::new(MockDb::default());
+        let (broadcast, receiver) = broadcast::channel(10);
+        let importer = Importer::new(
+            db.clone(),
+            Arc::new(MockExecutor { db: db.clone() }),
+            broadcast,
+        );
+        (importer, db, receiver)
+    }
+
+    fn block(height: u32, parent_hash: Bytes32) -> FuelBlock {
+        FuelBlock {
+            header: FuelBlockHeader {
+                height: height.into(),
+                parent_hash,
+                ..Default::default()
+            },
+            transactions: vec![],
+        }
+    }
+
+    fn sealed(block: FuelBlock) -> Arc<SealedFuelBlock> {
+        Arc::new(SealedFuelBlock {
+            block,
+            consensus: Default::default(),
+        })
+    }
+
+    #[tokio::test]
+    async fn imports_sealed_blocks_on_top_of_tip() {
+        let (mut importer, db, mut events) = importer();
+        let block1 = block(1, Bytes32::zeroed());
+        let block2 = block(2, block1.id());
+
+        importer.import_sealed_block(sealed(block1)).await.unwrap();
+        importer
+            .import_sealed_block(sealed(block2.clone()))
+            .await
+            .unwrap();
+
+        assert_eq!(db.current_height().unwrap(), 2u32.into());
+        events.recv().await.unwrap();
+        match events.recv().await.unwrap() {
+            ImportBlockBroadcast::SealedFuelBlockImported {
+                block,
+                is_created_by_self,
+            } => {
+                assert_eq!(block.id(), block2.id());
+                assert!(!is_created_by_self);
+            }
+            event => panic!("Unexpected event {:?}", event),
+        }
+    }
+
+    #[tokio::test]
+    async fn rejects_block_at_wrong_height() {
+        let (mut importer, db, _events) = importer();
+
+        let err = importer
+            .import_sealed_block(sealed(block(2, Bytes32::zeroed())))
+            .await;
+
+        assert!(err.is_err());
+        assert_eq!(db.current_height().unwrap(), 0u32.into());
+    }
+
+    #[tokio::test]
+    async fn rejects_block_with_wrong_parent() {
+        let (mut importer, db, _events) = importer();
+        let block1 = block(1, Bytes32::zeroed());
+        importer.import_sealed_block(sealed(block1)).await.unwrap();
+
+        let err = importer
+            .import_sealed_block(sealed(block(2, [1u8; 32].into())))
+            .await;
+
+        assert!(err.is_err());
+        assert_eq!(db.current_height().unwrap(), 1u32.into());
+    }
+
+    #[tokio::test]
+    async fn commits_own_block_once_sealed() {
+        let (mut importer, db, mut events) = importer();
+        let block1 = block(1, Bytes32::zeroed());
+
+        importer
+            .import_block(Arc::new(block1.clone()))
+            .await
+            .unwrap();
+        assert_eq!(db.current_height().unwrap(), 0u32.into());
+        assert!(matches!(
+            events.recv().await.unwrap(),
+            ImportBlockBroadcast::PendingFuelBlockImported { .. }
+        ));
+
+        importer
+            .seal_block(Default::default(), block1.id())
+            .await
+            .unwrap();
+        assert_eq!(db.current_height().unwrap(), 1u32.into());
+        assert!(matches!(
+            events.recv().await.unwrap(),
+            ImportBlockBroadcast::SealedFuelBlockImported {
+                is_created_by_self: true,
+                ..
+            }
+        ));
+    }
+
+    #[tokio::test]
+    async fn fails_to_seal_unknown_block() {
+        let (mut importer, db, _events) = importer();
+        let block1 = block(1, Bytes32::zeroed());
+        importer.import_block(Arc::new(block1)).await.unwrap();
+
+        assert!(importer
+            .seal_block(Default::default(), [1u8; 32].into())
+            .await
+            .is_err());
+        assert_eq!(db.current_height().unwrap(), 0u32.into());
+    }
+
+    #[tokio::test]
+    async fn validates_proposed_block_without_keeping_it() {
+        let (mut importer, db, _events) = importer();
+        let block1 = block(1, Bytes32::zeroed());
+
+        importer.validate_block(&block1).await.unwrap();
+        assert!(importer
+            .validate_block(&block(1, [1u8; 32].into()))
+            .await
+            .is_err());
+
+        assert!(importer
+            .seal_block(Default::default(), block1.id())
+            .await
+            .is_err());
+        assert_eq!(db.current_height().unwrap(), 0u32.into());
+    }
+}
@@ -1,5 +1,7 @@
 pub mod config;
+pub mod importer;
 pub mod service;
 
 pub use config::Config;
+pub use importer::Importer;
 pub use service::Service;
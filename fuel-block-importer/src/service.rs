@@ -1,9 +1,17 @@
-use crate::Config;
-use fuel_core_interfaces::block_importer::{
-    ImportBlockBroadcast,
-    ImportBlockMpsc,
+use crate::{
+    Config,
+    Importer,
+};
+use fuel_core_interfaces::{
+    block_importer::{
+        BlockImporterDb,
+        ImportBlockBroadcast,
+        ImportBlockMpsc,
+    },
+    executor::Executor,
 };
 use parking_lot::Mutex;
+use std::sync::Arc;
 use tokio::{
     sync::{
         broadcast,
@@ -15,31 +23,66 @@ use tokio::{
 pub struct Service {
     join: Mutex<Option<JoinHandle<()>>>,
     sender: mpsc::Sender<ImportBlockMpsc>,
+    receiver: Mutex<Option<mpsc::Receiver<ImportBlockMpsc>>>,
     broadcast: broadcast::Sender<ImportBlockBroadcast>,
+    db: Arc<dyn BlockImporterDb>,
 }
 
 impl Service {
-    pub async fn new(_config: &Config, _db: ()) -> anyhow::Result<Self> {
-        let (sender, _receiver) = mpsc::channel(100);
+    pub async fn new(
+        _config: &Config,
+        db: Arc<dyn BlockImporterDb>,
+    ) -> anyhow::Result<Self> {
+        let (sender, receiver) = mpsc::channel(100);
         let (broadcast, _receiver) = broadcast::channel(100);
         Ok(Self {
             sender,
+            receiver: Mutex::new(Some(receiver)),
             broadcast,
+            db,
             join: Mutex::new(None),
         })
     }
 
-    pub async fn start(&self) {
+    pub async fn start(&self, executor: Arc<dyn Executor>) {
         let mut join = self.join.lock();
         if join.is_none() {
-            *join = Some(tokio::spawn(async {}));
+            if let Some(receiver) = self.receiver.lock().take() {
+                let importer =
+                    Importer::new(self.db.clone(), executor, self.broadcast.clone());
+                *join = Some(tokio::spawn(Self::run(receiver, importer)));
+            }
+        }
+    }
+
+    async fn run(mut receiver: mpsc::Receiver<ImportBlockMpsc>, mut importer: Importer) {
+        while let Some(event) = receiver.recv().await {
+            match event {
+                ImportBlockMpsc::ImportSealedFuelBlock { block, response } => {
+                    let _ = response.send(importer.import_sealed_block(block).await);
+                }
+                ImportBlockMpsc::ImportFuelBlock { block, response } => {
+                    let _ = response.send(importer.import_block(block).await);
+                }
+                ImportBlockMpsc::ValidateFuelBlock { block, response } => {
+                    let _ = response.send(importer.validate_block(&block).await);
+                }
+                ImportBlockMpsc::SealFuelBlock {
+                    consensus,
+                    block_id,
+                    response,
+                } => {
+                    let _ = response.send(importer.seal_block(consensus, block_id).await);
+                }
+                ImportBlockMpsc::Stop => break,
+            }
         }
     }
 
     pub async fn stop(&self) -> Option<JoinHandle<()>> {
         let join = self.join.lock().take();
         if join.is_some() {
-            let _ = self.sender.send(ImportBlockMpsc::Stop);
+            let _ = self.sender.send(ImportBlockMpsc::Stop).await;
         }
         join
     }
@@ -1,2 +1,24 @@
-#[derive(Default, Debug, Clone)]
-pub struct Config {}
+use fuel_core_interfaces::common::{
+    fuel_tx::ConsensusParameters,
+    fuel_types::Address,
+};
+
+#[derive(Debug, Clone)]
+pub struct Config {
+    /// Maximum sum of the gas limits of all transactions inside a block
+    pub max_gas_per_block: u64,
+    /// Maximum serialized size of all transactions inside a block, in bytes
+    pub max_block_size: usize,
+    /// Address of this node, set as the producer of the blocks it creates
+    pub producer: Address,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            max_gas_per_block: 10 * ConsensusParameters::DEFAULT.max_gas_per_tx,
+            max_block_size: 16 * 1024 * 1024,
+            producer: Address::default(),
+        }
+    }
+}
@@ -1,5 +1,7 @@
 pub mod config;
+pub mod producer;
 pub mod service;
 
 pub use config::Config;
+pub use producer::Producer;
 pub use service::Service;
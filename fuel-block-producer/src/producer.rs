@@ -0,0 +1,269 @@
+use crate::Config;
+use anyhow::anyhow;
+use chrono::Utc;
+use fuel_core_interfaces::{
+    block_producer::BlockProducerDb,
+    executor::{
+        ExecutionMode,
+        Executor,
+    },
+    model::{
+        BlockHeight,
+        FuelBlock,
+        FuelBlockHeader,
+    },
+    txpool,
+};
+use std::sync::Arc;
+
+pub struct Producer {
+    pub config: Config,
+    pub db: Arc<dyn BlockProducerDb>,
+    pub txpool: txpool::Sender,
+    pub executor: Arc<dyn Executor>,
+}
+
+impl Producer {
+    /// Produce a new block on top of the current chain tip. The block is executed
+    /// in production mode, which also sets its transactions and previous block
+    /// roots, but none of its state changes are committed.
+    pub async fn produce_block(&self, height: BlockHeight) -> anyhow::Result<FuelBlock> {
+        let current_height = self.db.current_height()?;
+        if height != current_height + 1u32.into() {
+            return Err(anyhow!(
+                "Block at height {} can't be produced on top of height {}",
+                height,
+                current_height
+            ))
+        }
+
+        let transactions: Vec<_> = self
+            .txpool
+            .includable(self.config.max_gas_per_block, self.config.max_block_size)
+            .await?
+            .into_iter()
+            .map(|tx| tx.as_ref().clone())
+            .collect();
+        let tx_ids = transactions.iter().map(|tx| tx.id()).collect::<Vec<_>>();
+
+        let mut block = FuelBlock {
+            header: self.new_header(height, current_height)?,
+            transactions,
+        };
+        self.executor
+            .dry_run(&mut block, ExecutionMode::Production)
+            .await?;
+
+        // invalid transactions are dropped by the executor, don't select them again
+        let dropped = tx_ids
+            .into_iter()
+            .filter(|id| !block.transactions.iter().any(|tx| tx.id() == *id))
+            .collect::<Vec<_>>();
+        if !dropped.is_empty() {
+            self.txpool.remove(dropped).await?;
+        }
+
+        Ok(block)
+    }
+
+    fn new_header(
+        &self,
+        height: BlockHeight,
+        current_height: BlockHeight,
+    ) -> anyhow::Result<FuelBlockHeader> {
+        let parent_hash = self.db.block_id(current_height)?.unwrap_or_default();
+
+        Ok(FuelBlockHeader {
+            height,
+            number: self.db.finalized_da_height()?.into(),
+            parent_hash,
+            time: Utc::now(),
+            producer: self.config.producer,
+            ..Default::default()
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use async_trait::async_trait;
+    use fuel_core_interfaces::{
+        common::{
+            fuel_merkle::binary::in_memory::MerkleTree,
+            fuel_tx::{
+                Bytes32,
+                Transaction,
+                TransactionBuilder,
+            },
+            fuel_types::bytes::SerializableVec,
+        },
+        db::ChainDb,
+        model::DaBlockHeight,
+        txpool::TxPoolMpsc,
+    };
+    use tokio::sync::mpsc;
+
+    struct MockDb {
+        block_ids: Vec<Bytes32>,
+        da_height: DaBlockHeight,
+    }
+
+    impl ChainDb for MockDb {
+        fn current_height(&self) -> anyhow::Result<BlockHeight> {
+            Ok(self.block_ids.len().into())
+        }
+
+        fn block_id(&self, height: BlockHeight) -> anyhow::Result<Option<Bytes32>> {
+            Ok(self
+                .block_ids
+                .get(height.as_usize().wrapping_sub(1))
+                .copied())
+        }
+
+        fn finalized_da_height(&self) -> anyhow::Result<DaBlockHeight> {
+            Ok(self.da_height)
+        }
+    }
+
+    impl BlockProducerDb for MockDb {}
+
+    /// Drops the transactions with a maturity, like invalid transactions, and sets
+    /// the transactions root to the root of the remaining transactions.
+    struct MockExecutor;
+
+    #[async_trait]
+    impl Executor for MockExecutor {
+        async fn execute(
+            &self,
+            _block: &mut FuelBlock,
+            _mode: ExecutionMode,
+        ) -> anyhow::Result<()> {
+            Err(anyhow!("Producer must not commit blocks"))
+        }
+
+        async fn dry_run(
+            &self,
+            block: &mut FuelBlock,
+            _mode: ExecutionMode,
+        ) -> anyhow::Result<()> {
+            block.transactions.retain(|tx| tx.maturity() == 0);
+            let mut txs_merkle = MerkleTree::new();
+            for tx in block.transactions.iter_mut() {
+                txs_merkle.push(&tx.to_bytes());
+            }
+            block.header.transactions_root = txs_merkle.root().into();
+            Ok(())
+        }
+    }
+
+    fn txpool(txs: Vec<Arc<Transaction>>) -> txpool::Sender {
+        let (sender, mut receiver) = mpsc::channel(10);
+        tokio::spawn(async move {
+            let mut txs = txs;
+            while let Some(event) = receiver.recv().await {
+                match event {
+                    TxPoolMpsc::Includable {
+                        max_gas, response, ..
+                    } => {
+                        let txs = txs
+                            .iter()
+                            .filter(|tx| tx.gas_limit() <= max_gas)
+                            .cloned()
+                            .collect();
+                        let _ = response.send(txs);
+                    }
+                    TxPoolMpsc::Remove { ids, response } => {
+                        let (removed, kept) =
+                            txs.into_iter().partition(|tx| ids.contains(&tx.id()));
+                        txs = kept;
+                        let _ = response.send(removed);
+                    }
+                    _ => {}
+                }
+            }
+        });
+        txpool::Sender::new(sender)
+    }
+
+    fn producer(block_ids: Vec<Bytes32>, txs: Vec<Arc<Transaction>>) -> Producer {
+        Producer {
+            config: Default::default(),
+            db: Arc::new(MockDb {
+                block_ids,
+                da_height: 7,
+            }),
+            txpool: txpool(txs),
+            executor: Arc::new(MockExecutor),
+        }
+    }
+
+    #[tokio::test]
+    async fn produces_block_on_top_of_current_tip() {
+        let block_ids = vec![[1u8; 32].into(), [2u8; 32].into()];
+        let tx = Arc::new(
+            TransactionBuilder::script(vec![], vec![])
+                .gas_price(10)
+                .finalize(),
+        );
+        let producer = producer(block_ids.clone(), vec![tx.clone()]);
+
+        let block = producer.produce_block(3u32.into()).await.unwrap();
+
+        assert_eq!(block.header.height, 3u32.into());
+        assert_eq!(block.header.number, 7u64.into());
+        assert_eq!(block.header.parent_hash, block_ids[1]);
+        assert_ne!(block.header.transactions_root, Bytes32::zeroed());
+        assert_eq!(block.transactions, vec![tx.as_ref().clone()]);
+    }
+
+    #[tokio::test]
+    async fn requests_txs_within_block_gas_limit() {
+        let fits = Arc::new(
+            TransactionBuilder::script(vec![], vec![])
+                .gas_limit(1000)
+                .finalize(),
+        );
+        let too_big = Arc::new(
+            TransactionBuilder::script(vec![], vec![])
+                .gas_limit(1001)
+                .finalize(),
+        );
+        let mut producer = producer(vec![], vec![too_big, fits.clone()]);
+        producer.config.max_gas_per_block = 1000;
+
+        let block = producer.produce_block(1u32.into()).await.unwrap();
+
+        assert_eq!(block.transactions, vec![fits.as_ref().clone()]);
+    }
+
+    #[tokio::test]
+    async fn removes_dropped_txs_from_txpool() {
+        let valid = Arc::new(TransactionBuilder::script(vec![], vec![]).finalize());
+        let invalid = Arc::new(
+            TransactionBuilder::script(vec![], vec![])
+                .maturity(1)
+                .finalize(),
+        );
+        let producer = producer(vec![], vec![valid.clone(), invalid]);
+
+        let block = producer.produce_block(1u32.into()).await.unwrap();
+        assert_eq!(block.transactions, vec![valid.as_ref().clone()]);
+
+        // the invalid transaction isn't selected for the next block
+        let includable = producer
+            .txpool
+            .includable(u64::MAX, usize::MAX)
+            .await
+            .unwrap();
+        assert_eq!(includable, vec![valid]);
+    }
+
+    #[tokio::test]
+    async fn fails_to_produce_block_at_wrong_height() {
+        let producer = producer(vec![[1u8; 32].into()], vec![]);
+
+        assert!(producer.produce_block(1u32.into()).await.is_err());
+        assert!(producer.produce_block(3u32.into()).await.is_err());
+    }
+}
@@ -1,9 +1,17 @@
-use crate::Config;
+use crate::{
+    Config,
+    Producer,
+};
 use fuel_core_interfaces::{
-    block_producer::BlockProducerMpsc,
+    block_producer::{
+        BlockProducerDb,
+        BlockProducerMpsc,
+    },
+    executor::Executor,
     txpool,
 };
 use parking_lot::Mutex;
+use std::sync::Arc;
 use tokio::{
     sync::mpsc,
     task::JoinHandle,
@@ -12,28 +20,57 @@ use tokio::{
 pub struct Service {
     join: Mutex<Option<JoinHandle<()>>>,
     sender: mpsc::Sender<BlockProducerMpsc>,
+    receiver: Mutex<Option<mpsc::Receiver<BlockProducerMpsc>>>,
+    config: Config,
+    db: Arc<dyn BlockProducerDb>,
 }
 
 impl Service {
-    pub async fn new(_config: &Config, _db: ()) -> anyhow::Result<Self> {
-        let (sender, _receiver) = mpsc::channel(100);
+    pub async fn new(
+        config: &Config,
+        db: Arc<dyn BlockProducerDb>,
+    ) -> anyhow::Result<Self> {
+        let (sender, receiver) = mpsc::channel(100);
         Ok(Self {
             sender,
+            receiver: Mutex::new(Some(receiver)),
+            config: config.clone(),
+            db,
             join: Mutex::new(None),
         })
     }
 
-    pub async fn start(&self, _txpool: txpool::Sender) {
+    pub async fn start(&self, txpool: txpool::Sender, executor: Arc<dyn Executor>) {
         let mut join = self.join.lock();
         if join.is_none() {
-            *join = Some(tokio::spawn(async {}));
+            if let Some(receiver) = self.receiver.lock().take() {
+                let producer = Producer {
+                    config: self.config.clone(),
+                    db: self.db.clone(),
+                    txpool,
+                    executor,
+                };
+                *join = Some(tokio::spawn(Self::run(receiver, producer)));
+            }
+        }
+    }
+
+    async fn run(mut receiver: mpsc::Receiver<BlockProducerMpsc>, producer: Producer) {
+        while let Some(event) = receiver.recv().await {
+            match event {
+                BlockProducerMpsc::Produce { height, response } => {
+                    let block = producer.produce_block(height).await.map(Box::new);
+                    let _ = response.send(block);
+                }
+                BlockProducerMpsc::Stop => break,
+            }
         }
     }
 
     pub async fn stop(&self) -> Option<JoinHandle<()>> {
         let join = self.join.lock().take();
         if join.is_some() {
-            let _ = self.sender.send(BlockProducerMpsc::Stop);
+            let _ = self.sender.send(BlockProducerMpsc::Stop).await;
         }
         join
     }
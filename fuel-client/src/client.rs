@@ -56,11 +56,18 @@ use std::{
     },
 };
 use types::{
+    DryRunResult,
     TransactionResponse,
     TransactionStatus,
 };
 
-use crate::client::schema::tx::DryRunArg;
+use crate::client::schema::tx::{
+    DryRunArg,
+    DryRunBatchArg,
+    GasEstimate,
+    StateOverrides,
+    TransactionTrace,
+};
 pub use schema::{
     PageDirection,
     PaginatedResult,
@@ -148,6 +155,23 @@ impl FuelClient {
         self.query(query).await.map(|r| r.chain)
     }
 
+    pub async fn peers(&self) -> io::Result<Vec<schema::chain::PeerInfo>> {
+        let query = schema::chain::PeersQuery::build(());
+        self.query(query).await.map(|r| r.peers)
+    }
+
+    /// Sync progress of the node, to check if it has caught up with its peers.
+    pub async fn sync_status(&self) -> io::Result<schema::sync::SyncStatus> {
+        let query = schema::sync::SyncStatusQuery::build(());
+        self.query(query).await.map(|r| r.sync_status)
+    }
+
+    /// Whether the node has caught up with its peers.
+    pub async fn is_synced(&self) -> io::Result<bool> {
+        let status = self.sync_status().await?;
+        Ok(status.state == schema::sync::SyncState::Synced)
+    }
+
     /// Default dry run, matching the exact configuration as the node
     pub async fn dry_run(&self, tx: &Transaction) -> io::Result<Vec<Receipt>> {
         self.dry_run_opt(tx, None).await
@@ -159,11 +183,24 @@ impl FuelClient {
         tx: &Transaction,
         // Disable utxo input checks (exists, unspent, and valid signature)
         utxo_validation: Option<bool>,
+    ) -> io::Result<Vec<Receipt>> {
+        self.dry_run_with_overrides(tx, utxo_validation, None).await
+    }
+
+    /// Dry run on top of the state of the node with the `overrides` applied, they are
+    /// discarded after the run
+    pub async fn dry_run_with_overrides(
+        &self,
+        tx: &Transaction,
+        // Disable utxo input checks (exists, unspent, and valid signature)
+        utxo_validation: Option<bool>,
+        overrides: Option<StateOverrides>,
     ) -> io::Result<Vec<Receipt>> {
         let tx = tx.clone().to_bytes();
         let query = schema::tx::DryRun::build(&DryRunArg {
             tx: HexString(Bytes(tx)),
             utxo_validation,
+            overrides,
         });
         let receipts = self.query(query).await.map(|r| r.dry_run)?;
         receipts
@@ -172,6 +209,57 @@ impl FuelClient {
             .collect()
     }
 
+    /// Dry run the transactions in order in one block, later transactions may spend the
+    /// outputs of earlier ones
+    pub async fn dry_run_batch(
+        &self,
+        txs: &[Transaction],
+        // Disable utxo input checks (exists, unspent, and valid signature)
+        utxo_validation: Option<bool>,
+    ) -> io::Result<Vec<DryRunResult>> {
+        let query = schema::tx::DryRunBatch::build(&DryRunBatchArg {
+            txs: txs
+                .iter()
+                .map(|tx| HexString(Bytes(tx.clone().to_bytes())))
+                .collect(),
+            utxo_validation,
+        });
+        let results = self.query(query).await.map(|r| r.dry_run_batch)?;
+        results
+            .into_iter()
+            .map(|result| result.try_into().map_err(Into::into))
+            .collect()
+    }
+
+    /// Estimate the gas limit and gas price of the transaction
+    pub async fn estimate_gas(&self, tx: &Transaction) -> io::Result<GasEstimate> {
+        let tx = tx.clone().to_bytes();
+        let query = schema::tx::EstimateGasQuery::build(&TxArg {
+            tx: HexString(Bytes(tx)),
+        });
+        self.query(query).await.map(|r| r.estimate_gas)
+    }
+
+    /// Replay a committed transaction and return the trace of its execution, the node has
+    /// to be built with the `debug` feature
+    pub async fn trace_transaction(
+        &self,
+        id: &str,
+    ) -> io::Result<Option<TransactionTrace>> {
+        let query =
+            schema::tx::TraceTransactionQuery::build(&TxIdArgs { id: id.parse()? });
+        self.query(query).await.map(|r| r.trace_transaction)
+    }
+
+    /// Set the estimated gas limit and gas price on the transaction, it has to be signed
+    /// afterwards
+    pub async fn fill_gas(&self, tx: &mut Transaction) -> io::Result<()> {
+        let estimate = self.estimate_gas(tx).await?;
+        tx.set_gas_limit(estimate.gas_limit.0);
+        tx.set_gas_price(estimate.gas_price.0);
+        Ok(())
+    }
+
     pub async fn submit(&self, tx: &Transaction) -> io::Result<TransactionId> {
         let tx = tx.clone().to_bytes();
         let query = schema::tx::Submit::build(&TxArg {
@@ -473,6 +561,24 @@ impl FuelClient {
         Ok(balance.into())
     }
 
+    /// Proof of a storage slot of a contract against the contracts root of a block.
+    pub async fn storage_proof(
+        &self,
+        block_height: u64,
+        contract: &str,
+        key: &str,
+    ) -> io::Result<Option<schema::contract::StorageProof>> {
+        let query = schema::contract::StorageProofQuery::build(
+            schema::contract::StorageProofArgs {
+                block_height: block_height.into(),
+                contract: contract.parse()?,
+                key: key.parse()?,
+            },
+        );
+
+        Ok(self.query(query).await?.storage_proof)
+    }
+
     pub async fn balance(&self, owner: &str, asset_id: Option<&str>) -> io::Result<u64> {
         let owner: schema::Address = owner.parse()?;
         let asset_id: schema::AssetId = match asset_id {
@@ -525,6 +631,19 @@ impl FuelClient {
 
         Ok(messages)
     }
+
+    /// Inclusion proof of a message sent to the Da layer, for relaying it to the bridge.
+    pub async fn message_proof(
+        &self,
+        message_id: &str,
+    ) -> io::Result<Option<schema::message::MessageProof>> {
+        let message_id: schema::MessageId = message_id.parse()?;
+        let query = schema::message::MessageProofQuery::build(
+            &schema::message::MessageProofArgs { message_id },
+        );
+
+        Ok(self.query(query).await?.message_proof)
+    }
 }
 
 #[cfg(any(test, feature = "test-helpers"))]
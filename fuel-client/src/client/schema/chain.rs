@@ -59,6 +59,21 @@ pub struct ChainInfo {
     pub consensus_parameters: ConsensusParameters,
 }
 
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct PeerInfo {
+    pub id: String,
+    pub addresses: Vec<String>,
+    pub client_version: Option<String>,
+    pub latest_ping: Option<U64>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl", graphql_type = "Query")]
+pub struct PeersQuery {
+    pub peers: Vec<PeerInfo>,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -69,4 +84,11 @@ mod tests {
         let operation = ChainQuery::build(());
         insta::assert_snapshot!(operation.query)
     }
+
+    #[test]
+    fn peers_gql_query_output() {
+        use cynic::QueryBuilder;
+        let operation = PeersQuery::build(());
+        insta::assert_snapshot!(operation.query)
+    }
 }
@@ -2,6 +2,7 @@ use crate::client::{
     schema::{
         schema,
         AssetId,
+        Bytes32,
         ContractId,
         HexString,
         PageInfo,
@@ -68,6 +69,38 @@ pub struct ContractIdFragment {
     pub id: ContractId,
 }
 
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct StorageProof {
+    pub contract_id: ContractId,
+    pub key: Bytes32,
+    pub value: Bytes32,
+    pub block_height: U64,
+    pub state_root: Bytes32,
+    pub balance_root: Bytes32,
+    pub state_proof_set: Vec<Bytes32>,
+    pub contracts_root: Bytes32,
+    pub contract_proof_set: Vec<Bytes32>,
+}
+
+#[derive(cynic::FragmentArguments, Debug)]
+pub struct StorageProofArgs {
+    pub block_height: U64,
+    pub contract: ContractId,
+    pub key: Bytes32,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    argument_struct = "StorageProofArgs"
+)]
+pub struct StorageProofQuery {
+    #[arguments(block_height = &args.block_height, contract = &args.contract, key = &args.key)]
+    pub storage_proof: Option<StorageProof>,
+}
+
 #[derive(cynic::InputObject, Clone, Debug)]
 #[cynic(schema_path = "./assets/schema.sdl")]
 pub struct ContractBalanceFilterInput {
@@ -159,4 +192,15 @@ mod tests {
         });
         insta::assert_snapshot!(operation.query)
     }
+
+    #[test]
+    fn storage_proof_query_gql_output() {
+        use cynic::QueryBuilder;
+        let operation = StorageProofQuery::build(StorageProofArgs {
+            block_height: U64(1),
+            contract: ContractId::default(),
+            key: Bytes32::default(),
+        });
+        insta::assert_snapshot!(operation.query)
+    }
 }
@@ -7,6 +7,10 @@ use super::{
 use crate::client::schema::{
     schema,
     Address,
+    BlockId,
+    Bytes32,
+    HexString,
+    MessageId,
     U64,
 };
 
@@ -95,6 +99,38 @@ impl From<MessageConnection> for PaginatedResult<Message, String> {
     }
 }
 
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct MessageProof {
+    pub message_id: MessageId,
+    pub sender: Address,
+    pub recipient: Address,
+    pub nonce: Bytes32,
+    pub amount: U64,
+    pub data: HexString,
+    pub block_id: BlockId,
+    pub block_height: U64,
+    pub root: Bytes32,
+    pub proof_index: U64,
+    pub proof_set: Vec<Bytes32>,
+}
+
+#[derive(cynic::FragmentArguments, Debug)]
+pub struct MessageProofArgs {
+    pub message_id: MessageId,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    argument_struct = "MessageProofArgs"
+)]
+pub struct MessageProofQuery {
+    #[arguments(message_id = &args.message_id)]
+    pub message_proof: Option<MessageProof>,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -113,4 +149,15 @@ mod tests {
 
         insta::assert_snapshot!(operation.query)
     }
+
+    #[test]
+    fn message_proof_query_gql_output() {
+        use cynic::QueryBuilder;
+
+        let operation = MessageProofQuery::build(MessageProofArgs {
+            message_id: MessageId::default(),
+        });
+
+        insta::assert_snapshot!(operation.query)
+    }
 }
@@ -129,6 +129,12 @@ impl From<UtxoId> for fuel_tx::UtxoId {
     }
 }
 
+impl From<fuel_tx::UtxoId> for UtxoId {
+    fn from(s: fuel_tx::UtxoId) -> Self {
+        UtxoId(HexFormatted::<fuel_tx::UtxoId>(s))
+    }
+}
+
 impl LowerHex for UtxoId {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         LowerHex::fmt(&self.0 .0, f)
@@ -0,0 +1,41 @@
+use crate::client::schema::{
+    schema,
+    U64,
+};
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct SyncStatus {
+    pub state: SyncState,
+    pub current_height: U64,
+    pub peer_height: U64,
+    pub blocks_per_second: f64,
+    pub estimated_time_to_sync: Option<U64>,
+}
+
+#[derive(cynic::Enum, Clone, Copy, Debug, Eq, PartialEq)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub enum SyncState {
+    Stopped,
+    InitialSync,
+    Synced,
+    NoPeers,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl", graphql_type = "Query")]
+pub struct SyncStatusQuery {
+    pub sync_status: SyncStatus,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sync_status_query_gql_output() {
+        use cynic::QueryBuilder;
+        let operation = SyncStatusQuery::build(());
+        insta::assert_snapshot!(operation.query)
+    }
+}
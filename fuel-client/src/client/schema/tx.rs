@@ -3,11 +3,15 @@ use crate::client::{
     schema::{
         schema,
         Address,
+        AssetId,
         ConnectionArgs,
+        ContractId,
         ConversionError,
         HexString,
         PageInfo,
         TransactionId,
+        UtxoId,
+        U64,
     },
     types::TransactionResponse,
     PageDirection,
@@ -167,6 +171,7 @@ pub enum TransactionStatus {
     SubmittedStatus(SubmittedStatus),
     SuccessStatus(SuccessStatus),
     FailureStatus(FailureStatus),
+    RejectedStatus(RejectedStatus),
 }
 
 #[derive(cynic::QueryFragment, Debug)]
@@ -192,6 +197,43 @@ pub struct FailureStatus {
     pub program_state: Option<ProgramState>,
 }
 
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct RejectedStatus {
+    pub time: super::DateTime,
+    pub reason: String,
+}
+
+#[allow(clippy::enum_variant_names)]
+#[derive(cynic::InlineFragments, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub enum DryRunTransactionStatus {
+    DryRunSuccessStatus(DryRunSuccessStatus),
+    DryRunFailureStatus(DryRunFailureStatus),
+    RejectedStatus(RejectedStatus),
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct DryRunSuccessStatus {
+    pub program_state: ProgramState,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct DryRunFailureStatus {
+    pub reason: String,
+    pub program_state: Option<ProgramState>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct DryRunTransactionResult {
+    pub id: TransactionId,
+    pub receipts: Vec<OpaqueReceipt>,
+    pub status: DryRunTransactionStatus,
+}
+
 #[derive(cynic::FragmentArguments, Debug)]
 pub struct TransactionsByOwnerConnectionArgs {
     /// Select transactions based on related `owner`s
@@ -239,6 +281,85 @@ pub struct TransactionsByOwnerQuery {
     pub transactions_by_owner: TransactionConnection,
 }
 
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct GasEstimate {
+    pub gas_limit: U64,
+    pub gas_used: U64,
+    pub gas_price: U64,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    argument_struct = "TxArg"
+)]
+pub struct EstimateGasQuery {
+    #[arguments(tx = &args.tx)]
+    pub estimate_gas: GasEstimate,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct CallTrace {
+    pub depth: U64,
+    pub caller: ContractId,
+    pub contract_id: ContractId,
+    pub amount: U64,
+    pub asset_id: AssetId,
+    pub gas: U64,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct TraceStep {
+    pub contract_id: Option<ContractId>,
+    pub pc: U64,
+    pub opcode: String,
+    pub gas: U64,
+    pub gas_used: U64,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct StorageAccess {
+    pub step: U64,
+    pub contract_id: ContractId,
+    pub key: super::Bytes32,
+    pub value: super::Bytes32,
+    pub write: bool,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct RevertTrace {
+    pub step: U64,
+    pub reason: String,
+    pub registers: Vec<U64>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct TransactionTrace {
+    pub receipts: Vec<OpaqueReceipt>,
+    pub calls: Vec<CallTrace>,
+    pub steps: Vec<TraceStep>,
+    pub storage: Vec<StorageAccess>,
+    pub reverts: Vec<RevertTrace>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    argument_struct = "TxIdArgs"
+)]
+pub struct TraceTransactionQuery {
+    #[arguments(id = &args.id)]
+    pub trace_transaction: Option<TransactionTrace>,
+}
+
 // mutations
 
 #[derive(cynic::FragmentArguments)]
@@ -246,10 +367,66 @@ pub struct TxArg {
     pub tx: HexString,
 }
 
+/// State injected into the database view of a dry run before the execution
+#[derive(cynic::InputObject, Clone, Debug, Default)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct StateOverrides {
+    pub coins: Option<Vec<CoinOverride>>,
+    pub messages: Option<Vec<MessageOverride>>,
+    pub contracts: Option<Vec<ContractOverride>>,
+}
+
+#[derive(cynic::InputObject, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct CoinOverride {
+    pub utxo_id: UtxoId,
+    pub owner: Address,
+    pub amount: U64,
+    pub asset_id: AssetId,
+    pub maturity: Option<U64>,
+}
+
+#[derive(cynic::InputObject, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct MessageOverride {
+    pub sender: Address,
+    pub recipient: Address,
+    pub owner: Address,
+    pub nonce: U64,
+    pub amount: U64,
+    pub data: HexString,
+    pub da_height: Option<U64>,
+}
+
+#[derive(cynic::InputObject, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct ContractOverride {
+    pub contract_id: ContractId,
+    /// Bytecode of the contract, the contract is created if it doesn't exist
+    pub bytecode: Option<HexString>,
+    pub balances: Option<Vec<ContractBalanceOverride>>,
+    pub storage: Option<Vec<StorageSlotOverride>>,
+}
+
+#[derive(cynic::InputObject, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct ContractBalanceOverride {
+    pub asset_id: AssetId,
+    pub amount: U64,
+}
+
+#[derive(cynic::InputObject, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct StorageSlotOverride {
+    pub key: super::Bytes32,
+    pub value: super::Bytes32,
+}
+
 #[derive(cynic::FragmentArguments)]
 pub struct DryRunArg {
     pub tx: HexString,
     pub utxo_validation: Option<bool>,
+    pub overrides: Option<StateOverrides>,
 }
 
 #[derive(cynic::QueryFragment, Debug)]
@@ -259,10 +436,27 @@ pub struct DryRunArg {
     argument_struct = "DryRunArg"
 )]
 pub struct DryRun {
-    #[arguments(tx = &args.tx, utxo_validation = &args.utxo_validation)]
+    #[arguments(tx = &args.tx, utxo_validation = &args.utxo_validation, overrides = &args.overrides)]
     pub dry_run: Vec<OpaqueReceipt>,
 }
 
+#[derive(cynic::FragmentArguments)]
+pub struct DryRunBatchArg {
+    pub txs: Vec<HexString>,
+    pub utxo_validation: Option<bool>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Mutation",
+    argument_struct = "DryRunBatchArg"
+)]
+pub struct DryRunBatch {
+    #[arguments(txs = &args.txs, utxo_validation = &args.utxo_validation)]
+    pub dry_run_batch: Vec<DryRunTransactionResult>,
+}
+
 #[derive(cynic::QueryFragment, Debug)]
 #[cynic(
     schema_path = "./assets/schema.sdl",
@@ -327,6 +521,25 @@ pub mod tests {
         insta::assert_snapshot!(operation.query)
     }
 
+    #[test]
+    fn estimate_gas_gql_output() {
+        use cynic::QueryBuilder;
+        let mut tx = fuel_tx::Transaction::default();
+        let query = EstimateGasQuery::build(TxArg {
+            tx: HexString(Bytes(tx.to_bytes())),
+        });
+        insta::assert_snapshot!(query.query)
+    }
+
+    #[test]
+    fn trace_transaction_gql_output() {
+        use cynic::QueryBuilder;
+        let query = TraceTransactionQuery::build(TxIdArgs {
+            id: TransactionId::default(),
+        });
+        insta::assert_snapshot!(query.query)
+    }
+
     #[test]
     fn dry_run_tx_gql_output() {
         use cynic::MutationBuilder;
@@ -334,6 +547,18 @@ pub mod tests {
         let query = DryRun::build(DryRunArg {
             tx: HexString(Bytes(tx.to_bytes())),
             utxo_validation: None,
+            overrides: None,
+        });
+        insta::assert_snapshot!(query.query)
+    }
+
+    #[test]
+    fn dry_run_batch_gql_output() {
+        use cynic::MutationBuilder;
+        let mut tx = fuel_tx::Transaction::default();
+        let query = DryRunBatch::build(DryRunBatchArg {
+            txs: vec![HexString(Bytes(tx.to_bytes()))],
+            utxo_validation: None,
         });
         insta::assert_snapshot!(query.query)
     }
@@ -1,5 +1,7 @@
 use crate::client::schema::{
     tx::{
+        DryRunTransactionResult as SchemaDryRunResult,
+        DryRunTransactionStatus as SchemaDryRunStatus,
         OpaqueTransaction,
         TransactionStatus as SchemaTxStatus,
     },
@@ -9,7 +11,10 @@ use chrono::{
     DateTime,
     Utc,
 };
-use fuel_tx::Transaction;
+use fuel_tx::{
+    Receipt,
+    Transaction,
+};
 use fuel_types::bytes::Deserializable;
 use fuel_vm::prelude::ProgramState;
 use serde::{
@@ -39,6 +44,10 @@ pub enum TransactionStatus {
         reason: String,
         program_state: Option<ProgramState>,
     },
+    Rejected {
+        time: DateTime<Utc>,
+        reason: String,
+    },
 }
 
 impl TryFrom<SchemaTxStatus> for TransactionStatus {
@@ -60,6 +69,69 @@ impl TryFrom<SchemaTxStatus> for TransactionStatus {
                 reason: s.reason,
                 program_state: s.program_state.map(TryInto::try_into).transpose()?,
             },
+            SchemaTxStatus::RejectedStatus(s) => TransactionStatus::Rejected {
+                time: s.time,
+                reason: s.reason,
+            },
+        })
+    }
+}
+
+/// Outcome of a transaction of a dry run batch.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DryRunResult {
+    pub id: String,
+    pub receipts: Vec<Receipt>,
+    pub status: DryRunStatus,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum DryRunStatus {
+    Success {
+        program_state: ProgramState,
+    },
+    Failure {
+        reason: String,
+        program_state: Option<ProgramState>,
+    },
+    Rejected {
+        time: DateTime<Utc>,
+        reason: String,
+    },
+}
+
+impl TryFrom<SchemaDryRunStatus> for DryRunStatus {
+    type Error = ConversionError;
+
+    fn try_from(status: SchemaDryRunStatus) -> Result<Self, Self::Error> {
+        Ok(match status {
+            SchemaDryRunStatus::DryRunSuccessStatus(s) => DryRunStatus::Success {
+                program_state: s.program_state.try_into()?,
+            },
+            SchemaDryRunStatus::DryRunFailureStatus(s) => DryRunStatus::Failure {
+                reason: s.reason,
+                program_state: s.program_state.map(TryInto::try_into).transpose()?,
+            },
+            SchemaDryRunStatus::RejectedStatus(s) => DryRunStatus::Rejected {
+                time: s.time,
+                reason: s.reason,
+            },
+        })
+    }
+}
+
+impl TryFrom<SchemaDryRunResult> for DryRunResult {
+    type Error = ConversionError;
+
+    fn try_from(result: SchemaDryRunResult) -> Result<Self, Self::Error> {
+        Ok(DryRunResult {
+            id: result.id.0.to_string(),
+            receipts: result
+                .receipts
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<Result<_, _>>()?,
+            status: result.status.try_into()?,
         })
     }
 }
@@ -1,2 +1,51 @@
+use fuel_core_interfaces::{
+    common::{
+        fuel_crypto::PublicKey,
+        fuel_types::Address,
+    },
+    signer::Signer,
+};
+use std::{
+    sync::Arc,
+    time::Duration,
+};
+
 #[derive(Default, Debug, Clone)]
-pub struct Config {}
+pub struct Config {
+    /// Proof-of-authority block producers, in the order they take turns producing
+    /// blocks. Blocks are not checked for consensus if there are no producers.
+    pub producers: Vec<PublicKey>,
+    /// Seal blocks by round based BFT voting of the validators staked on the DA layer.
+    pub proof_of_stake: bool,
+    /// Signs the blocks produced by this node and its consensus votes.
+    pub consensus_key: Option<Arc<dyn Signer>>,
+    pub timeouts: Timeouts,
+}
+
+impl Config {
+    /// Address set as the producer in the header of blocks created by this node.
+    pub fn producer_address(&self) -> Option<Address> {
+        self.consensus_key.as_ref().map(|signer| signer.address())
+    }
+}
+
+/// How long a validator waits in each step of a BFT round. Every round waits
+/// `delta` longer than the previous one, so slow validators eventually catch up.
+#[derive(Debug, Clone)]
+pub struct Timeouts {
+    pub propose: Duration,
+    pub prevote: Duration,
+    pub precommit: Duration,
+    pub delta: Duration,
+}
+
+impl Default for Timeouts {
+    fn default() -> Self {
+        Self {
+            propose: Duration::from_secs(3),
+            prevote: Duration::from_secs(1),
+            precommit: Duration::from_secs(1),
+            delta: Duration::from_millis(500),
+        }
+    }
+}
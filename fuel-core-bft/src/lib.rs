@@ -1,5 +1,9 @@
 pub mod config;
+pub mod poa;
 pub mod service;
+pub mod tendermint;
 
 pub use config::Config;
+pub use poa::ProofOfAuthority;
 pub use service::Service;
+pub use tendermint::Tendermint;
@@ -0,0 +1,239 @@
+use crate::Config;
+use anyhow::anyhow;
+use fuel_core_interfaces::{
+    common::{
+        fuel_crypto::{
+            Message,
+            PublicKey,
+        },
+        fuel_types::Address,
+    },
+    model::{
+        BlockHeight,
+        FuelBlock,
+        FuelBlockConsensus,
+        SealedFuelBlock,
+    },
+    signer::Signer,
+};
+use std::sync::Arc;
+
+/// Proof-of-authority consensus where a fixed set of producers take turns producing
+/// blocks in round robin order, and each block is signed by its producer.
+pub struct ProofOfAuthority {
+    producers: Vec<PublicKey>,
+    signer: Option<Arc<dyn Signer>>,
+}
+
+impl ProofOfAuthority {
+    pub fn new(config: &Config) -> Self {
+        Self {
+            producers: config.producers.clone(),
+            signer: config.consensus_key.clone(),
+        }
+    }
+
+    /// Producer that is allowed to create the block at `height`.
+    pub fn leader(&self, height: BlockHeight) -> Option<&PublicKey> {
+        if self.producers.is_empty() {
+            return None
+        }
+        self.producers.get(height.as_usize() % self.producers.len())
+    }
+
+    /// Check that the block was created by the leader of its height.
+    pub fn check_leader(&self, block: &FuelBlock) -> anyhow::Result<()> {
+        let leader = match self.leader(block.header.height) {
+            Some(leader) => leader,
+            None => return Ok(()),
+        };
+        let leader_address = Address::from(*leader.hash());
+        if block.header.producer != leader_address {
+            return Err(anyhow!(
+                "Block {} is produced by {:#x}, but the leader is {:#x}",
+                block.header.height,
+                block.header.producer,
+                leader_address
+            ))
+        }
+        Ok(())
+    }
+
+    /// Check that the block was created by the leader of its height and that the
+    /// leader signed it.
+    pub fn check_consensus(&self, block: &SealedFuelBlock) -> anyhow::Result<()> {
+        let leader = match self.leader(block.header.height) {
+            Some(leader) => leader,
+            None => return Ok(()),
+        };
+        self.check_leader(block)?;
+        let signature = block.consensus.signature.ok_or_else(|| {
+            anyhow!(
+                "Block {} is not signed by its producer",
+                block.header.height
+            )
+        })?;
+        signature
+            .verify(leader, &Message::new(block.id()))
+            .map_err(|_| {
+                anyhow!(
+                    "Block {} has an invalid producer signature",
+                    block.header.height
+                )
+            })
+    }
+
+    /// Sign a block produced by this node. Blocks don't need consensus if there are
+    /// no producers.
+    pub async fn seal(&self, block: &FuelBlock) -> anyhow::Result<FuelBlockConsensus> {
+        let leader = match self.leader(block.header.height) {
+            Some(leader) => leader,
+            None => return Ok(FuelBlockConsensus::default()),
+        };
+        let signer = self
+            .signer
+            .as_ref()
+            .ok_or_else(|| anyhow!("Producer key is required to sign blocks"))?;
+        if signer.public_key() != *leader {
+            return Err(anyhow!(
+                "This node is not the leader of block {}",
+                block.header.height
+            ))
+        }
+        self.check_leader(block)?;
+
+        Ok(FuelBlockConsensus {
+            signature: Some(signer.sign(&Message::new(block.id())).await?),
+            ..Default::default()
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fuel_core_interfaces::{
+        common::fuel_crypto::{
+            SecretKey,
+            Signature,
+        },
+        model::FuelBlockHeader,
+        signer::InMemorySigner,
+    };
+    use rand::{
+        rngs::StdRng,
+        SeedableRng,
+    };
+
+    fn keys(count: usize) -> Vec<SecretKey> {
+        let mut rng = StdRng::seed_from_u64(2322);
+        (0..count).map(|_| SecretKey::random(&mut rng)).collect()
+    }
+
+    fn poa(keys: &[SecretKey], consensus_key: Option<SecretKey>) -> ProofOfAuthority {
+        ProofOfAuthority::new(&Config {
+            producers: keys.iter().map(|key| key.public_key()).collect(),
+            consensus_key: consensus_key
+                .map(|key| Arc::new(InMemorySigner::new(key)) as Arc<dyn Signer>),
+            ..Default::default()
+        })
+    }
+
+    fn block(height: u32, producer: &SecretKey) -> FuelBlock {
+        FuelBlock {
+            header: FuelBlockHeader {
+                height: height.into(),
+                producer: Address::from(*producer.public_key().hash()),
+                ..Default::default()
+            },
+            transactions: vec![],
+        }
+    }
+
+    #[test]
+    fn leaders_rotate_by_height() {
+        let keys = keys(3);
+        let poa = poa(&keys, None);
+
+        for height in 0u32..6 {
+            let expected = keys[height as usize % 3].public_key();
+            assert_eq!(poa.leader(height.into()), Some(&expected));
+        }
+    }
+
+    #[test]
+    fn rejects_block_from_wrong_leader() {
+        let keys = keys(3);
+        let poa = poa(&keys, None);
+
+        assert!(poa.check_leader(&block(4, &keys[1])).is_ok());
+        assert!(poa.check_leader(&block(4, &keys[2])).is_err());
+    }
+
+    #[tokio::test]
+    async fn sealed_block_passes_consensus_check() {
+        let keys = keys(3);
+        let block = block(5, &keys[2]);
+
+        let consensus = poa(&keys, Some(keys[2])).seal(&block).await.unwrap();
+        let sealed = SealedFuelBlock { block, consensus };
+
+        assert!(poa(&keys, None).check_consensus(&sealed).is_ok());
+    }
+
+    #[tokio::test]
+    async fn rejects_block_with_bad_signature() {
+        let keys = keys(3);
+        let block = block(5, &keys[2]);
+        let consensus = poa(&keys, Some(keys[2])).seal(&block).await.unwrap();
+        let poa = poa(&keys, None);
+
+        let mut forged = SealedFuelBlock {
+            block: block.clone(),
+            consensus: consensus.clone(),
+        };
+        forged.block.header.number = 7u32.into();
+        assert!(poa.check_consensus(&forged).is_err());
+
+        let unsigned = SealedFuelBlock {
+            block: block.clone(),
+            consensus: Default::default(),
+        };
+        assert!(poa.check_consensus(&unsigned).is_err());
+
+        let signed_by_other = SealedFuelBlock {
+            block: block.clone(),
+            consensus: FuelBlockConsensus {
+                signature: Some(Signature::sign(&keys[0], &Message::new(block.id()))),
+                ..Default::default()
+            },
+        };
+        assert!(poa.check_consensus(&signed_by_other).is_err());
+    }
+
+    #[tokio::test]
+    async fn only_leader_can_seal() {
+        let keys = keys(3);
+
+        assert!(poa(&keys, Some(keys[0]))
+            .seal(&block(5, &keys[2]))
+            .await
+            .is_err());
+        assert!(poa(&keys, None).seal(&block(5, &keys[2])).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn consensus_is_not_checked_without_producers() {
+        let keys = keys(1);
+        let poa = poa(&[], None);
+        let block = block(1, &keys[0]);
+
+        assert!(poa.check_leader(&block).is_ok());
+        assert_eq!(poa.seal(&block).await.unwrap().signature, None);
+        let sealed = SealedFuelBlock {
+            block,
+            consensus: Default::default(),
+        };
+        assert!(poa.check_consensus(&sealed).is_ok());
+    }
+}
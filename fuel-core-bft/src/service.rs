@@ -1,55 +1,113 @@
-use crate::Config;
+use crate::{
+    tendermint::{
+        check_votes,
+        Action,
+        Timeout,
+        Validators,
+    },
+    Config,
+    ProofOfAuthority,
+    Tendermint,
+};
+use anyhow::anyhow;
 use fuel_core_interfaces::{
-    bft::BftMpsc,
+    bft::{
+        BftDb,
+        BftMpsc,
+    },
     block_importer::{
         ImportBlockBroadcast,
         ImportBlockMpsc,
     },
     block_producer::BlockProducerMpsc,
-    p2p::P2pRequestEvent,
+    common::fuel_types::Bytes32,
+    model::{
+        BlockHeight,
+        FuelBlock,
+        FuelBlockConsensus,
+        SealedFuelBlock,
+    },
+    p2p::{
+        ConsensusBroadcast,
+        P2pRequestEvent,
+    },
     relayer,
 };
 use parking_lot::Mutex;
+use std::sync::Arc;
 use tokio::{
     sync::{
         broadcast,
         mpsc,
+        oneshot,
     },
     task::JoinHandle,
 };
+use tracing::warn;
 
 pub struct Service {
     join: Mutex<Option<JoinHandle<()>>>,
     sender: mpsc::Sender<BftMpsc>,
+    receiver: Mutex<Option<mpsc::Receiver<BftMpsc>>>,
+    config: Config,
+    db: Arc<dyn BftDb>,
 }
 
 impl Service {
-    pub async fn new(_config: &Config, _db: ()) -> anyhow::Result<Self> {
-        let (sender, _receiver) = mpsc::channel(100);
+    pub async fn new(config: &Config, db: Arc<dyn BftDb>) -> anyhow::Result<Self> {
+        let (sender, receiver) = mpsc::channel(100);
         Ok(Self {
             sender,
+            receiver: Mutex::new(Some(receiver)),
+            config: config.clone(),
+            db,
             join: Mutex::new(None),
         })
     }
 
     pub async fn start(
         &self,
-        _relayer: relayer::Sender,
-        _p2p_consensus: mpsc::Sender<P2pRequestEvent>,
-        _block_producer: mpsc::Sender<BlockProducerMpsc>,
-        _block_importer_sender: mpsc::Sender<ImportBlockMpsc>,
-        _block_importer_broadcast: broadcast::Receiver<ImportBlockBroadcast>,
+        relayer: relayer::Sender,
+        p2p_consensus: mpsc::Sender<P2pRequestEvent>,
+        p2p_consensus_broadcast: mpsc::Receiver<ConsensusBroadcast>,
+        block_producer: mpsc::Sender<BlockProducerMpsc>,
+        block_importer_sender: mpsc::Sender<ImportBlockMpsc>,
+        block_importer_broadcast: broadcast::Receiver<ImportBlockBroadcast>,
     ) {
         let mut join = self.join.lock();
         if join.is_none() {
-            *join = Some(tokio::spawn(async {}));
+            if let Some(receiver) = self.receiver.lock().take() {
+                let (timeout_sender, timeout_receiver) = mpsc::channel(100);
+                let context = Context {
+                    receiver,
+                    poa: ProofOfAuthority::new(&self.config),
+                    tendermint: Tendermint::new(
+                        self.config
+                            .consensus_key
+                            .as_ref()
+                            .map(|signer| signer.public_key()),
+                        self.config.timeouts.clone(),
+                    ),
+                    config: self.config.clone(),
+                    db: self.db.clone(),
+                    relayer,
+                    p2p_consensus,
+                    p2p_consensus_broadcast,
+                    block_producer,
+                    block_importer: block_importer_sender,
+                    import_block_events: block_importer_broadcast,
+                    timeout_sender,
+                    timeout_receiver,
+                };
+                *join = Some(tokio::spawn(context.run()));
+            }
         }
     }
 
     pub async fn stop(&self) -> Option<JoinHandle<()>> {
         let join = self.join.lock().take();
         if join.is_some() {
-            let _ = self.sender.send(BftMpsc::Stop);
+            let _ = self.sender.send(BftMpsc::Stop).await;
         }
         join
     }
@@ -58,3 +116,299 @@ impl Service {
         &self.sender
     }
 }
+
+struct Context {
+    receiver: mpsc::Receiver<BftMpsc>,
+    config: Config,
+    db: Arc<dyn BftDb>,
+    poa: ProofOfAuthority,
+    tendermint: Tendermint,
+    relayer: relayer::Sender,
+    p2p_consensus: mpsc::Sender<P2pRequestEvent>,
+    p2p_consensus_broadcast: mpsc::Receiver<ConsensusBroadcast>,
+    block_producer: mpsc::Sender<BlockProducerMpsc>,
+    block_importer: mpsc::Sender<ImportBlockMpsc>,
+    import_block_events: broadcast::Receiver<ImportBlockBroadcast>,
+    timeout_sender: mpsc::Sender<Timeout>,
+    timeout_receiver: mpsc::Receiver<Timeout>,
+}
+
+impl Context {
+    async fn run(mut self) {
+        if self.config.proof_of_stake {
+            self.start_next_height().await;
+        }
+        let mut p2p_closed = false;
+        let mut import_block_events_closed = false;
+        loop {
+            tokio::select! {
+                event = self.receiver.recv() => {
+                    match event {
+                        Some(BftMpsc::Stop) | None => break,
+                        Some(event) => self.handle_request(event).await,
+                    }
+                }
+                gossip = self.p2p_consensus_broadcast.recv(), if !p2p_closed => {
+                    match gossip {
+                        Some(ConsensusBroadcast::NewVote(vote)) => {
+                            self.tendermint.on_vote(vote)
+                        }
+                        Some(ConsensusBroadcast::NewBlock(block)) => {
+                            self.on_proposal(Arc::new(block)).await
+                        }
+                        None => p2p_closed = true,
+                    }
+                }
+                Some(timeout) = self.timeout_receiver.recv() => {
+                    self.tendermint.on_timeout(timeout)
+                }
+                event = self.import_block_events.recv(), if !import_block_events_closed => {
+                    match event {
+                        Ok(ImportBlockBroadcast::SealedFuelBlockImported { block, .. }) => {
+                            // blocks can be imported by sync before validators commit them
+                            if self.config.proof_of_stake
+                                && block.header.height >= self.tendermint.height()
+                            {
+                                self.start_next_height().await;
+                            }
+                        }
+                        Ok(ImportBlockBroadcast::PendingFuelBlockImported { .. }) => {}
+                        Err(broadcast::error::RecvError::Lagged(_)) => {}
+                        Err(broadcast::error::RecvError::Closed) => {
+                            import_block_events_closed = true
+                        }
+                    }
+                }
+            }
+            self.handle_actions().await;
+        }
+    }
+
+    async fn handle_request(&mut self, event: BftMpsc) {
+        match event {
+            BftMpsc::CheckBlockConsensus { block, ret } => {
+                let result = if self.config.proof_of_stake {
+                    match self.validators(block.header.height).await {
+                        Ok(validators) => check_votes(&block, &validators),
+                        Err(e) => Err(e),
+                    }
+                } else {
+                    self.poa.check_consensus(&block)
+                };
+                let _ = ret.send(result);
+            }
+            BftMpsc::CheckBlockLeader { block, ret } => {
+                let result = if self.config.proof_of_stake {
+                    self.check_proposer(&block).await
+                } else {
+                    self.poa.check_leader(&block)
+                };
+                let _ = ret.send(result);
+            }
+            BftMpsc::SealBlock { block, ret } => {
+                let result = if self.config.proof_of_stake {
+                    Err(anyhow!("Blocks are sealed by the votes of the validators"))
+                } else {
+                    self.poa.seal(&block).await
+                };
+                let _ = ret.send(result);
+            }
+            BftMpsc::Start | BftMpsc::Stop => {}
+        }
+    }
+
+    async fn handle_actions(&mut self) {
+        loop {
+            let actions = self.tendermint.take_actions();
+            if actions.is_empty() {
+                break
+            }
+            for action in actions {
+                match action {
+                    Action::Produce { height, round } => {
+                        if let Err(e) = self.produce(height).await {
+                            warn!(
+                                "Failed to propose block {} in round {}: {}",
+                                height, round, e
+                            );
+                        }
+                    }
+                    // gossip is best effort, votes are not worth blocking on a busy network
+                    Action::BroadcastBlock(block) => {
+                        let _ = self
+                            .p2p_consensus
+                            .try_send(P2pRequestEvent::BroadcastNewBlock { block });
+                    }
+                    Action::SignVote(mut vote) => {
+                        let signer = match &self.config.consensus_key {
+                            Some(signer) => signer,
+                            None => continue,
+                        };
+                        if let Err(e) = vote.sign(signer.as_ref()).await {
+                            warn!(
+                                "Failed to sign vote for block {} in round {}: {}",
+                                vote.height, vote.round, e
+                            );
+                            continue
+                        }
+                        self.tendermint.on_vote(vote);
+                        let _ = self.p2p_consensus.try_send(
+                            P2pRequestEvent::BroadcastConsensusVote {
+                                vote: Arc::new(vote),
+                            },
+                        );
+                    }
+                    Action::ScheduleTimeout(timeout, after) => {
+                        let sender = self.timeout_sender.clone();
+                        tokio::spawn(async move {
+                            tokio::time::sleep(after).await;
+                            let _ = sender.send(timeout).await;
+                        });
+                    }
+                    Action::Commit { block, consensus } => {
+                        let height = block.header.height;
+                        if let Err(e) = self.commit(block, consensus).await {
+                            warn!("Failed to commit block {}: {}", height, e);
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    /// Start voting on the block on top of the current chain tip.
+    async fn start_next_height(&mut self) {
+        let next = async {
+            let current_height = self.db.current_height()?;
+            let parent_hash = self
+                .db
+                .block_header(current_height)?
+                .map(|header| header.id())
+                .unwrap_or_default();
+            let height = current_height + 1u32.into();
+            let validators = self.validators(height).await?;
+            anyhow::Ok((height, parent_hash, validators))
+        };
+        match next.await {
+            Ok((height, parent_hash, validators)) => {
+                self.tendermint
+                    .start_height(height, parent_hash, validators)
+            }
+            Err(e) => warn!("Failed to start voting on the next block: {}", e),
+        }
+    }
+
+    /// Validators of the block at `height`, with their stake at the DA height of its
+    /// parent block.
+    async fn validators(&self, height: BlockHeight) -> anyhow::Result<Validators> {
+        let parent_height = u32::from(height).saturating_sub(1).into();
+        let da_height = self
+            .db
+            .block_header(parent_height)?
+            .map(|header| header.number.into())
+            .unwrap_or_default();
+        let validator_set = self.relayer.get_validator_set(da_height).await?;
+        Ok(Validators::from_validator_set(&validator_set))
+    }
+
+    async fn check_proposer(&self, block: &FuelBlock) -> anyhow::Result<()> {
+        let validators = self.validators(block.header.height).await?;
+        if validators.stake(&block.header.producer) == 0 {
+            return Err(anyhow!(
+                "Block {} is produced by {:#x}, which is not a validator",
+                block.header.height,
+                block.header.producer
+            ))
+        }
+        Ok(())
+    }
+
+    /// Produce the block proposed by this validator. The importer validates the block
+    /// and keeps it pending until it is sealed.
+    async fn produce(&mut self, height: BlockHeight) -> anyhow::Result<()> {
+        let (response, receiver) = oneshot::channel();
+        self.block_producer
+            .send(BlockProducerMpsc::Produce { height, response })
+            .await?;
+        let block = Arc::new(*receiver.await??);
+
+        let (response, receiver) = oneshot::channel();
+        self.block_importer
+            .send(ImportBlockMpsc::ImportFuelBlock {
+                block: block.clone(),
+                response,
+            })
+            .await?;
+        receiver.await??;
+
+        self.tendermint.propose(block);
+        Ok(())
+    }
+
+    /// Pass the gossiped block to tendermint only if it executes on top of the chain
+    /// tip, so validators never vote for blocks that fail to import.
+    async fn on_proposal(&mut self, block: Arc<FuelBlock>) {
+        if !self.tendermint.is_valid(&block) {
+            return
+        }
+        let validated = async {
+            let (response, receiver) = oneshot::channel();
+            self.block_importer
+                .send(ImportBlockMpsc::ValidateFuelBlock {
+                    block: block.clone(),
+                    response,
+                })
+                .await?;
+            receiver.await?
+        };
+        match validated.await {
+            Ok(()) => self.tendermint.on_block(block),
+            Err(e) => warn!(
+                "Block {} proposed by {:#x} is invalid: {}",
+                block.header.height, block.header.producer, e
+            ),
+        }
+    }
+
+    async fn commit(
+        &self,
+        block: Arc<FuelBlock>,
+        consensus: FuelBlockConsensus,
+    ) -> anyhow::Result<()> {
+        // the pending block of the importer is replaced if this validator proposed
+        // another block in a later round, then it is imported like any other block
+        if Some(block.header.producer) == self.config.producer_address()
+            && self.seal(block.id(), consensus.clone()).await.is_ok()
+        {
+            return Ok(())
+        }
+
+        let (response, receiver) = oneshot::channel();
+        self.block_importer
+            .send(ImportBlockMpsc::ImportSealedFuelBlock {
+                block: Arc::new(SealedFuelBlock {
+                    block: block.as_ref().clone(),
+                    consensus,
+                }),
+                response,
+            })
+            .await?;
+        receiver.await?
+    }
+
+    async fn seal(
+        &self,
+        block_id: Bytes32,
+        consensus: FuelBlockConsensus,
+    ) -> anyhow::Result<()> {
+        let (response, receiver) = oneshot::channel();
+        self.block_importer
+            .send(ImportBlockMpsc::SealFuelBlock {
+                consensus,
+                block_id,
+                response,
+            })
+            .await?;
+        receiver.await?
+    }
+}
@@ -0,0 +1,841 @@
+use crate::config::Timeouts;
+use anyhow::anyhow;
+use fuel_core_interfaces::{
+    common::{
+        fuel_crypto::PublicKey,
+        fuel_types::{
+            Address,
+            Bytes32,
+        },
+    },
+    model::{
+        BlockHeight,
+        ConsensusVote,
+        FuelBlock,
+        FuelBlockConsensus,
+        SealedFuelBlock,
+        ValidatorStake,
+        VoteStep,
+    },
+    relayer::ValidatorSet,
+};
+use std::{
+    collections::{
+        BTreeMap,
+        HashMap,
+        HashSet,
+    },
+    sync::Arc,
+    time::Duration,
+};
+
+/// Stake of the validators that vote on a block, by the address of their consensus key.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Validators {
+    stakes: BTreeMap<Address, ValidatorStake>,
+    total: u128,
+}
+
+impl Validators {
+    pub fn new(stakes: impl IntoIterator<Item = (Address, ValidatorStake)>) -> Self {
+        let stakes: BTreeMap<_, _> =
+            stakes.into_iter().filter(|(_, stake)| *stake > 0).collect();
+        let total = stakes.values().map(|stake| *stake as u128).sum();
+        Self { stakes, total }
+    }
+
+    /// Validators of the relayer validator set. The consensus id of a validator is the
+    /// hash of its consensus public key, and validators without one can't vote.
+    pub fn from_validator_set(set: &ValidatorSet) -> Self {
+        let mut stakes = BTreeMap::<Address, ValidatorStake>::new();
+        for (stake, consensus_id) in set.values() {
+            if let Some(consensus_id) = consensus_id {
+                *stakes.entry(Address::from(**consensus_id)).or_default() += stake;
+            }
+        }
+        Self::new(stakes)
+    }
+
+    pub fn stake(&self, validator: &Address) -> ValidatorStake {
+        self.stakes.get(validator).copied().unwrap_or_default()
+    }
+
+    /// Smallest stake that is more than two thirds of the total stake.
+    pub fn required_stake(&self) -> u64 {
+        (self.total * 2 / 3 + 1) as u64
+    }
+
+    fn is_quorum(&self, stake: u128) -> bool {
+        stake * 3 > self.total * 2
+    }
+
+    /// More than one third of the stake contains at least one honest validator.
+    fn is_one_third(&self, stake: u128) -> bool {
+        stake * 3 > self.total
+    }
+
+    /// Validators take turns proposing blocks, by height and round.
+    pub fn proposer(&self, height: BlockHeight, round: u64) -> Option<&Address> {
+        if self.stakes.is_empty() {
+            return None
+        }
+        let index =
+            (height.as_usize() as u64).wrapping_add(round) % self.stakes.len() as u64;
+        self.stakes.keys().nth(index as usize)
+    }
+
+    fn stake_of<'a>(&self, voters: impl Iterator<Item = &'a Address>) -> u128 {
+        voters.map(|voter| self.stake(voter) as u128).sum()
+    }
+}
+
+/// Check that the block is sealed by precommits of more than two thirds of the stake.
+pub fn check_votes(
+    block: &SealedFuelBlock,
+    validators: &Validators,
+) -> anyhow::Result<()> {
+    let height = block.header.height;
+    let block_id = block.id();
+    let round = block.consensus.votes.first().map(|vote| vote.round);
+    let mut voters = HashSet::new();
+    for vote in &block.consensus.votes {
+        if vote.height != height
+            || vote.step != VoteStep::Precommit
+            || vote.block_id != Some(block_id)
+            || Some(vote.round) != round
+        {
+            return Err(anyhow!(
+                "Vote of {:#x} is not a precommit of block {} in round {:?}",
+                vote.validator_address(),
+                height,
+                round
+            ))
+        }
+        if !vote.verify() {
+            return Err(anyhow!(
+                "Vote of {:#x} has an invalid signature",
+                vote.validator_address()
+            ))
+        }
+        voters.insert(vote.validator_address());
+    }
+
+    let stake = validators.stake_of(voters.iter());
+    if !validators.is_quorum(stake) {
+        return Err(anyhow!(
+            "Block {} is sealed by {} stake, but {} is required",
+            height,
+            stake,
+            validators.required_stake()
+        ))
+    }
+    Ok(())
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Step {
+    Propose,
+    Prevote,
+    Precommit,
+    /// Block of the height is committed, nothing to vote on until the next height.
+    Commit,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Timeout {
+    pub height: BlockHeight,
+    pub round: u64,
+    pub step: Step,
+}
+
+#[derive(Debug)]
+pub enum Action {
+    /// Produce a new block for the round and pass it to `Tendermint::propose`.
+    Produce {
+        height: BlockHeight,
+        round: u64,
+    },
+    BroadcastBlock(Arc<FuelBlock>),
+    /// Sign the vote of this validator, then broadcast it and pass it to
+    /// `Tendermint::on_vote`.
+    SignVote(ConsensusVote),
+    /// Pass the timeout to `Tendermint::on_timeout` once the duration elapses.
+    ScheduleTimeout(Timeout, Duration),
+    /// The block is sealed by the consensus and can be committed.
+    Commit {
+        block: Arc<FuelBlock>,
+        consensus: FuelBlockConsensus,
+    },
+}
+
+/// Round based BFT voting on the block of one height at a time, following the
+/// Tendermint algorithm. The engine doesn't do any IO, it returns the actions the
+/// caller has to perform with `take_actions`.
+///
+/// Proposals are not separate messages. The proposal of a round is the block its
+/// proposer prevotes for, and the block itself is gossiped like any other block.
+pub struct Tendermint {
+    validator: Option<PublicKey>,
+    timeouts: Timeouts,
+    height: BlockHeight,
+    parent_hash: Bytes32,
+    validators: Validators,
+    round: u64,
+    step: Step,
+    /// Block this validator precommitted, it only prevotes for other blocks once more
+    /// than two thirds of the stake prevote for them in a later round.
+    locked: Option<(u64, Bytes32)>,
+    /// Latest block that received prevotes of more than two thirds of the stake.
+    valid: Option<(u64, Bytes32)>,
+    blocks: HashMap<Bytes32, Arc<FuelBlock>>,
+    votes: HashMap<(u64, VoteStep), HashMap<Address, ConsensusVote>>,
+    prevote_timeout_scheduled: bool,
+    precommit_timeout_scheduled: bool,
+    valid_updated: bool,
+    actions: Vec<Action>,
+}
+
+impl Tendermint {
+    /// Create an engine that votes as the validator with the `validator` consensus
+    /// key, or only follows the votes of the validators if there is no key.
+    pub fn new(validator: Option<PublicKey>, timeouts: Timeouts) -> Self {
+        Self {
+            validator,
+            timeouts,
+            height: BlockHeight::default(),
+            parent_hash: Bytes32::default(),
+            validators: Validators::default(),
+            round: 0,
+            step: Step::Commit,
+            locked: None,
+            valid: None,
+            blocks: HashMap::new(),
+            votes: HashMap::new(),
+            prevote_timeout_scheduled: false,
+            precommit_timeout_scheduled: false,
+            valid_updated: false,
+            actions: Vec::new(),
+        }
+    }
+
+    pub fn height(&self) -> BlockHeight {
+        self.height
+    }
+
+    pub fn round(&self) -> u64 {
+        self.round
+    }
+
+    pub fn step(&self) -> Step {
+        self.step
+    }
+
+    pub fn take_actions(&mut self) -> Vec<Action> {
+        std::mem::take(&mut self.actions)
+    }
+
+    /// Start voting on the block at `height`, which extends the block `parent_hash`.
+    pub fn start_height(
+        &mut self,
+        height: BlockHeight,
+        parent_hash: Bytes32,
+        validators: Validators,
+    ) {
+        self.height = height;
+        self.parent_hash = parent_hash;
+        self.validators = validators;
+        self.locked = None;
+        self.valid = None;
+        self.blocks.clear();
+        self.votes.clear();
+        self.start_round(0);
+        self.update();
+    }
+
+    /// Propose a block produced by this node after `Action::Produce`.
+    pub fn propose(&mut self, block: Arc<FuelBlock>) {
+        if self.step != Step::Propose
+            || !self.is_proposer(self.round)
+            || !self.is_valid(&block)
+        {
+            return
+        }
+        let block_id = block.id();
+        self.blocks.insert(block_id, block.clone());
+        self.actions.push(Action::BroadcastBlock(block));
+        self.vote(VoteStep::Prevote, Some(block_id));
+        self.step = Step::Prevote;
+        self.update();
+    }
+
+    /// Block gossiped by other nodes. The caller executes the block first, only valid
+    /// blocks can get votes.
+    pub fn on_block(&mut self, block: Arc<FuelBlock>) {
+        if self.is_valid(&block) {
+            self.blocks.insert(block.id(), block);
+            self.update();
+        }
+    }
+
+    /// Vote gossiped by other nodes.
+    pub fn on_vote(&mut self, vote: ConsensusVote) {
+        if vote.height == self.height
+            && self.validators.stake(&vote.validator_address()) > 0
+            && vote.verify()
+        {
+            self.insert_vote(vote);
+            self.update();
+        }
+    }
+
+    pub fn on_timeout(&mut self, timeout: Timeout) {
+        if timeout.height != self.height || timeout.round != self.round {
+            return
+        }
+        match (timeout.step, self.step) {
+            (Step::Propose, Step::Propose) => {
+                self.vote(VoteStep::Prevote, None);
+                self.step = Step::Prevote;
+            }
+            (Step::Prevote, Step::Prevote) => {
+                self.vote(VoteStep::Precommit, None);
+                self.step = Step::Precommit;
+            }
+            (Step::Precommit, step) if step != Step::Commit => {
+                self.start_round(self.round + 1)
+            }
+            _ => return,
+        }
+        self.update();
+    }
+
+    fn start_round(&mut self, round: u64) {
+        self.round = round;
+        self.step = Step::Propose;
+        self.prevote_timeout_scheduled = false;
+        self.precommit_timeout_scheduled = false;
+        self.valid_updated = false;
+
+        if self.is_proposer(round) {
+            // a block that may already be locked by other validators is proposed again
+            match self.valid.and_then(|(_, id)| self.blocks.get(&id).cloned()) {
+                Some(block) => {
+                    self.actions.push(Action::BroadcastBlock(block.clone()));
+                    self.vote(VoteStep::Prevote, Some(block.id()));
+                    self.step = Step::Prevote;
+                }
+                None => self.actions.push(Action::Produce {
+                    height: self.height,
+                    round,
+                }),
+            }
+        }
+        self.schedule_timeout(Step::Propose);
+    }
+
+    /// Apply the rules of the algorithm until none of them changes the state.
+    fn update(&mut self) {
+        while self.apply_rule() {}
+    }
+
+    fn apply_rule(&mut self) -> bool {
+        if self.step == Step::Commit {
+            return false
+        }
+        if let Some((round, block_id)) = self.precommit_quorum() {
+            self.commit(round, block_id);
+            return true
+        }
+        if let Some(round) = self.later_round() {
+            self.start_round(round);
+            return true
+        }
+
+        let round = self.round;
+        let proposal = self.proposal(round);
+        match self.step {
+            Step::Propose => {
+                if let Some(block_id) = proposal {
+                    let unlocked = match self.locked {
+                        None => true,
+                        Some((_, locked_id)) if locked_id == block_id => true,
+                        // the lock is released by a quorum of prevotes after it
+                        Some((locked_round, _)) => (locked_round..round).any(|vr| {
+                            self.has_quorum(vr, VoteStep::Prevote, Some(Some(block_id)))
+                        }),
+                    };
+                    self.vote(VoteStep::Prevote, unlocked.then_some(block_id));
+                    self.step = Step::Prevote;
+                    return true
+                }
+            }
+            Step::Prevote => {
+                if !self.prevote_timeout_scheduled
+                    && self.has_quorum(round, VoteStep::Prevote, None)
+                {
+                    self.prevote_timeout_scheduled = true;
+                    self.schedule_timeout(Step::Prevote);
+                    return true
+                }
+                if self.has_quorum(round, VoteStep::Prevote, Some(None)) {
+                    self.vote(VoteStep::Precommit, None);
+                    self.step = Step::Precommit;
+                    return true
+                }
+            }
+            Step::Precommit | Step::Commit => {}
+        }
+
+        if let Some(block_id) = proposal {
+            if !self.valid_updated
+                && self.step != Step::Propose
+                && self.has_quorum(round, VoteStep::Prevote, Some(Some(block_id)))
+            {
+                self.valid_updated = true;
+                if self.step == Step::Prevote {
+                    self.locked = Some((round, block_id));
+                    self.vote(VoteStep::Precommit, Some(block_id));
+                    self.step = Step::Precommit;
+                }
+                self.valid = Some((round, block_id));
+                return true
+            }
+        }
+        if !self.precommit_timeout_scheduled
+            && self.has_quorum(round, VoteStep::Precommit, None)
+        {
+            self.precommit_timeout_scheduled = true;
+            self.schedule_timeout(Step::Precommit);
+            return true
+        }
+        false
+    }
+
+    fn commit(&mut self, round: u64, block_id: Bytes32) {
+        let block = self.blocks[&block_id].clone();
+        let votes = self.votes[&(round, VoteStep::Precommit)]
+            .values()
+            .filter(|vote| vote.block_id == Some(block_id))
+            .copied()
+            .collect();
+        self.step = Step::Commit;
+        self.actions.push(Action::Commit {
+            block,
+            consensus: FuelBlockConsensus {
+                required_stake: self.validators.required_stake(),
+                votes,
+                ..Default::default()
+            },
+        });
+    }
+
+    /// Round and known block that received precommits of more than two thirds of the
+    /// stake in any round.
+    fn precommit_quorum(&self) -> Option<(u64, Bytes32)> {
+        self.votes
+            .iter()
+            .filter(|((_, step), _)| *step == VoteStep::Precommit)
+            .find_map(|((round, _), votes)| {
+                votes
+                    .values()
+                    .filter_map(|vote| vote.block_id)
+                    .filter(|block_id| self.blocks.contains_key(block_id))
+                    .find(|block_id| {
+                        self.has_quorum(
+                            *round,
+                            VoteStep::Precommit,
+                            Some(Some(*block_id)),
+                        )
+                    })
+                    .map(|block_id| (*round, block_id))
+            })
+    }
+
+    /// Later round that more than one third of the stake already votes in.
+    fn later_round(&self) -> Option<u64> {
+        let mut voters = HashMap::<u64, HashSet<Address>>::new();
+        for ((round, _), votes) in &self.votes {
+            if *round > self.round {
+                voters.entry(*round).or_default().extend(votes.keys());
+            }
+        }
+        voters
+            .into_iter()
+            .filter(|(_, voters)| {
+                self.validators
+                    .is_one_third(self.validators.stake_of(voters.iter()))
+            })
+            .map(|(round, _)| round)
+            .max()
+    }
+
+    /// Block that the proposer of the round prevotes for.
+    fn proposal(&self, round: u64) -> Option<Bytes32> {
+        let proposer = self.validators.proposer(self.height, round)?;
+        self.votes
+            .get(&(round, VoteStep::Prevote))?
+            .get(proposer)?
+            .block_id
+            .filter(|block_id| self.blocks.contains_key(block_id))
+    }
+
+    /// Whether more than two thirds of the stake voted in the round and step, for the
+    /// given block if `block_id` is set.
+    fn has_quorum(
+        &self,
+        round: u64,
+        step: VoteStep,
+        block_id: Option<Option<Bytes32>>,
+    ) -> bool {
+        let votes = match self.votes.get(&(round, step)) {
+            Some(votes) => votes,
+            None => return false,
+        };
+        let voters = votes
+            .iter()
+            .filter(|(_, vote)| match block_id {
+                Some(id) => vote.block_id == id,
+                None => true,
+            })
+            .map(|(voter, _)| voter);
+        self.validators.is_quorum(self.validators.stake_of(voters))
+    }
+
+    fn is_proposer(&self, round: u64) -> bool {
+        match (
+            &self.validator,
+            self.validators.proposer(self.height, round),
+        ) {
+            (Some(validator), Some(proposer)) => {
+                Address::from(*validator.hash()) == *proposer
+            }
+            _ => false,
+        }
+    }
+
+    /// Whether the block can be proposed in the current height, without executing it.
+    pub fn is_valid(&self, block: &FuelBlock) -> bool {
+        block.header.height == self.height
+            && block.header.parent_hash == self.parent_hash
+            && self.validators.stake(&block.header.producer) > 0
+    }
+
+    fn vote(&mut self, step: VoteStep, block_id: Option<Bytes32>) {
+        let validator = match self.validator {
+            Some(validator) => validator,
+            None => return,
+        };
+        let vote = ConsensusVote::new(validator, self.height, self.round, step, block_id);
+        if self.validators.stake(&vote.validator_address()) > 0 {
+            self.actions.push(Action::SignVote(vote));
+        }
+    }
+
+    fn insert_vote(&mut self, vote: ConsensusVote) {
+        // only the first vote of a validator in a step counts
+        self.votes
+            .entry((vote.round, vote.step))
+            .or_default()
+            .entry(vote.validator_address())
+            .or_insert(vote);
+    }
+
+    fn schedule_timeout(&mut self, step: Step) {
+        let base = match step {
+            Step::Propose => self.timeouts.propose,
+            Step::Prevote => self.timeouts.prevote,
+            Step::Precommit | Step::Commit => self.timeouts.precommit,
+        };
+        let timeout = Timeout {
+            height: self.height,
+            round: self.round,
+            step,
+        };
+        let duration = base + self.timeouts.delta * self.round as u32;
+        self.actions
+            .push(Action::ScheduleTimeout(timeout, duration));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{
+        TimeZone,
+        Utc,
+    };
+    use fuel_core_interfaces::{
+        common::fuel_crypto::{
+            SecretKey,
+            Signature,
+        },
+        model::FuelBlockHeader,
+    };
+    use rand::{
+        rngs::StdRng,
+        SeedableRng,
+    };
+    use std::collections::VecDeque;
+
+    enum Gossip {
+        Block(Arc<FuelBlock>),
+        Vote(ConsensusVote),
+    }
+
+    /// In-process network of validators that delivers every gossiped message to every
+    /// online validator, and fires timeouts by a simulated clock.
+    struct Network {
+        keys: Vec<SecretKey>,
+        nodes: Vec<Tendermint>,
+        online: Vec<bool>,
+        validators: Validators,
+        messages: VecDeque<(usize, Gossip)>,
+        timeouts: Vec<(Duration, usize, Timeout)>,
+        now: Duration,
+        committed: Vec<Option<(Arc<FuelBlock>, FuelBlockConsensus)>>,
+    }
+
+    impl Network {
+        fn new(stakes: &[ValidatorStake]) -> Self {
+            let mut rng = StdRng::seed_from_u64(1234);
+            let keys: Vec<_> =
+                stakes.iter().map(|_| SecretKey::random(&mut rng)).collect();
+            let validators = Validators::new(
+                keys.iter()
+                    .map(|key| Address::from(*key.public_key().hash()))
+                    .zip(stakes.iter().copied()),
+            );
+            let nodes = keys
+                .iter()
+                .map(|key| Tendermint::new(Some(key.public_key()), Timeouts::default()))
+                .collect();
+            Self {
+                online: vec![true; keys.len()],
+                committed: vec![None; keys.len()],
+                keys,
+                nodes,
+                validators,
+                messages: VecDeque::new(),
+                timeouts: Vec::new(),
+                now: Duration::ZERO,
+            }
+        }
+
+        fn index_of(&self, address: &Address) -> usize {
+            self.keys
+                .iter()
+                .position(|key| Address::from(*key.public_key().hash()) == *address)
+                .unwrap()
+        }
+
+        fn proposer(&self, round: u64) -> usize {
+            self.index_of(self.validators.proposer(1u32.into(), round).unwrap())
+        }
+
+        /// Vote on the block at height 1 until every online validator commits it or the
+        /// simulated time runs out.
+        fn run(&mut self, time_limit: Duration) {
+            for node in &mut self.nodes {
+                node.start_height(
+                    1u32.into(),
+                    Bytes32::zeroed(),
+                    self.validators.clone(),
+                );
+            }
+            loop {
+                self.handle_actions();
+                let all_committed = (0..self.nodes.len())
+                    .all(|i| !self.online[i] || self.committed[i].is_some());
+                if all_committed {
+                    return
+                }
+                if let Some((from, gossip)) = self.messages.pop_front() {
+                    for i in
+                        (0..self.nodes.len()).filter(|i| *i != from && self.online[*i])
+                    {
+                        match &gossip {
+                            Gossip::Block(block) => self.nodes[i].on_block(block.clone()),
+                            Gossip::Vote(vote) => self.nodes[i].on_vote(*vote),
+                        }
+                    }
+                    continue
+                }
+                self.timeouts.sort_by_key(|(at, ..)| *at);
+                if self.timeouts.is_empty() || self.timeouts[0].0 > time_limit {
+                    return
+                }
+                let (at, i, timeout) = self.timeouts.remove(0);
+                self.now = at;
+                self.nodes[i].on_timeout(timeout);
+            }
+        }
+
+        fn handle_actions(&mut self) {
+            for i in 0..self.nodes.len() {
+                while self.online[i] {
+                    let actions = self.nodes[i].take_actions();
+                    if actions.is_empty() {
+                        break
+                    }
+                    for action in actions {
+                        self.handle_action(i, action);
+                    }
+                }
+            }
+        }
+
+        fn handle_action(&mut self, i: usize, action: Action) {
+            match action {
+                Action::Produce { height, round } => {
+                    let block = FuelBlock {
+                        header: FuelBlockHeader {
+                            height,
+                            time: Utc.timestamp(round as i64, 0),
+                            producer: Address::from(*self.keys[i].public_key().hash()),
+                            ..Default::default()
+                        },
+                        transactions: vec![],
+                    };
+                    self.nodes[i].propose(Arc::new(block));
+                }
+                Action::BroadcastBlock(block) => {
+                    self.messages.push_back((i, Gossip::Block(block)))
+                }
+                Action::SignVote(mut vote) => {
+                    vote.signature = Signature::sign(&self.keys[i], &vote.message());
+                    self.nodes[i].on_vote(vote);
+                    self.messages.push_back((i, Gossip::Vote(vote)))
+                }
+                Action::ScheduleTimeout(timeout, after) => {
+                    self.timeouts.push((self.now + after, i, timeout))
+                }
+                Action::Commit { block, consensus } => {
+                    self.committed[i] = Some((block, consensus))
+                }
+            }
+        }
+
+        /// Block committed by all online validators, panics if they disagree.
+        fn committed_block(&self) -> Option<SealedFuelBlock> {
+            let mut committed = (0..self.nodes.len())
+                .filter(|i| self.online[*i])
+                .map(|i| self.committed[i].clone());
+            let (block, consensus) = committed.next()??;
+            for other in committed {
+                assert_eq!(other.map(|(block, _)| block.id()), Some(block.id()));
+            }
+            Some(SealedFuelBlock {
+                block: block.as_ref().clone(),
+                consensus,
+            })
+        }
+    }
+
+    const TIME_LIMIT: Duration = Duration::from_secs(60);
+
+    #[test]
+    fn validators_commit_proposed_block() {
+        let mut network = Network::new(&[10, 10, 10, 10]);
+        let proposer = network.proposer(0);
+
+        network.run(TIME_LIMIT);
+
+        let block = network.committed_block().expect("block is committed");
+        assert_eq!(block.header.height, 1u32.into());
+        assert_eq!(
+            block.header.producer,
+            Address::from(*network.keys[proposer].public_key().hash())
+        );
+        assert_eq!(network.now, Duration::ZERO);
+        check_votes(&block, &network.validators).unwrap();
+    }
+
+    #[test]
+    fn commits_with_validator_offline() {
+        let mut network = Network::new(&[10, 10, 10, 10]);
+        let offline = (network.proposer(0) + 1) % 4;
+        network.online[offline] = false;
+
+        network.run(TIME_LIMIT);
+
+        let block = network.committed_block().expect("block is committed");
+        assert_eq!(block.consensus.votes.len(), 3);
+        assert_eq!(block.consensus.votes[0].round, 0);
+        check_votes(&block, &network.validators).unwrap();
+    }
+
+    #[test]
+    fn next_round_commits_if_proposer_is_offline() {
+        let mut network = Network::new(&[10, 10, 10, 10]);
+        let proposer = network.proposer(0);
+        network.online[proposer] = false;
+
+        network.run(TIME_LIMIT);
+
+        let block = network.committed_block().expect("block is committed");
+        assert_eq!(block.consensus.votes[0].round, 1);
+        assert_eq!(
+            block.header.producer,
+            Address::from(*network.keys[network.proposer(1)].public_key().hash())
+        );
+        check_votes(&block, &network.validators).unwrap();
+    }
+
+    #[test]
+    fn no_commit_without_two_thirds_of_stake() {
+        let mut network = Network::new(&[10, 10, 10, 10]);
+        network.online[0] = false;
+        network.online[1] = false;
+
+        network.run(TIME_LIMIT);
+
+        // online validators wait for more prevotes forever
+        assert!(network.committed_block().is_none());
+        assert_eq!(network.nodes[2].round(), 0);
+        assert_eq!(network.nodes[2].step(), Step::Prevote);
+    }
+
+    #[test]
+    fn votes_are_weighted_by_stake() {
+        let mut network = Network::new(&[10, 10, 10, 100]);
+        network.online[3] = false;
+        network.run(TIME_LIMIT);
+        assert!(network.committed_block().is_none());
+
+        let mut network = Network::new(&[10, 10, 10, 100]);
+        for i in 0..3 {
+            network.online[i] = false;
+        }
+        network.run(TIME_LIMIT);
+        let block = network.committed_block().expect("block is committed");
+        assert_eq!(block.consensus.votes.len(), 1);
+        check_votes(&block, &network.validators).unwrap();
+    }
+
+    #[test]
+    fn check_votes_rejects_invalid_seals() {
+        let mut network = Network::new(&[10, 10, 10, 10]);
+        network.run(TIME_LIMIT);
+        let block = network.committed_block().unwrap();
+
+        let mut without_quorum = block.clone();
+        without_quorum.consensus.votes.truncate(2);
+        assert!(check_votes(&without_quorum, &network.validators).is_err());
+
+        let mut duplicated = without_quorum.clone();
+        duplicated
+            .consensus
+            .votes
+            .push(duplicated.consensus.votes[0]);
+        assert!(check_votes(&duplicated, &network.validators).is_err());
+
+        let mut other_block = block.clone();
+        other_block.block.header.time = Utc.timestamp(100, 0);
+        assert!(check_votes(&other_block, &network.validators).is_err());
+
+        let mut forged = block.clone();
+        forged.consensus.votes[0].validator = network.keys[3].public_key();
+        forged.consensus.votes[1].validator = network.keys[3].public_key();
+        assert!(check_votes(&forged, &network.validators).is_err());
+    }
+}
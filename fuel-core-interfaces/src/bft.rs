@@ -1,11 +1,16 @@
 use super::model::{
+    BlockHeight,
     FuelBlock,
+    FuelBlockConsensus,
+    FuelBlockHeader,
     SealedFuelBlock,
 };
+use crate::db::ChainDb;
 use anyhow::Result;
 use std::sync::Arc;
 use tokio::sync::oneshot;
 
+#[derive(Debug)]
 pub enum BftMpsc {
     CheckBlockConsensus {
         block: Arc<SealedFuelBlock>,
@@ -15,6 +20,16 @@ pub enum BftMpsc {
         block: Arc<FuelBlock>,
         ret: oneshot::Sender<Result<()>>,
     },
+    /// Create consensus for a block produced by this node, so it can be sealed.
+    SealBlock {
+        block: Arc<FuelBlock>,
+        ret: oneshot::Sender<Result<FuelBlockConsensus>>,
+    },
     Stop,
     Start,
 }
+
+pub trait BftDb: ChainDb {
+    /// Header of the block at `height`, if it exists.
+    fn block_header(&self, height: BlockHeight) -> Result<Option<FuelBlockHeader>>;
+}
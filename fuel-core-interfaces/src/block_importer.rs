@@ -1,10 +1,12 @@
 use super::model::{
-    ConsensusVote,
     FuelBlock,
+    FuelBlockConsensus,
     SealedFuelBlock,
 };
+use crate::db::ChainDb;
 use fuel_types::Bytes32;
 use std::sync::Arc;
+use tokio::sync::oneshot;
 
 #[derive(Clone, Debug)]
 pub enum ImportBlockBroadcast {
@@ -27,16 +29,32 @@ impl ImportBlockBroadcast {
     }
 }
 
+/// RPC commands that can be sent to the block importer through an MPSC channel.
+/// Result of the import is returned using `response` oneshot channel.
+#[derive(Debug)]
 pub enum ImportBlockMpsc {
+    /// Validate and commit block sealed by other node.
     ImportSealedFuelBlock {
         block: Arc<SealedFuelBlock>,
+        response: oneshot::Sender<anyhow::Result<()>>,
     },
+    /// Validate block created by this node and keep it pending until it is sealed.
     ImportFuelBlock {
         block: Arc<FuelBlock>,
+        response: oneshot::Sender<anyhow::Result<()>>,
     },
+    /// Validate block proposed by other node without committing or keeping it.
+    ValidateFuelBlock {
+        block: Arc<FuelBlock>,
+        response: oneshot::Sender<anyhow::Result<()>>,
+    },
+    /// Commit pending block with `block_id` together with its consensus.
     SealFuelBlock {
-        votes: Vec<ConsensusVote>,
+        consensus: FuelBlockConsensus,
         block_id: Bytes32,
+        response: oneshot::Sender<anyhow::Result<()>>,
     },
     Stop,
 }
+
+pub trait BlockImporterDb: ChainDb {}
@@ -1,14 +1,21 @@
-use crate::model::{
-    BlockHeight,
-    FuelBlock,
+use crate::{
+    db::ChainDb,
+    model::{
+        BlockHeight,
+        FuelBlock,
+    },
 };
 use tokio::sync::oneshot;
 
+#[derive(Debug)]
 pub enum BlockProducerMpsc {
     Produce {
         // add needed information for block to be produced
         height: BlockHeight,
-        response: oneshot::Sender<Box<FuelBlock>>,
+        response: oneshot::Sender<anyhow::Result<Box<FuelBlock>>>,
     },
     Stop,
 }
+
+/// Database of the block producer, which builds new blocks on top of the chain tip.
+pub trait BlockProducerDb: ChainDb {}
@@ -1,3 +1,8 @@
+use crate::model::{
+    BlockHeight,
+    DaBlockHeight,
+};
+use fuel_types::Bytes32;
 use fuel_vm::prelude::InterpreterError;
 use std::io::ErrorKind;
 use thiserror::Error;
@@ -13,6 +18,8 @@ pub enum Error {
     ChainUninitialized,
     #[error("Invalid database version")]
     InvalidDatabaseVersion,
+    #[error("Block {0} can't be rolled back, its reverse diff is missing")]
+    MissingReverseDiff(BlockHeight),
     #[error("error occurred in the underlying datastore `{0}`")]
     DatabaseError(Box<dyn std::error::Error + Send + Sync>),
 }
@@ -60,3 +67,15 @@ impl From<KvStoreError> for InterpreterError {
         InterpreterError::Io(std::io::Error::new(std::io::ErrorKind::Other, e))
     }
 }
+
+/// Tip of the chain, shared by the services that build on top of it.
+pub trait ChainDb: Send + Sync {
+    /// Height of the latest block in the chain.
+    fn current_height(&self) -> anyhow::Result<BlockHeight>;
+
+    /// Id of the block at `height`, if it exists.
+    fn block_id(&self, height: BlockHeight) -> anyhow::Result<Option<Bytes32>>;
+
+    /// Last finalized block height of the DA layer, as seen by the relayer.
+    fn finalized_da_height(&self) -> anyhow::Result<DaBlockHeight>;
+}
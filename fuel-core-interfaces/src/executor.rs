@@ -0,0 +1,29 @@
+use crate::model::FuelBlock;
+use async_trait::async_trait;
+
+/// Starting point for executing a block.
+/// In production mode, block fields like transaction commitments are set based on the executed txs.
+/// In validation mode, the processed block commitments are compared with the proposed block.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ExecutionMode {
+    Production,
+    Validation,
+}
+
+#[async_trait]
+pub trait Executor: Sync + Send {
+    /// Execute all transactions of the block and commit the resulting state changes.
+    async fn execute(
+        &self,
+        block: &mut FuelBlock,
+        mode: ExecutionMode,
+    ) -> anyhow::Result<()>;
+
+    /// Execute all transactions of the block inside of a storage transaction
+    /// that is dropped afterwards, so no state changes are committed.
+    async fn dry_run(
+        &self,
+        block: &mut FuelBlock,
+        mode: ExecutionMode,
+    ) -> anyhow::Result<()>;
+}
@@ -2,6 +2,7 @@ pub mod bft;
 pub mod block_importer;
 pub mod block_producer;
 pub mod db;
+pub mod executor;
 pub mod model;
 pub mod p2p;
 pub mod relayer;
@@ -26,7 +26,10 @@ pub use txpool::{
     ArcTx,
     TxInfo,
 };
-pub use vote::ConsensusVote;
+pub use vote::{
+    ConsensusVote,
+    VoteStep,
+};
 
 pub type DaBlockHeight = u64;
 pub type ValidatorStake = u64;
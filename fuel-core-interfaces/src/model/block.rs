@@ -1,12 +1,18 @@
 pub use super::BlockHeight;
-use super::ValidatorStake;
+use super::{
+    ConsensusVote,
+    ValidatorStake,
+};
 use chrono::{
     DateTime,
     TimeZone,
     Utc,
 };
 use core::ops::Deref;
-use fuel_crypto::Hasher;
+use fuel_crypto::{
+    Hasher,
+    Signature,
+};
 use fuel_tx::{
     Address,
     AssetId,
@@ -34,10 +40,17 @@ pub struct FuelBlockHeader {
     pub prev_root: Bytes32,
     /// Merkle root of transactions.
     pub transactions_root: Bytes32,
+    /// Merkle root of the ids of messages sent to the Da layer by the transactions.
+    pub message_outbox_root: Bytes32,
+    /// Sparse merkle root of the state and balance roots of the contracts touched by
+    /// the transactions, keyed by contract id.
+    pub contracts_root: Bytes32,
     /// The block producer time
     pub time: DateTime<Utc>,
     /// The block producer public key
     pub producer: Address,
+    /// Fees collected from the transactions of the block, minted to the producer.
+    pub coinbase: Word,
     /// Header Metadata
     #[cfg_attr(feature = "serde", serde(skip))]
     pub metadata: Option<HeaderMetadata>,
@@ -60,8 +73,11 @@ impl FuelBlockHeader {
         hasher.input(self.parent_hash.as_ref());
         hasher.input(self.prev_root.as_ref());
         hasher.input(self.transactions_root.as_ref());
+        hasher.input(self.message_outbox_root.as_ref());
+        hasher.input(self.contracts_root.as_ref());
         hasher.input(self.time.timestamp_millis().to_be_bytes());
         hasher.input(self.producer.as_ref());
+        hasher.input(self.coinbase.to_be_bytes());
         hasher.digest()
     }
 
@@ -83,7 +99,10 @@ impl Default for FuelBlockHeader {
             parent_hash: Bytes32::default(),
             prev_root: Bytes32::default(),
             transactions_root: Bytes32::default(),
+            message_outbox_root: Bytes32::default(),
+            contracts_root: Bytes32::default(),
             producer: Address::default(),
+            coinbase: Word::default(),
             metadata: None,
         }
     }
@@ -160,6 +179,10 @@ pub struct FuelBlockConsensus {
     pub required_stake: u64,
     /// Map of Validator consensus key and pair of stake and signature
     pub validators: HashMap<Address, (ValidatorStake, Address)>,
+    /// Signature of the block id by the proof-of-authority producer of the block
+    pub signature: Option<Signature>,
+    /// Precommit votes of the validators that sealed the block
+    pub votes: Vec<ConsensusVote>,
 }
 
 #[derive(Clone, Debug, Default)]
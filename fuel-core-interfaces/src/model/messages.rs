@@ -2,8 +2,10 @@ use super::BlockHeight;
 use crate::model::DaBlockHeight;
 use core::ops::Deref;
 use fuel_crypto::Hasher;
+use fuel_tx::Output;
 use fuel_types::{
     Address,
+    Bytes32,
     MessageId,
     Word,
 };
@@ -41,6 +43,33 @@ impl Message {
     }
 }
 
+/// Message sent from fuel to the Da layer, kept in the outbox so the bridge can prove it
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct OutboxMessage {
+    pub sender: Address,
+    pub recipient: Address,
+    pub nonce: Bytes32,
+    pub amount: Word,
+    pub data: Vec<u8>,
+    /// The fuel block that sent the message
+    pub block_height: BlockHeight,
+    /// Position of the message in the outbox of the block
+    pub index: u64,
+}
+
+impl OutboxMessage {
+    pub fn id(&self) -> MessageId {
+        Output::message_id(
+            &self.sender,
+            &self.recipient,
+            &self.nonce,
+            self.amount,
+            &self.data,
+        )
+    }
+}
+
 #[derive(Debug, Clone, Eq, PartialEq)]
 pub struct CheckedMessage {
     message: Message,
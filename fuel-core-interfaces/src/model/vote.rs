@@ -1,19 +1,91 @@
+use super::BlockHeight;
+use crate::signer::{
+    Signer,
+    SignerError,
+};
 use fuel_crypto::{
+    Message,
     PublicKey,
     Signature,
 };
-use fuel_types::Bytes32;
+use fuel_types::{
+    Address,
+    Bytes32,
+};
+
+/// Voting step of a BFT round.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum VoteStep {
+    #[default]
+    Prevote,
+    Precommit,
+}
 
-/// A vote from a validator.
-///
-/// This is a dummy placeholder for the Vote Struct in fuel-bft
+/// A signed vote of a validator for a block, or for no block, in one step of a BFT
+/// round.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ConsensusVote {
-    block_id: Bytes32,
-    height: u64,
-    round: u64,
-    signature: Signature,
-    // step: Step,
-    validator: PublicKey,
+    /// Id of the block voted for, `None` if the validator votes for no block.
+    pub block_id: Option<Bytes32>,
+    pub height: BlockHeight,
+    pub round: u64,
+    pub step: VoteStep,
+    /// Consensus key of the validator.
+    pub validator: PublicKey,
+    pub signature: Signature,
+}
+
+impl ConsensusVote {
+    /// Create an unsigned vote of the validator, see `ConsensusVote::sign`.
+    pub fn new(
+        validator: PublicKey,
+        height: BlockHeight,
+        round: u64,
+        step: VoteStep,
+        block_id: Option<Bytes32>,
+    ) -> Self {
+        Self {
+            block_id,
+            height,
+            round,
+            step,
+            validator,
+            signature: Signature::default(),
+        }
+    }
+
+    /// Sign the vote with the consensus key of the validator.
+    pub async fn sign(&mut self, signer: &dyn Signer) -> Result<(), SignerError> {
+        self.signature = signer.sign(&self.message()).await?;
+        Ok(())
+    }
+
+    /// Address of the validator, which is the hash of its consensus key.
+    pub fn validator_address(&self) -> Address {
+        Address::from(*self.validator.hash())
+    }
+
+    /// Check that the vote is signed by its validator.
+    pub fn verify(&self) -> bool {
+        self.signature
+            .verify(&self.validator, &self.message())
+            .is_ok()
+    }
+
+    /// Message signed by the validator.
+    pub fn message(&self) -> Message {
+        let mut bytes = self.height.to_bytes().to_vec();
+        bytes.extend(self.round.to_be_bytes());
+        bytes.push(self.step as u8);
+        match &self.block_id {
+            Some(block_id) => {
+                bytes.push(1);
+                bytes.extend(block_id.as_ref());
+            }
+            None => bytes.push(0),
+        }
+        Message::new(bytes)
+    }
 }
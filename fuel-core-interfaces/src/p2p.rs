@@ -3,10 +3,16 @@ use super::model::{
     FuelBlock,
     SealedFuelBlock,
 };
-use crate::model::ConsensusVote;
+use crate::{
+    db::ChainDb,
+    model::ConsensusVote,
+};
 use async_trait::async_trait;
 use fuel_tx::Transaction;
-use std::sync::Arc;
+use std::{
+    sync::Arc,
+    time::Duration,
+};
 use tokio::sync::oneshot;
 
 pub enum TransactionBroadcast {
@@ -15,6 +21,8 @@ pub enum TransactionBroadcast {
 
 pub enum ConsensusBroadcast {
     NewVote(ConsensusVote),
+    /// Block gossiped by a validator, validators vote on it if it is proposed.
+    NewBlock(FuelBlock),
 }
 
 pub enum BlockBroadcast {
@@ -22,11 +30,28 @@ pub enum BlockBroadcast {
     NewBlock(FuelBlock),
 }
 
+/// Peer this node is connected to.
+#[derive(Clone, Debug)]
+pub struct PeerInfo {
+    pub id: String,
+    pub addresses: Vec<String>,
+    pub client_version: Option<String>,
+    pub latest_ping: Option<Duration>,
+}
+
 pub enum P2pRequestEvent {
     RequestBlock {
         height: BlockHeight,
         response: oneshot::Sender<SealedFuelBlock>,
     },
+    /// Ask a random peer for the height of its chain.
+    RequestHeight {
+        response: oneshot::Sender<BlockHeight>,
+    },
+    /// Info about the connected peers.
+    GetPeers {
+        response: oneshot::Sender<Vec<PeerInfo>>,
+    },
     BroadcastNewTransaction {
         transaction: Arc<Transaction>,
     },
@@ -40,7 +65,7 @@ pub enum P2pRequestEvent {
 }
 
 #[async_trait]
-pub trait P2pDb: Send + Sync {
+pub trait P2pDb: ChainDb {
     async fn get_sealed_block(&self, height: BlockHeight)
         -> Option<Arc<SealedFuelBlock>>;
 }
@@ -1,30 +1,74 @@
 use async_trait::async_trait;
-use fuel_types::Bytes32;
+use fuel_crypto::{
+    Message,
+    PublicKey,
+    SecretKey,
+    Signature,
+};
+use fuel_types::Address;
+use std::fmt;
 use thiserror::Error;
 
-/// Dummy signer that will be removed in next pull request.
-/// TODO do not use.
+/// Signs messages with the secp256k1 key of the node. Block seals, consensus votes
+/// and the block commits of the relayer are all signed through it, so the key can
+/// be kept outside of the node.
 #[async_trait]
-pub trait Signer {
-    async fn sign(&self, hash: &Bytes32) -> Result<Bytes32, SignerError>;
+pub trait Signer: fmt::Debug + Send + Sync {
+    /// Public key of the signing key.
+    fn public_key(&self) -> PublicKey;
+
+    /// Address of the signing key, which is the hash of its public key.
+    fn address(&self) -> Address {
+        Address::from(*self.public_key().hash())
+    }
+
+    async fn sign(&self, message: &Message) -> Result<Signature, SignerError>;
 }
 
 #[derive(Error, Debug)]
 pub enum SignerError {
     #[error("Private key not loaded")]
     KeyNotLoaded,
+    #[error("Failed to load the keystore: {0}")]
+    Keystore(String),
+    #[error("Remote signer failed: {0}")]
+    Remote(String),
+    #[error("Failed to encode the message to sign: {0}")]
+    Encoding(String),
 }
 
-#[cfg(any(test, feature = "test-helpers"))]
-pub mod helpers {
-    use super::*;
-
-    pub struct DummySigner {}
+/// Signer with the secret key held in memory.
+#[derive(Clone)]
+pub struct InMemorySigner {
+    key: SecretKey,
+    public_key: PublicKey,
+}
 
-    #[async_trait]
-    impl Signer for DummySigner {
-        async fn sign(&self, hash: &Bytes32) -> Result<Bytes32, SignerError> {
-            Ok(*hash)
+impl InMemorySigner {
+    pub fn new(key: SecretKey) -> Self {
+        Self {
+            key,
+            public_key: key.public_key(),
         }
     }
 }
+
+// do not log the secret key
+impl fmt::Debug for InMemorySigner {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("InMemorySigner")
+            .field("public_key", &self.public_key)
+            .finish()
+    }
+}
+
+#[async_trait]
+impl Signer for InMemorySigner {
+    fn public_key(&self) -> PublicKey {
+        self.public_key
+    }
+
+    async fn sign(&self, message: &Message) -> Result<Signature, SignerError> {
+        Ok(Signature::sign(&self.key, message))
+    }
+}
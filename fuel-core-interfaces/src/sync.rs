@@ -1,12 +1,56 @@
+use crate::{
+    db::ChainDb,
+    model::BlockHeight,
+};
+use std::time::Duration;
 use tokio::sync::oneshot;
 
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum SyncStatus {
     Stopped,
+    /// Fetching blocks that peers have and this node is missing.
     InitialSync,
+    /// Local chain has caught up with the highest known peer height.
+    Synced,
+    /// No peer answered, there is no chain to catch up with.
+    NoPeers,
 }
 
+/// Progress of the local chain towards the chain of the other nodes.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SyncProgress {
+    pub status: SyncStatus,
+    /// Height of the local chain.
+    pub current_height: BlockHeight,
+    /// Highest block height known to exist on other nodes.
+    pub peer_height: BlockHeight,
+    /// Blocks imported per second since the node started to catch up.
+    pub blocks_per_second: f64,
+}
+
+impl SyncProgress {
+    /// Estimated time to catch up with the peers at the current import rate, `None` if
+    /// the node doesn't import blocks while behind.
+    pub fn time_to_sync(&self) -> Option<Duration> {
+        let missing =
+            u32::from(self.peer_height).saturating_sub(self.current_height.into());
+        if missing == 0 {
+            Some(Duration::ZERO)
+        } else if self.blocks_per_second > 0.0 {
+            Some(Duration::from_secs_f64(
+                missing as f64 / self.blocks_per_second,
+            ))
+        } else {
+            None
+        }
+    }
+}
+
+#[derive(Debug)]
 pub enum SyncMpsc {
-    Status { ret: oneshot::Sender<SyncStatus> },
+    Status { ret: oneshot::Sender<SyncProgress> },
     Start,
     Stop,
 }
+
+pub trait SyncDb: ChainDb {}
@@ -16,12 +16,17 @@ use derive_more::{
 };
 use fuel_storage::Storage;
 use fuel_tx::{
+    Address,
     ContractId,
     Transaction,
     TxId,
     UtxoId,
+    ValidationError,
+};
+use fuel_types::{
+    MessageId,
+    Word,
 };
-use fuel_types::MessageId;
 use fuel_vm::prelude::Contract;
 use std::sync::Arc;
 use thiserror::Error;
@@ -51,6 +56,17 @@ pub trait TxPoolDb:
     }
 }
 
+/// Durable record of the transactions inside the pool, used to restore the pool after a
+/// restart.
+pub trait TxPoolJournal: Send + Sync + std::fmt::Debug {
+    fn record(&self, tx: &Transaction) -> Result<(), KvStoreError>;
+
+    fn forget(&self, tx_id: &TxId) -> Result<(), KvStoreError>;
+
+    /// Every recorded transaction, in no particular order.
+    fn transactions(&self) -> Result<Vec<Transaction>, KvStoreError>;
+}
+
 /// RPC client for doing calls to the TxPool through an MPSC channel.
 #[derive(Clone, Deref, DerefMut)]
 pub struct Sender(mpsc::Sender<TxPoolMpsc>);
@@ -98,9 +114,18 @@ impl Sender {
         receiver.await.map_err(Into::into)
     }
 
-    pub async fn includable(&self) -> anyhow::Result<Vec<Arc<Transaction>>> {
+    pub async fn includable(
+        &self,
+        max_gas: Word,
+        max_size: usize,
+    ) -> anyhow::Result<Vec<Arc<Transaction>>> {
         let (response, receiver) = oneshot::channel();
-        self.send(TxPoolMpsc::Includable { response }).await?;
+        self.send(TxPoolMpsc::Includable {
+            max_gas,
+            max_size,
+            response,
+        })
+        .await?;
         receiver.await.map_err(Into::into)
     }
 
@@ -109,15 +134,24 @@ impl Sender {
         self.send(TxPoolMpsc::Remove { ids, response }).await?;
         receiver.await.map_err(Into::into)
     }
+
+    pub async fn gas_price(&self) -> anyhow::Result<Word> {
+        let (response, receiver) = oneshot::channel();
+        self.send(TxPoolMpsc::GasPrice { response }).await?;
+        receiver.await.map_err(Into::into)
+    }
 }
 
 /// RPC commands that can be sent to the TxPool through an MPSC channel.
 /// Responses are returned using `response` oneshot channel.
 #[derive(Debug)]
 pub enum TxPoolMpsc {
-    /// Return all sorted transactions that are includable in next block.
+    /// Return sorted transactions that are includable in next block, without exceeding
+    /// `max_gas` in total gas limit and `max_size` in total serialized size.
     /// This is going to be heavy operation, use it only when needed.
     Includable {
+        max_gas: Word,
+        max_size: usize,
         response: oneshot::Sender<Vec<Arc<Transaction>>>,
     },
     /// import list of transaction into txpool. All needed parents need to be known
@@ -150,6 +184,9 @@ pub enum TxPoolMpsc {
         ids: Vec<TxId>,
         response: oneshot::Sender<Vec<Arc<Transaction>>>,
     },
+    /// Gas price a new transaction should pay to be accepted into the txpool without being
+    /// the first one pushed out, based on the cheapest transaction and the fill level.
+    GasPrice { response: oneshot::Sender<Word> },
     /// Iterate over `hashes` and return all hashes that we don't have.
     /// Needed when we receive list of new hashed from peer with
     /// **BroadcastTransactionHashes**, so txpool needs to return
@@ -240,6 +277,30 @@ pub enum Error {
     #[error("Transaction is not inserted. Maximum depth of dependent transaction chain reached")]
     NotInsertedMaxDepth,
     // small todo for now it can pass but in future we should include better messages
+    #[error("Transaction is not inserted. Replacing tx {0:#x} requires a higher gas price by the minimum bump and a higher total fee")]
+    NotInsertedReplacementUnderpriced(TxId),
+    #[error("Transaction is not inserted. Owner {0:#x} has reached its limit of pooled transactions")]
+    NotInsertedOwnerLimitHit(Address),
+    #[error("Transaction is not inserted. Gas limit {0} is above the maximum gas per transaction")]
+    NotInsertedMaxGasLimit(Word),
+    #[error("Transaction is not inserted. Size of {0} bytes is above the maximum transaction size")]
+    NotInsertedMaxSize(usize),
+    #[error("Transaction is not inserted. Transaction is invalid: {0}")]
+    NotInsertedInvalid(ValidationError),
+    #[error("Transaction is not inserted. Input signature is invalid: {0}")]
+    NotInsertedInvalidSignature(ValidationError),
+    #[error("Transaction is not inserted. Predicate execution is disabled")]
+    NotInsertedPredicateExecutionDisabled,
+    #[error("Transaction is not inserted. Predicate verification failed")]
+    NotInsertedInvalidPredicate,
     #[error("Transaction removed.")]
     Removed,
+    #[error("Transaction removed. UTXO {0:#x} was spent by an imported block")]
+    RemovedCoinSpentByBlock(UtxoId),
+    #[error("Transaction removed. Contract {0:#x} was created by an imported block")]
+    RemovedContractCreatedByBlock(ContractId),
+    #[error("Transaction removed. Message {0:#x} was spent by an imported block")]
+    RemovedMessageSpentByBlock(MessageId),
+    #[error("Transaction removed. It stayed in the pool longer than the configured time to live")]
+    TTLReason,
 }
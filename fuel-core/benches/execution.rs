@@ -0,0 +1,120 @@
+//! Compares serial and parallel execution of a block of independent transactions.
+//!
+//! Run with `cargo bench -p fuel-core --bench execution`.
+
+use fuel_core::{
+    database::Database,
+    executor::Executor,
+    model::FuelBlock,
+    service::Config,
+};
+use fuel_core_interfaces::{
+    common::{
+        fuel_asm::Opcode,
+        fuel_crypto::SecretKey,
+        fuel_tx::{
+            Input,
+            Output,
+            TransactionBuilder,
+        },
+        fuel_vm::consts::{
+            REG_ONE,
+            REG_ZERO,
+        },
+    },
+    executor::ExecutionMode,
+    model::FuelBlockHeader,
+};
+use rand::{
+    rngs::StdRng,
+    Rng,
+    SeedableRng,
+};
+use std::time::{
+    Duration,
+    Instant,
+};
+
+const TRANSACTIONS: usize = 256;
+const LOOP_ITERATIONS: u16 = 2_000;
+const RUNS: usize = 10;
+
+/// Every transaction spends its own coin and counts to `LOOP_ITERATIONS` in the script.
+fn block() -> FuelBlock {
+    let mut rng = StdRng::seed_from_u64(2322);
+    let script = [
+        Opcode::ADDI(0x11, REG_ZERO, LOOP_ITERATIONS),
+        Opcode::ADDI(0x10, 0x10, 1),
+        Opcode::LT(0x12, 0x10, 0x11),
+        Opcode::JNZI(0x12, 1),
+        Opcode::RET(REG_ONE),
+    ];
+    let transactions = (0..TRANSACTIONS)
+        .map(|_| {
+            let secret = SecretKey::random(&mut rng);
+            TransactionBuilder::script(script.iter().copied().collect(), vec![])
+                .gas_limit(1_000_000)
+                .add_unsigned_coin_input(
+                    secret,
+                    rng.gen(),
+                    100,
+                    Default::default(),
+                    Default::default(),
+                    0,
+                )
+                .add_output(Output::change(
+                    Input::owner(&secret.public_key()),
+                    0,
+                    Default::default(),
+                ))
+                .finalize()
+        })
+        .collect();
+    FuelBlock {
+        header: FuelBlockHeader {
+            height: 1u32.into(),
+            ..Default::default()
+        },
+        transactions,
+    }
+}
+
+fn main() {
+    let runtime = tokio::runtime::Runtime::new().unwrap();
+    let block = block();
+
+    for parallel_execution in [false, true] {
+        let config = Config {
+            parallel_execution,
+            ..Config::local_node()
+        };
+        let mut timings: Vec<Duration> = (0..RUNS)
+            .map(|_| {
+                let executor = Executor {
+                    database: Database::default(),
+                    config: config.clone(),
+                };
+                let mut block = block.clone();
+                let start = Instant::now();
+                runtime
+                    .block_on(executor.execute(&mut block, ExecutionMode::Production))
+                    .unwrap();
+                assert_eq!(block.transactions.len(), TRANSACTIONS);
+                start.elapsed()
+            })
+            .collect();
+        timings.sort();
+
+        println!(
+            "{} execution of {} transactions: median {:?}, min {:?}",
+            if parallel_execution {
+                "parallel"
+            } else {
+                "serial"
+            },
+            TRANSACTIONS,
+            timings[RUNS / 2],
+            timings[0],
+        );
+    }
+}
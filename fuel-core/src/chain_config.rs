@@ -4,6 +4,7 @@ use crate::{
 };
 use fuel_core_interfaces::{
     common::{
+        fuel_crypto::PublicKey,
         fuel_tx::ConsensusParameters,
         fuel_types::{
             Address,
@@ -33,6 +34,7 @@ use serde_with::{
 };
 use serialization::{
     HexNumber,
+    HexPublicKey,
     HexType,
 };
 use std::{
@@ -132,11 +134,16 @@ impl FromStr for ChainConfig {
     }
 }
 
+#[serde_as]
 #[derive(Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
 pub enum ProductionStrategy {
     Instant,
     Manual,
-    RoundRobin,
+    /// Proof-of-authority where the producers take turns producing blocks by height
+    RoundRobin {
+        #[serde_as(as = "Vec<HexPublicKey>")]
+        producers: Vec<PublicKey>,
+    },
     ProofOfStake,
 }
 
@@ -251,6 +258,7 @@ mod tests {
     use super::*;
     use fuel_core_interfaces::common::{
         fuel_asm::Opcode,
+        fuel_crypto::SecretKey,
         fuel_vm::prelude::Contract,
     };
     use rand::{
@@ -324,6 +332,37 @@ mod tests {
         assert_eq!(config, deserialized_config);
     }
 
+    #[test]
+    fn snapshot_round_robin_block_production() {
+        let config = test_round_robin_config();
+        let json = serde_json::to_string_pretty(&config).unwrap();
+        insta::assert_snapshot!(json);
+    }
+
+    #[test]
+    fn can_roundtrip_round_robin_block_production() {
+        let config = test_round_robin_config();
+        let json = serde_json::to_string(&config).unwrap();
+        let deserialized_config: ChainConfig =
+            serde_json::from_str(json.as_str()).unwrap();
+        assert_eq!(config, deserialized_config);
+    }
+
+    #[test]
+    fn fails_to_load_producer_key_not_on_curve() {
+        let config = test_round_robin_config();
+        let json = serde_json::to_string(&config).unwrap();
+        let producer = match &config.block_production {
+            ProductionStrategy::RoundRobin { producers } => producers[0],
+            _ => unreachable!(),
+        };
+        let invalid = format!("0x{}", hex::encode([0xffu8; 64]));
+        let json = json.replace(&format!("0x{}", hex::encode(*producer)), &invalid);
+        assert!(json.contains(&invalid));
+
+        assert!(serde_json::from_str::<ChainConfig>(&json).is_err());
+    }
+
     #[test]
     fn snapshot_simple_contract() {
         let config = test_config_contract(false, false);
@@ -404,6 +443,17 @@ mod tests {
         assert_eq!(config, deserialized_config);
     }
 
+    fn test_round_robin_config() -> ChainConfig {
+        let mut rng = StdRng::seed_from_u64(1);
+        let producers = (0..2)
+            .map(|_| SecretKey::random(&mut rng).public_key())
+            .collect();
+        ChainConfig {
+            block_production: ProductionStrategy::RoundRobin { producers },
+            ..ChainConfig::local_testnet()
+        }
+    }
+
     fn test_config_contract(state: bool, balances: bool) -> ChainConfig {
         let mut rng = StdRng::seed_from_u64(1);
         let state = if state {
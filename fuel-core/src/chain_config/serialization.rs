@@ -1,8 +1,12 @@
 use crate::model::BlockHeight;
 use core::fmt;
-use fuel_core_interfaces::common::fuel_types::{
-    bytes::WORD_SIZE,
-    Word,
+use fuel_core_interfaces::common::{
+    fuel_crypto::PublicKey,
+    fuel_types::{
+        bytes::WORD_SIZE,
+        Bytes64,
+        Word,
+    },
 };
 use serde::{
     de::Error,
@@ -101,6 +105,34 @@ where
     }
 }
 
+/// fuel-crypto fails to parse valid public keys with `PublicKey::try_from`, so keys are
+/// checked to be on the curve with secp256k1 and then read from raw bytes.
+pub(crate) struct HexPublicKey;
+
+impl SerializeAs<PublicKey> for HexPublicKey {
+    fn serialize_as<S>(value: &PublicKey, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        HexType::serialize_as(value, serializer)
+    }
+}
+
+impl<'de> DeserializeAs<'de, PublicKey> for HexPublicKey {
+    fn deserialize_as<D>(deserializer: D) -> Result<PublicKey, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let bytes: Bytes64 = serde_hex::deserialize(deserializer)?;
+        // public keys are stored without the prefix of uncompressed keys
+        let mut uncompressed = [0x04; 65];
+        uncompressed[1..].copy_from_slice(bytes.as_ref());
+        secp256k1::PublicKey::from_slice(&uncompressed).map_err(D::Error::custom)?;
+        // Safety: the key is checked to be a valid point on the curve
+        Ok(unsafe { PublicKey::from_bytes_unchecked(*bytes) })
+    }
+}
+
 pub mod serde_hex {
     use core::fmt;
     use hex::{
@@ -12,7 +12,10 @@ lazy_static::lazy_static! {
     pub static ref DEFAULT_DB_PATH: PathBuf = dirs::home_dir().unwrap().join(".fuel").join("db");
 }
 
+pub mod rollback;
 pub mod run;
+#[cfg(unix)]
+pub mod signer;
 pub mod snapshot;
 
 #[derive(Parser, Debug)]
@@ -32,6 +35,9 @@ pub struct Opt {
 pub enum Fuel {
     Run(run::Command),
     Snapshot(snapshot::Command),
+    Rollback(rollback::Command),
+    #[cfg(unix)]
+    Signer(signer::Command),
 }
 
 pub const LOG_FILTER: &str = "RUST_LOG";
@@ -92,6 +98,9 @@ pub async fn run_cli() -> Result<()> {
         Ok(opt) => match opt.command {
             Fuel::Run(command) => run::exec(command).await,
             Fuel::Snapshot(command) => snapshot::exec(command).await,
+            Fuel::Rollback(command) => rollback::exec(command).await,
+            #[cfg(unix)]
+            Fuel::Signer(command) => signer::exec(command).await,
         },
         Err(e) => {
             // Prints the error and exits.
@@ -0,0 +1,48 @@
+use crate::cli::DEFAULT_DB_PATH;
+use clap::Parser;
+use std::path::PathBuf;
+
+/// Roll a stopped node back by undoing its most recent blocks
+#[derive(Debug, Clone, Parser)]
+pub struct Command {
+    #[clap(
+        name = "DB_PATH",
+        long = "db-path",
+        parse(from_os_str),
+        default_value = (*DEFAULT_DB_PATH).to_str().unwrap()
+    )]
+    pub database_path: PathBuf,
+
+    /// Number of blocks to undo
+    #[clap(long = "blocks")]
+    pub blocks: u32,
+}
+
+#[cfg(not(feature = "rocksdb"))]
+pub async fn exec(command: Command) -> anyhow::Result<()> {
+    Err(anyhow::anyhow!(
+        "Rocksdb must be enabled to use the database at {}",
+        command.database_path.display()
+    ))
+}
+
+#[cfg(feature = "rocksdb")]
+pub async fn exec(command: Command) -> anyhow::Result<()> {
+    use anyhow::Context;
+    use fuel_core::database::Database;
+    use tracing::info;
+
+    let path = command.database_path;
+    let db = Database::open(&path).context(format!(
+        "failed to open database at path {}",
+        path.display()
+    ))?;
+
+    let height = u32::from(db.get_block_height()?.unwrap_or_default());
+    let target = height.saturating_sub(command.blocks);
+    db.rollback_to(target.into())
+        .context(format!("failed to roll back to block {}", target))?;
+
+    info!("Rolled back from block {} to block {}", height, target);
+    Ok(())
+}
@@ -3,11 +3,15 @@ use crate::{
     FuelService,
 };
 use clap::Parser;
-use fuel_core::service::{
-    Config,
-    DbType,
-    VMConfig,
+use fuel_core::{
+    service::{
+        Config,
+        DbType,
+        VMConfig,
+    },
+    signer::SignerConfig,
 };
+use fuel_core_interfaces::common::fuel_crypto::SecretKey;
 use std::{
     env,
     io,
@@ -52,6 +56,11 @@ pub struct Command {
     #[clap(long = "manual_blocks_enabled")]
     pub manual_blocks_enabled: bool,
 
+    /// Execute the transactions of a block that don't share inputs or contracts in
+    /// parallel
+    #[clap(long = "parallel-execution")]
+    pub parallel_execution: bool,
+
     /// Enable logging of backtraces from vm errors
     #[clap(long = "vm-backtrace")]
     pub vm_backtrace: bool,
@@ -65,11 +74,64 @@ pub struct Command {
     #[clap(long = "min-gas-price", default_value = "0")]
     pub min_gas_price: u64,
 
+    /// How much higher, in percent, the gas price of a transaction has to be to replace a
+    /// pooled transaction spending the same inputs
+    #[clap(long = "replacement-bump-percent", default_value = "10")]
+    pub replacement_bump_percent: u64,
+
+    /// The maximum number of pooled transactions spending inputs of the same owner
+    #[clap(long = "max-tx-per-owner", default_value = "256")]
+    pub max_tx_per_owner: usize,
+
+    /// The maximum total size in bytes of pooled transactions spending inputs of the same owner
+    #[clap(long = "max-bytes-per-owner", default_value = "33554432")]
+    pub max_bytes_per_owner: usize,
+
+    /// Keep pending transactions in the database, so they are restored after a restart
+    #[clap(long = "txpool-journal")]
+    pub txpool_journal: bool,
+
     /// Enable predicate execution on transaction inputs.
     /// Will reject any transactions with predicates if set to false.
     #[clap(long = "predicates")]
     pub predicates: bool,
 
+    /// Secret key used to sign the blocks, consensus votes and block commits of
+    /// this node
+    #[clap(
+        long = "consensus-key",
+        parse(try_from_str),
+        conflicts_with = "keystore"
+    )]
+    pub consensus_key: Option<SecretKey>,
+
+    /// Encrypted JSON keystore file with the key used to sign the blocks, consensus
+    /// votes and block commits of this node
+    #[clap(
+        long = "keystore",
+        parse(from_os_str),
+        requires = "keystore-passphrase"
+    )]
+    pub keystore: Option<PathBuf>,
+
+    /// Passphrase to decrypt the keystore
+    #[clap(
+        long = "keystore-passphrase",
+        env = "FUEL_KEYSTORE_PASSPHRASE",
+        hide_env_values = true
+    )]
+    pub keystore_passphrase: Option<String>,
+
+    /// Unix socket of a remote signer process used to sign the blocks, consensus
+    /// votes and block commits of this node
+    #[cfg(unix)]
+    #[clap(
+        long = "remote-signer",
+        parse(from_os_str),
+        conflicts_with_all = &["consensus-key", "keystore"]
+    )]
+    pub remote_signer: Option<PathBuf>,
+
     #[cfg(feature = "relayer")]
     #[clap(flatten)]
     pub relayer_args: relayer::RelayerArgs,
@@ -89,9 +151,19 @@ impl Command {
             chain_config,
             vm_backtrace,
             manual_blocks_enabled,
+            parallel_execution,
             utxo_validation,
             min_gas_price,
+            replacement_bump_percent,
+            max_tx_per_owner,
+            max_bytes_per_owner,
+            txpool_journal,
             predicates,
+            consensus_key,
+            keystore,
+            keystore_passphrase,
+            #[cfg(unix)]
+            remote_signer,
             #[cfg(feature = "relayer")]
             relayer_args,
             #[cfg(feature = "p2p")]
@@ -100,6 +172,19 @@ impl Command {
 
         let addr = net::SocketAddr::new(ip, port);
 
+        #[cfg(unix)]
+        let remote_signer = remote_signer.map(SignerConfig::Remote);
+        #[cfg(not(unix))]
+        let remote_signer = None;
+        let signer = match (consensus_key, keystore) {
+            (Some(key), _) => SignerConfig::Key(key),
+            (None, Some(path)) => SignerConfig::Keystore {
+                path,
+                passphrase: keystore_passphrase.unwrap_or_default(),
+            },
+            (None, None) => remote_signer.unwrap_or_default(),
+        };
+
         #[cfg(feature = "p2p")]
         let p2p = {
             match p2p_args.into() {
@@ -115,13 +200,18 @@ impl Command {
             chain_conf: chain_config.as_str().parse()?,
             utxo_validation,
             manual_blocks_enabled,
+            parallel_execution,
             vm: VMConfig {
                 backtrace: vm_backtrace,
             },
             txpool: fuel_txpool::Config {
                 min_gas_price,
+                replacement_bump_percent,
+                max_tx_per_owner,
+                max_bytes_per_owner,
                 ..Default::default()
             },
+            txpool_journal,
             predicates,
             block_importer: Default::default(),
             block_producer: Default::default(),
@@ -129,6 +219,7 @@ impl Command {
             #[cfg(feature = "relayer")]
             relayer: relayer_args.into(),
             bft: Default::default(),
+            signer,
             sync: Default::default(),
             #[cfg(feature = "p2p")]
             p2p,
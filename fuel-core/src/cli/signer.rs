@@ -0,0 +1,49 @@
+use anyhow::Context;
+use clap::Parser;
+use fuel_core::signer::{
+    keystore,
+    remote,
+};
+use fuel_core_interfaces::signer::InMemorySigner;
+use std::{
+    path::PathBuf,
+    sync::Arc,
+};
+use tokio::net::UnixListener;
+use tracing::info;
+
+/// Run a remote signer for the nodes started with `--remote-signer`, so the key stays
+/// in this process.
+#[derive(Debug, Clone, Parser)]
+pub struct Command {
+    /// Unix socket to listen on for signing requests
+    #[clap(long = "socket", parse(from_os_str))]
+    pub socket: PathBuf,
+
+    /// Encrypted JSON keystore file with the signing key
+    #[clap(long = "keystore", parse(from_os_str))]
+    pub keystore: PathBuf,
+
+    /// Passphrase to decrypt the keystore
+    #[clap(
+        long = "keystore-passphrase",
+        env = "FUEL_KEYSTORE_PASSPHRASE",
+        hide_env_values = true
+    )]
+    pub keystore_passphrase: String,
+}
+
+pub async fn exec(command: Command) -> anyhow::Result<()> {
+    let key = keystore::decrypt(&command.keystore, &command.keystore_passphrase)?;
+    let signer = InMemorySigner::new(key);
+
+    let listener = UnixListener::bind(&command.socket)
+        .context(format!("failed to listen on {}", command.socket.display()))?;
+    info!(
+        "Remote signer of {} listening on {}",
+        key.public_key(),
+        command.socket.display()
+    );
+    remote::serve(listener, Arc::new(signer)).await;
+    Ok(())
+}
@@ -11,11 +11,15 @@ use crate::{
         DataSource,
         Error,
         IterDirection,
+        WriteOperation,
     },
 };
 use async_trait::async_trait;
 pub use fuel_core_interfaces::db::KvStoreError;
 use fuel_core_interfaces::{
+    bft::BftDb,
+    block_importer::BlockImporterDb,
+    block_producer::BlockProducerDb,
     common::{
         fuel_asm::Word,
         fuel_storage::Storage,
@@ -25,10 +29,12 @@ use fuel_core_interfaces::{
             InterpreterStorage,
         },
     },
+    db::ChainDb,
     model::{
         BlockHeight,
         ConsensusId,
         DaBlockHeight,
+        FuelBlockHeader,
         SealedFuelBlock,
         ValidatorId,
         ValidatorStake,
@@ -38,6 +44,7 @@ use fuel_core_interfaces::{
         RelayerDb,
         StakingDiff,
     },
+    sync::SyncDb,
     txpool::TxPoolDb,
 };
 use serde::{
@@ -66,15 +73,19 @@ pub mod balances;
 pub mod block;
 pub mod code_root;
 pub mod coin;
+pub mod contract_roots;
 pub mod contracts;
 pub mod delegates_index;
 pub mod message;
 pub mod metadata;
+pub mod outbox;
 mod receipts;
+pub mod reverse_diff;
 pub mod staking_diffs;
 pub mod state;
 pub mod transaction;
 pub mod transactional;
+mod txpool_journal;
 pub mod validator_set;
 
 pub mod columns {
@@ -105,10 +116,22 @@ pub mod columns {
     pub const DELEGATES_INDEX: u32 = 17;
     // (Owner, MessageId) => true
     pub const OWNED_MESSAGE_IDS: u32 = 18;
+    // block height -> merkle subtree roots of the block ids up to the height
+    pub const BLOCK_ROOTS: u32 = 19;
+    // MessageId -> message sent to the Da layer
+    pub const OUTBOX: u32 = 20;
+    // (block height, index) -> MessageId
+    pub const BLOCK_OUTBOX: u32 = 21;
+    // (block height, ContractId) -> roots of the contracts touched by the block
+    pub const BLOCK_CONTRACT_ROOTS: u32 = 22;
+    // block height -> changes undoing the block
+    pub const BLOCK_REVERSE_DIFFS: u32 = 23;
+    // TxId -> transaction pending in the txpool
+    pub const TXPOOL_JOURNAL: u32 = 24;
 
     // Number of columns
     #[cfg(feature = "rocksdb")]
-    pub const COLUMN_NUM: u32 = 19;
+    pub const COLUMN_NUM: u32 = 25;
 }
 
 #[derive(Clone, Debug)]
@@ -156,6 +179,43 @@ unsafe impl Send for Database {}
 unsafe impl Sync for Database {}
 
 impl TxPoolDb for Database {}
+
+impl ChainDb for Database {
+    fn current_height(&self) -> anyhow::Result<BlockHeight> {
+        Ok(self.get_block_height()?.unwrap_or_default())
+    }
+
+    fn block_id(&self, height: BlockHeight) -> anyhow::Result<Option<Bytes32>> {
+        self.get_block_id(height).map_err(Into::into)
+    }
+
+    fn finalized_da_height(&self) -> anyhow::Result<DaBlockHeight> {
+        Ok(self
+            .get(metadata::FINALIZED_DA_HEIGHT_KEY, METADATA)?
+            .unwrap_or_default())
+    }
+}
+
+impl BlockProducerDb for Database {}
+
+impl BlockImporterDb for Database {}
+
+impl BftDb for Database {
+    fn block_header(
+        &self,
+        height: BlockHeight,
+    ) -> anyhow::Result<Option<FuelBlockHeader>> {
+        let id = match self.get_block_id(height)? {
+            Some(id) => id,
+            None => return Ok(None),
+        };
+        let block = Storage::<Bytes32, FuelBlockDb>::get(self, &id)?;
+        Ok(block.map(|block| block.headers.clone()))
+    }
+}
+
+impl SyncDb for Database {}
+
 #[async_trait]
 impl P2pDb for Database {
     async fn get_sealed_block(
@@ -256,6 +316,11 @@ impl Database {
     pub fn transaction(&self) -> DatabaseTransaction {
         self.into()
     }
+
+    /// Apply changes taken from a [`DatabaseTransaction`] of another database.
+    pub fn batch_write(&self, changes: Vec<WriteOperation>) -> Result<(), Error> {
+        self.data.batch_write(&mut changes.into_iter())
+    }
 }
 
 impl AsRef<Database> for Database {
@@ -451,12 +516,8 @@ impl RelayerDb for Database {
     }
 
     async fn get_finalized_da_height(&self) -> DaBlockHeight {
-        match self.get(metadata::FINALIZED_DA_HEIGHT_KEY, METADATA) {
-            Ok(res) => {
-                return res.expect(
-                    "get_finalized_da_height value should be always present and set",
-                )
-            }
+        match ChainDb::finalized_da_height(self) {
+            Ok(height) => height,
             Err(err) => {
                 panic!("get_finalized_da_height database corruption, err:{:?}", err);
             }
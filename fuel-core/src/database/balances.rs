@@ -10,15 +10,13 @@ use crate::{
     },
 };
 use fuel_core_interfaces::common::{
+    fuel_merkle::sparse::in_memory::MerkleTree,
     fuel_storage::MerkleRoot,
-    fuel_vm::{
-        crypto,
-        prelude::{
-            AssetId,
-            ContractId,
-            MerkleStorage,
-            Word,
-        },
+    fuel_vm::prelude::{
+        AssetId,
+        ContractId,
+        MerkleStorage,
+        Word,
     },
 };
 use itertools::Itertools;
@@ -70,15 +68,15 @@ impl MerkleStorage<ContractId, AssetId, Word> for Database {
         )
         .try_collect()?;
 
-        let root = items
-            .iter()
-            .filter_map(|(key, value)| {
-                (&key[..parent.len()] == parent.as_ref()).then(|| (key, value))
-            })
-            .sorted_by_key(|t| t.0)
-            .map(|(_, value)| value.to_be_bytes());
+        // sparse merkle tree keyed by the asset id
+        let mut tree = MerkleTree::new();
+        for (key, value) in items {
+            let key =
+                AssetId::try_from(&key[parent.len()..]).map_err(|_| Error::Codec)?;
+            tree.update(&key, &value.to_be_bytes());
+        }
 
-        Ok(crypto::ephemeral_merkle_root(root).into())
+        Ok(tree.root())
     }
 }
 
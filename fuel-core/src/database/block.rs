@@ -3,6 +3,7 @@ use crate::{
         columns::{
             BLOCKS,
             BLOCK_IDS,
+            BLOCK_ROOTS,
         },
         Database,
         KvStoreError,
@@ -17,9 +18,14 @@ use crate::{
     },
 };
 use fuel_core_interfaces::common::{
+    fuel_crypto::Hasher,
     fuel_storage::Storage,
     fuel_tx::Bytes32,
 };
+use serde::{
+    Deserialize,
+    Serialize,
+};
 use std::{
     borrow::Cow,
     convert::{
@@ -37,6 +43,7 @@ impl Storage<Bytes32, FuelBlockDb> for Database {
         value: &FuelBlockDb,
     ) -> Result<Option<FuelBlockDb>, KvStoreError> {
         Database::insert(self, value.headers.height, BLOCK_IDS, *key)?;
+        self.insert_block_roots(value.headers.height, key)?;
         Database::insert(self, key.as_ref(), BLOCKS, value.clone()).map_err(Into::into)
     }
 
@@ -45,6 +52,8 @@ impl Storage<Bytes32, FuelBlockDb> for Database {
         if let Some(block) = &block {
             let _: Option<Bytes32> =
                 Database::remove(self, &block.headers.height.to_bytes(), BLOCK_IDS)?;
+            let _: Option<BlockRoots> =
+                Database::remove(self, &block.headers.height.to_bytes(), BLOCK_ROOTS)?;
         }
         Ok(block)
     }
@@ -98,4 +107,109 @@ impl Database {
                 ))
             })
     }
+
+    /// Root of the binary merkle tree of the ids of all blocks below `height`, which is
+    /// the `prev_root` of the block at `height`.
+    pub fn prev_root(&self, height: BlockHeight) -> Result<Bytes32, Error> {
+        let roots = match u32::from(height).checked_sub(1) {
+            Some(prev) => self.block_roots(prev.into())?,
+            None => BlockRoots::default(),
+        };
+        Ok(roots.root())
+    }
+
+    fn block_roots(&self, height: BlockHeight) -> Result<BlockRoots, Error> {
+        Ok(Database::get(self, &height.to_bytes()[..], BLOCK_ROOTS)?.unwrap_or_default())
+    }
+
+    // extend the tree of the previous block instead of rebuilding it from genesis
+    fn insert_block_roots(
+        &mut self,
+        height: BlockHeight,
+        id: &Bytes32,
+    ) -> Result<(), Error> {
+        let mut roots = match u32::from(height).checked_sub(1) {
+            Some(prev) => self.block_roots(prev.into())?,
+            None => BlockRoots::default(),
+        };
+        roots.push(id.as_ref());
+        let _: Option<BlockRoots> = Database::insert(self, height, BLOCK_ROOTS, roots)?;
+        Ok(())
+    }
+}
+
+/// Roots of the perfect subtrees of the block ids merkle tree with their heights, from
+/// the oldest (highest) subtree to the newest one. Pushing a leaf only touches the
+/// newest subtrees, so the tree of a new block is derived from its parent in
+/// `O(log n)`. Hashes are the same as in `fuel_merkle::binary`.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BlockRoots(Vec<(u32, Bytes32)>);
+
+impl BlockRoots {
+    pub fn push(&mut self, data: &[u8]) {
+        let mut subtree = (0, Hasher::default().chain([0u8]).chain(data).digest());
+        while let Some((height, root)) = self.0.last().copied() {
+            if height != subtree.0 {
+                break
+            }
+            self.0.pop();
+            subtree = (height + 1, node_sum(&root, &subtree.1));
+        }
+        self.0.push(subtree);
+    }
+
+    pub fn root(&self) -> Bytes32 {
+        let mut subtrees = self.0.iter().rev();
+        match subtrees.next() {
+            Some((_, newest)) => {
+                subtrees.fold(*newest, |root, (_, older)| node_sum(older, &root))
+            }
+            None => Hasher::default().digest(),
+        }
+    }
+}
+
+fn node_sum(lhs: &Bytes32, rhs: &Bytes32) -> Bytes32 {
+    Hasher::default()
+        .chain([1u8])
+        .chain(lhs)
+        .chain(rhs)
+        .digest()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fuel_core_interfaces::common::fuel_merkle::binary::in_memory::MerkleTree;
+
+    #[test]
+    fn block_roots_match_merkle_tree() {
+        let mut roots = BlockRoots::default();
+        let mut tree = MerkleTree::new();
+        assert_eq!(roots.root(), Bytes32::from(tree.root()));
+
+        for i in 0u8..20 {
+            roots.push(&[i; 32]);
+            tree.push(&[i; 32]);
+            assert_eq!(roots.root(), Bytes32::from(tree.root()));
+        }
+    }
+
+    #[test]
+    fn block_ids_root_follows_inserted_blocks() {
+        let mut db = Database::default();
+        let mut tree = MerkleTree::new();
+        for height in 0u32..5 {
+            let mut block = FuelBlockDb::default();
+            block.headers.height = height.into();
+            let id = Bytes32::from([height as u8 + 1; 32]);
+            Storage::<Bytes32, FuelBlockDb>::insert(&mut db, &id, &block).unwrap();
+            tree.push(id.as_ref());
+
+            assert_eq!(
+                db.prev_root((height + 1).into()).unwrap(),
+                Bytes32::from(tree.root())
+            );
+        }
+    }
 }
@@ -0,0 +1,336 @@
+use crate::{
+    database::{
+        columns::{
+            BLOCK_CONTRACT_ROOTS,
+            CONTRACTS_STATE,
+        },
+        Database,
+        KvStoreError,
+    },
+    model::FuelBlockDb,
+    state::{
+        Error,
+        IterDirection,
+    },
+};
+use fuel_core_interfaces::{
+    common::{
+        fuel_crypto::Hasher,
+        fuel_merkle::{
+            common::{
+                Msb,
+                LEAF,
+                NODE,
+            },
+            sparse::in_memory::MerkleTree,
+        },
+        fuel_storage::Storage,
+        fuel_types::{
+            AssetId,
+            Bytes32,
+            ContractId,
+            Word,
+        },
+        fuel_vm::prelude::MerkleStorage,
+    },
+    model::BlockHeight,
+};
+use itertools::Itertools;
+use serde::{
+    Deserialize,
+    Serialize,
+};
+use std::ops::Deref;
+
+/// Roots of the storage and balances of a contract.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ContractRoots {
+    pub state_root: Bytes32,
+    pub balance_root: Bytes32,
+}
+
+impl ContractRoots {
+    /// Data of the leaf of the contract in the contracts tree of a block.
+    fn leaf_data(&self) -> Vec<u8> {
+        [self.state_root.as_ref(), self.balance_root.as_ref()].concat()
+    }
+}
+
+/// Proof of the value of a storage slot of a contract against the contracts root of a
+/// block. Proof sets contain the side nodes from the root down to the leaf.
+#[derive(Clone, Debug)]
+pub struct StorageProof {
+    pub contract_id: ContractId,
+    pub key: Bytes32,
+    pub value: Bytes32,
+    /// Roots of the contract after the block.
+    pub roots: ContractRoots,
+    /// Proof of the slot in the state tree of the contract.
+    pub state_proof_set: Vec<Bytes32>,
+    /// Contracts root of the block.
+    pub contracts_root: Bytes32,
+    /// Proof of the roots of the contract in the contracts tree of the block.
+    pub contract_proof_set: Vec<Bytes32>,
+}
+
+impl StorageProof {
+    /// Verify both proofs, fails if the slot was changed after the block.
+    pub fn verify(&self) -> bool {
+        let slot_leaf = leaf_hash(&self.key, self.value.as_ref());
+        let contract_leaf =
+            leaf_hash(&(*self.contract_id).into(), &self.roots.leaf_data());
+
+        root_from_proof(&self.key, slot_leaf, &self.state_proof_set)
+            == self.roots.state_root
+            && root_from_proof(
+                &(*self.contract_id).into(),
+                contract_leaf,
+                &self.contract_proof_set,
+            ) == self.contracts_root
+    }
+}
+
+fn block_contract_roots_key(height: BlockHeight, contract_id: &ContractId) -> Vec<u8> {
+    height
+        .to_bytes()
+        .iter()
+        .chain(contract_id.as_ref().iter())
+        .copied()
+        .collect()
+}
+
+impl Database {
+    /// Current roots of the storage and balances of the contract.
+    pub fn contract_roots(
+        &mut self,
+        contract_id: &ContractId,
+    ) -> Result<ContractRoots, Error> {
+        Ok(ContractRoots {
+            state_root: MerkleStorage::<ContractId, Bytes32, Bytes32>::root(
+                self,
+                contract_id,
+            )?
+            .into(),
+            balance_root: MerkleStorage::<ContractId, AssetId, Word>::root(
+                self,
+                contract_id,
+            )?
+            .into(),
+        })
+    }
+
+    /// Record the roots of a contract touched by the block at `height`.
+    pub fn insert_block_contract_roots(
+        &self,
+        height: BlockHeight,
+        contract_id: &ContractId,
+        roots: &ContractRoots,
+    ) -> Result<(), Error> {
+        let _: Option<ContractRoots> = self.insert(
+            block_contract_roots_key(height, contract_id),
+            BLOCK_CONTRACT_ROOTS,
+            *roots,
+        )?;
+        Ok(())
+    }
+
+    /// Roots of the contracts touched by the block at `height`, ordered by contract id.
+    pub fn block_contract_roots(
+        &self,
+        height: BlockHeight,
+    ) -> Result<Vec<(ContractId, ContractRoots)>, Error> {
+        let prefix = height.to_bytes();
+        self.iter_all::<Vec<u8>, ContractRoots>(
+            BLOCK_CONTRACT_ROOTS,
+            Some(prefix.to_vec()),
+            None,
+            Some(IterDirection::Forward),
+        )
+        .map(|res| {
+            res.and_then(|(key, roots)| {
+                let contract_id = ContractId::try_from(&key[prefix.len()..])
+                    .map_err(|_| Error::Codec)?;
+                Ok((contract_id, roots))
+            })
+        })
+        .try_collect()
+    }
+
+    /// Proof of a storage slot of a contract touched by the block at `height`. The proof
+    /// is built from the state right after the block, the later blocks are rolled back on
+    /// a view of the database that is never committed.
+    pub fn storage_proof(
+        &self,
+        height: BlockHeight,
+        contract_id: &ContractId,
+        key: &Bytes32,
+    ) -> Result<Option<StorageProof>, Error> {
+        let contracts = self.block_contract_roots(height)?;
+        let roots = match contracts.iter().find(|(id, _)| id == contract_id) {
+            Some((_, roots)) => *roots,
+            None => return Ok(None),
+        };
+        let view = self.transaction();
+        view.rollback_to(height)?;
+        let value = match MerkleStorage::<ContractId, Bytes32, Bytes32>::get(
+            view.deref(),
+            contract_id,
+            key,
+        )? {
+            Some(value) => value.into_owned(),
+            None => return Ok(None),
+        };
+        let block_id = self.get_block_id(height)?.ok_or(KvStoreError::NotFound)?;
+        let contracts_root = Storage::<Bytes32, FuelBlockDb>::get(self, &block_id)?
+            .ok_or(KvStoreError::NotFound)?
+            .headers
+            .contracts_root;
+
+        let slots = view
+            .iter_all::<Vec<u8>, Bytes32>(
+                CONTRACTS_STATE,
+                Some(contract_id.as_ref().to_vec()),
+                None,
+                Some(IterDirection::Forward),
+            )
+            .map(|res| {
+                res.and_then(|(slot_key, value)| {
+                    let slot_key = Bytes32::try_from(&slot_key[contract_id.len()..])
+                        .map_err(|_| Error::Codec)?;
+                    Ok((slot_key, leaf_hash(&slot_key, value.as_ref())))
+                })
+            })
+            .collect::<Result<Vec<_>, Error>>()?;
+        let leaves = contracts
+            .iter()
+            .map(|(id, roots)| {
+                let id = (**id).into();
+                (id, leaf_hash(&id, &roots.leaf_data()))
+            })
+            .collect_vec();
+
+        Ok(Some(StorageProof {
+            contract_id: *contract_id,
+            key: *key,
+            value,
+            roots,
+            state_proof_set: proof_set(&slots, key),
+            contracts_root,
+            contract_proof_set: proof_set(&leaves, &(**contract_id).into()),
+        }))
+    }
+}
+
+/// Root of the contracts touched by a block, the leaves are keyed by contract id.
+pub fn contracts_root<'a>(
+    contracts: impl Iterator<Item = (&'a ContractId, &'a ContractRoots)>,
+) -> Bytes32 {
+    let mut tree = MerkleTree::new();
+    for (contract_id, roots) in contracts {
+        tree.update(contract_id, &roots.leaf_data());
+    }
+    tree.root().into()
+}
+
+// The sparse merkle tree of fuel-merkle doesn't build proofs, the helpers below follow
+// its layout: a subtree with a single leaf is represented by the leaf itself and empty
+// subtrees hash to zero.
+
+fn leaf_hash(key: &Bytes32, data: &[u8]) -> Bytes32 {
+    Hasher::default()
+        .chain([LEAF])
+        .chain(key)
+        .chain(Hasher::hash(data))
+        .digest()
+}
+
+fn node_hash(left: &Bytes32, right: &Bytes32) -> Bytes32 {
+    Hasher::default()
+        .chain([NODE])
+        .chain(left)
+        .chain(right)
+        .digest()
+}
+
+fn is_right(key: &Bytes32, depth: usize) -> bool {
+    key.get_bit_at_index_from_msb(depth) == Some(1)
+}
+
+/// Root of the subtree at `depth` with the `leaves`, given as keys with leaf hashes.
+fn subtree_root(leaves: &[(Bytes32, Bytes32)], depth: usize) -> Bytes32 {
+    match leaves {
+        [] => Bytes32::zeroed(),
+        [(_, leaf)] => *leaf,
+        _ => {
+            let (right, left): (Vec<_>, Vec<_>) =
+                leaves.iter().partition(|(key, _)| is_right(key, depth));
+            node_hash(
+                &subtree_root(&left, depth + 1),
+                &subtree_root(&right, depth + 1),
+            )
+        }
+    }
+}
+
+fn proof_set(leaves: &[(Bytes32, Bytes32)], key: &Bytes32) -> Vec<Bytes32> {
+    let mut proof_set = vec![];
+    let mut path = leaves.to_vec();
+    let mut depth = 0;
+    while path.len() > 1 {
+        let (on_path, side): (Vec<_>, Vec<_>) = path
+            .into_iter()
+            .partition(|(leaf_key, _)| is_right(leaf_key, depth) == is_right(key, depth));
+        proof_set.push(subtree_root(&side, depth + 1));
+        path = on_path;
+        depth += 1;
+    }
+    proof_set
+}
+
+fn root_from_proof(key: &Bytes32, leaf: Bytes32, proof_set: &[Bytes32]) -> Bytes32 {
+    proof_set
+        .iter()
+        .enumerate()
+        .rev()
+        .fold(leaf, |node, (depth, side)| {
+            if is_right(key, depth) {
+                node_hash(side, &node)
+            } else {
+                node_hash(&node, side)
+            }
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::{
+        rngs::StdRng,
+        Rng,
+        SeedableRng,
+    };
+
+    #[test]
+    fn helpers_match_sparse_merkle_tree() {
+        let mut rng = StdRng::seed_from_u64(2322);
+        for len in [1, 2, 7, 64] {
+            let slots: Vec<(Bytes32, Bytes32)> =
+                (0..len).map(|_| (rng.gen(), rng.gen())).collect();
+            let mut tree = MerkleTree::new();
+            for (key, value) in &slots {
+                tree.update(key, value.as_ref());
+            }
+            let leaves = slots
+                .iter()
+                .map(|(key, value)| (*key, leaf_hash(key, value.as_ref())))
+                .collect_vec();
+
+            let root: Bytes32 = tree.root().into();
+            assert_eq!(subtree_root(&leaves, 0), root);
+            for (key, leaf) in &leaves {
+                let proof_set = proof_set(&leaves, key);
+                assert_eq!(root_from_proof(key, *leaf, &proof_set), root);
+            }
+        }
+    }
+}
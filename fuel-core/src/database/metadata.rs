@@ -7,6 +7,7 @@ use crate::{
     service::config::Config,
     state::Error,
 };
+use fuel_core_interfaces::model::DaBlockHeight;
 
 pub(crate) const DB_VERSION_KEY: &[u8] = b"version";
 pub(crate) const CHAIN_NAME_KEY: &[u8] = b"chain_name";
@@ -44,9 +45,13 @@ impl Database {
 
         self.insert(DB_VERSION_KEY, METADATA, DB_VERSION)?;
         self.insert(CHAIN_HEIGHT_KEY, METADATA, chain_height)?;
-        self.insert(FINALIZED_DA_HEIGHT_KEY, METADATA, 0)?;
-        self.insert(VALIDATORS_DA_HEIGHT_KEY, METADATA, 0)?;
-        self.insert(LAST_COMMITTED_FINALIZED_BLOCK_HEIGHT_KEY, METADATA, 0)?;
+        self.insert(FINALIZED_DA_HEIGHT_KEY, METADATA, DaBlockHeight::default())?;
+        self.insert(VALIDATORS_DA_HEIGHT_KEY, METADATA, DaBlockHeight::default())?;
+        self.insert(
+            LAST_COMMITTED_FINALIZED_BLOCK_HEIGHT_KEY,
+            METADATA,
+            BlockHeight::default(),
+        )?;
         Ok(())
     }
 
@@ -0,0 +1,175 @@
+use crate::{
+    database::{
+        columns::{
+            BLOCK_OUTBOX,
+            OUTBOX,
+        },
+        Database,
+        KvStoreError,
+    },
+    state::Error,
+};
+use fuel_core_interfaces::{
+    common::{
+        fuel_merkle::binary::in_memory::MerkleTree,
+        fuel_storage::Storage,
+        fuel_types::{
+            Bytes32,
+            MessageId,
+        },
+    },
+    model::{
+        BlockHeight,
+        OutboxMessage,
+    },
+};
+use std::borrow::Cow;
+
+fn block_outbox_key(height: BlockHeight, index: u64) -> Vec<u8> {
+    height
+        .to_bytes()
+        .iter()
+        .chain(index.to_be_bytes().iter())
+        .copied()
+        .collect()
+}
+
+impl Storage<MessageId, OutboxMessage> for Database {
+    type Error = KvStoreError;
+
+    fn insert(
+        &mut self,
+        key: &MessageId,
+        value: &OutboxMessage,
+    ) -> Result<Option<OutboxMessage>, KvStoreError> {
+        // insert primary record
+        let result = Database::insert(self, key.as_ref(), OUTBOX, value.clone())?;
+
+        // insert secondary record by block
+        Database::insert(
+            self,
+            block_outbox_key(value.block_height, value.index),
+            BLOCK_OUTBOX,
+            *key,
+        )?;
+
+        Ok(result)
+    }
+
+    fn remove(&mut self, key: &MessageId) -> Result<Option<OutboxMessage>, KvStoreError> {
+        let result: Option<OutboxMessage> = Database::remove(self, key.as_ref(), OUTBOX)?;
+
+        if let Some(message) = &result {
+            Database::remove::<MessageId>(
+                self,
+                &block_outbox_key(message.block_height, message.index),
+                BLOCK_OUTBOX,
+            )?;
+        }
+
+        Ok(result)
+    }
+
+    fn get(&self, key: &MessageId) -> Result<Option<Cow<'_, OutboxMessage>>, KvStoreError> {
+        Database::get(self, key.as_ref(), OUTBOX).map_err(Into::into)
+    }
+
+    fn contains_key(&self, key: &MessageId) -> Result<bool, KvStoreError> {
+        Database::exists(self, key.as_ref(), OUTBOX).map_err(Into::into)
+    }
+}
+
+/// Merkle proof that a message is part of the outbox of its block.
+#[derive(Clone, Debug)]
+pub struct MessageProof {
+    pub message: OutboxMessage,
+    /// Message outbox root of the block.
+    pub root: Bytes32,
+    /// Hashes from the leaf of the message up to the root.
+    pub proof_set: Vec<Bytes32>,
+}
+
+impl Database {
+    /// Ids of the messages sent by the block at `height`, in the order of the outbox.
+    pub fn block_outbox(
+        &self,
+        height: BlockHeight,
+    ) -> impl Iterator<Item = Result<MessageId, Error>> + '_ {
+        self.iter_all::<Vec<u8>, MessageId>(
+            BLOCK_OUTBOX,
+            Some(height.to_bytes().to_vec()),
+            None,
+            None,
+        )
+        .map(|res| res.map(|(_, id)| id))
+    }
+
+    pub fn message_proof(
+        &self,
+        message_id: &MessageId,
+    ) -> Result<Option<MessageProof>, Error> {
+        let message = match Storage::<MessageId, OutboxMessage>::get(self, message_id)? {
+            Some(message) => message.into_owned(),
+            None => return Ok(None),
+        };
+
+        let mut tree = MerkleTree::new();
+        for id in self.block_outbox(message.block_height) {
+            tree.push(id?.as_ref());
+        }
+        let (root, proof_set) = tree.prove(message.index).ok_or_else(|| {
+            Error::DatabaseError("outbox of the block is corrupted".into())
+        })?;
+
+        Ok(Some(MessageProof {
+            message,
+            root: root.into(),
+            proof_set: proof_set.into_iter().map(Into::into).collect(),
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fuel_core_interfaces::common::fuel_types::Address;
+
+    fn message(height: u32, index: u64) -> OutboxMessage {
+        OutboxMessage {
+            sender: Address::new([1; 32]),
+            recipient: Address::new([2; 32]),
+            nonce: Bytes32::new([index as u8; 32]),
+            amount: 10,
+            data: vec![],
+            block_height: height.into(),
+            index,
+        }
+    }
+
+    #[test]
+    fn proves_message_in_block_outbox() {
+        let mut db = Database::default();
+        let messages = (0..3).map(|index| message(1, index)).collect::<Vec<_>>();
+        let mut tree = MerkleTree::new();
+        for message in &messages {
+            Storage::<MessageId, OutboxMessage>::insert(&mut db, &message.id(), message)
+                .unwrap();
+            tree.push(message.id().as_ref());
+        }
+        // messages of other blocks are not part of the proof
+        let other = message(2, 0);
+        Storage::<MessageId, OutboxMessage>::insert(&mut db, &other.id(), &other)
+            .unwrap();
+
+        let proof = db.message_proof(&messages[1].id()).unwrap().unwrap();
+
+        let (root, proof_set) = tree.prove(1).unwrap();
+        assert_eq!(proof.message, messages[1]);
+        assert_eq!(proof.root, Bytes32::from(root));
+        assert_eq!(
+            proof.proof_set,
+            proof_set.into_iter().map(Bytes32::from).collect::<Vec<_>>()
+        );
+        assert!(db.message_proof(&MessageId::zeroed()).unwrap().is_none());
+    }
+}
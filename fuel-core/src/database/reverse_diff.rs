@@ -0,0 +1,232 @@
+use crate::{
+    database::{
+        columns::BLOCK_REVERSE_DIFFS,
+        transactional::DatabaseTransaction,
+        Database,
+    },
+    model::BlockHeight,
+    state::{
+        ColumnId,
+        Error,
+        WriteOperation,
+    },
+};
+use serde::{
+    Deserialize,
+    Serialize,
+};
+
+/// Previous value of a key written by a block, `None` if the key didn't exist.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+struct ReverseChange {
+    column: ColumnId,
+    key: Vec<u8>,
+    previous: Option<Vec<u8>>,
+}
+
+impl Database {
+    /// Record how to undo the block at `height`, whose changes are still pending in
+    /// `block_transaction`, a transaction on top of this database. The reverse diff is
+    /// written to `block_transaction` so it's committed along with the block.
+    pub fn record_reverse_diff(
+        &self,
+        height: BlockHeight,
+        block_transaction: &DatabaseTransaction,
+    ) -> Result<(), Error> {
+        let diff = block_transaction
+            .changes()
+            .into_iter()
+            .map(|change| {
+                let (key, column) = match change {
+                    WriteOperation::Insert(key, column, _)
+                    | WriteOperation::Remove(key, column) => (key, column),
+                };
+                let previous = self.data.get(&key, column)?;
+                Ok(ReverseChange {
+                    column,
+                    key,
+                    previous,
+                })
+            })
+            .collect::<Result<Vec<_>, Error>>()?;
+        let _: Option<Vec<ReverseChange>> =
+            Database::insert(block_transaction, height, BLOCK_REVERSE_DIFFS, diff)?;
+        Ok(())
+    }
+
+    /// Undo the blocks above `height`, restoring the state as it was right after the block
+    /// at `height` was committed. Nothing is changed if one of the blocks can't be undone.
+    pub fn rollback_to(&self, height: BlockHeight) -> Result<(), Error> {
+        let current = self.get_block_height()?.unwrap_or_default();
+        let transaction = self.transaction();
+        // newest first, every diff restores the state its block was executed on
+        for block in (u32::from(height) + 1..=current.into()).rev() {
+            let block = BlockHeight::from(block);
+            let diff: Vec<ReverseChange> =
+                Database::remove(&transaction, &block.to_bytes(), BLOCK_REVERSE_DIFFS)?
+                    .ok_or(Error::MissingReverseDiff(block))?;
+            transaction.batch_write(
+                diff.into_iter()
+                    .map(|change| match change.previous {
+                        Some(value) => {
+                            WriteOperation::Insert(change.key, change.column, value)
+                        }
+                        None => WriteOperation::Remove(change.key, change.column),
+                    })
+                    .collect(),
+            )?;
+        }
+        transaction.commit()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        executor::Executor,
+        model::{
+            FuelBlock,
+            FuelBlockHeader,
+        },
+        service::Config,
+        state::IterDirection,
+    };
+    use fuel_core_interfaces::{
+        common::{
+            fuel_asm::Opcode,
+            fuel_crypto::SecretKey,
+            fuel_tx::{
+                Input,
+                Output,
+                Transaction,
+                TransactionBuilder,
+                UtxoId,
+            },
+            fuel_vm::consts::REG_ONE,
+        },
+        executor::ExecutionMode,
+    };
+    use rand::{
+        rngs::StdRng,
+        Rng,
+        SeedableRng,
+    };
+
+    /// Every key and value of the database, except for the reverse diffs.
+    fn dump(db: &Database) -> Vec<(ColumnId, Vec<u8>, Vec<u8>)> {
+        (0..BLOCK_REVERSE_DIFFS)
+            .flat_map(|column| {
+                db.data
+                    .iter_all(column, None, None, IterDirection::Forward)
+                    .map(move |entry| {
+                        let (key, value) = entry.unwrap();
+                        (column, key, value)
+                    })
+            })
+            .collect()
+    }
+
+    fn transfer(rng: &mut StdRng, utxo_id: UtxoId) -> Transaction {
+        let secret = SecretKey::random(rng);
+        TransactionBuilder::script(
+            vec![Opcode::RET(REG_ONE)].into_iter().collect(),
+            vec![],
+        )
+        .gas_limit(100)
+        .add_unsigned_coin_input(
+            secret,
+            utxo_id,
+            100,
+            Default::default(),
+            Default::default(),
+            0,
+        )
+        .add_output(Output::coin(rng.gen(), 50, Default::default()))
+        .add_output(Output::change(
+            Input::owner(&secret.public_key()),
+            0,
+            Default::default(),
+        ))
+        .finalize()
+    }
+
+    async fn execute(executor: &Executor, height: u32, transactions: Vec<Transaction>) {
+        let parent_hash = executor
+            .database
+            .get_block_id((height - 1).into())
+            .unwrap()
+            .unwrap_or_default();
+        let mut block = FuelBlock {
+            header: FuelBlockHeader {
+                height: height.into(),
+                parent_hash,
+                ..Default::default()
+            },
+            transactions,
+        };
+        executor
+            .execute(&mut block, ExecutionMode::Production)
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn rollback_restores_state_exactly() {
+        let mut rng = StdRng::seed_from_u64(2322);
+        let executor = Executor {
+            database: Default::default(),
+            config: Config::local_node(),
+        };
+
+        let utxo_id = rng.gen();
+        let first = transfer(&mut rng, utxo_id);
+        let first_output = UtxoId::new(first.id(), 0);
+        execute(&executor, 1, vec![first]).await;
+        let state = dump(&executor.database);
+
+        // spend the output of the first block, and a coin that didn't exist
+        let utxo_id = rng.gen();
+        let other = transfer(&mut rng, utxo_id);
+        execute(&executor, 2, vec![transfer(&mut rng, first_output), other]).await;
+        let utxo_id = rng.gen();
+        let third = transfer(&mut rng, utxo_id);
+        execute(&executor, 3, vec![third]).await;
+        assert_ne!(dump(&executor.database), state);
+
+        executor.database.rollback_to(1u32.into()).unwrap();
+        assert_eq!(dump(&executor.database), state);
+        assert_eq!(
+            executor.database.get_block_height().unwrap(),
+            Some(1u32.into())
+        );
+    }
+
+    #[tokio::test]
+    async fn rollback_without_diff_changes_nothing() {
+        let mut rng = StdRng::seed_from_u64(2322);
+        let executor = Executor {
+            database: Default::default(),
+            config: Config::local_node(),
+        };
+        for height in 1..=2 {
+            let utxo_id = rng.gen();
+            let tx = transfer(&mut rng, utxo_id);
+            execute(&executor, height, vec![tx]).await;
+        }
+        // lose the diff of the first block
+        let _: Option<Vec<ReverseChange>> = Database::remove(
+            &executor.database,
+            &BlockHeight::from(1u32).to_bytes(),
+            BLOCK_REVERSE_DIFFS,
+        )
+        .unwrap();
+        let state = dump(&executor.database);
+
+        let result = executor.database.rollback_to(0u32.into());
+        assert!(
+            matches!(result, Err(Error::MissingReverseDiff(height)) if height == 1u32.into())
+        );
+        assert_eq!(dump(&executor.database), state);
+    }
+}
@@ -9,9 +9,9 @@ use crate::{
         MultiKey,
     },
 };
-use fuel_core_interfaces::common::fuel_vm::{
-    crypto,
-    prelude::{
+use fuel_core_interfaces::common::{
+    fuel_merkle::sparse::in_memory::MerkleTree,
+    fuel_vm::prelude::{
         Bytes32,
         ContractId,
         MerkleRoot,
@@ -69,15 +69,15 @@ impl MerkleStorage<ContractId, Bytes32, Bytes32> for Database {
         )
         .try_collect()?;
 
-        let root = items
-            .iter()
-            .filter_map(|(key, value)| {
-                (&key[..parent.len()] == parent.as_ref()).then(|| (key, value))
-            })
-            .sorted_by_key(|t| t.0)
-            .map(|(_, value)| value);
+        // sparse merkle tree keyed by the storage slot
+        let mut tree = MerkleTree::new();
+        for (key, value) in items {
+            let key =
+                Bytes32::try_from(&key[parent.len()..]).map_err(|_| Error::Codec)?;
+            tree.update(&key, value.as_ref());
+        }
 
-        Ok(crypto::ephemeral_merkle_root(root).into())
+        Ok(tree.root())
     }
 }
 
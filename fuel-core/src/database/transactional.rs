@@ -1,6 +1,9 @@
 use crate::{
     database::Database,
-    state::in_memory::transaction::MemoryTransactionView,
+    state::{
+        in_memory::transaction::MemoryTransactionView,
+        WriteOperation,
+    },
 };
 use std::{
     fmt::Debug,
@@ -52,6 +55,12 @@ impl Default for DatabaseTransaction {
 }
 
 impl DatabaseTransaction {
+    /// Changes made in this transaction, they can be applied to another database with
+    /// [`Database::batch_write`].
+    pub fn changes(&self) -> Vec<WriteOperation> {
+        self.changes.changes()
+    }
+
     /// Commit all the changes in this transaction to the data source
     pub fn commit(self) -> crate::state::Result<()> {
         // TODO: should commit be fallible if this api is meant to be atomic?
@@ -0,0 +1,32 @@
+use crate::database::{
+    columns::TXPOOL_JOURNAL,
+    Database,
+    KvStoreError,
+};
+use fuel_core_interfaces::{
+    common::fuel_tx::{
+        Transaction,
+        TxId,
+    },
+    txpool::TxPoolJournal,
+};
+
+impl TxPoolJournal for Database {
+    fn record(&self, tx: &Transaction) -> Result<(), KvStoreError> {
+        let _: Option<Transaction> =
+            Database::insert(self, tx.id().as_ref(), TXPOOL_JOURNAL, tx.clone())?;
+        Ok(())
+    }
+
+    fn forget(&self, tx_id: &TxId) -> Result<(), KvStoreError> {
+        let _: Option<Transaction> =
+            Database::remove(self, tx_id.as_ref(), TXPOOL_JOURNAL)?;
+        Ok(())
+    }
+
+    fn transactions(&self) -> Result<Vec<Transaction>, KvStoreError> {
+        self.iter_all::<Vec<u8>, Transaction>(TXPOOL_JOURNAL, None, None, None)
+            .map(|entry| entry.map(|(_, tx)| tx).map_err(Into::into))
+            .collect()
+    }
+}
@@ -1,5 +1,6 @@
 use crate::{
     database::{
+        contract_roots,
         transaction::TransactionIndex,
         Database,
         KvStoreError,
@@ -12,8 +13,10 @@ use crate::{
         FuelBlockDb,
     },
     service::Config,
+    state::WriteOperation,
     tx_pool::TransactionStatus,
 };
+use async_trait::async_trait;
 use chrono::Utc;
 use fuel_core_interfaces::{
     common::{
@@ -48,20 +51,29 @@ use fuel_core_interfaces::{
             },
         },
     },
+    executor::Executor as ExecutorTrait,
     model::{
         FuelBlockHeader,
         Message,
+        OutboxMessage,
     },
 };
 use std::{
+    collections::{
+        btree_map::Entry,
+        BTreeMap,
+    },
     error::Error as StdError,
+    num::NonZeroUsize,
     ops::{
         Deref,
         DerefMut,
     },
     sync::Arc,
+    thread,
 };
 use thiserror::Error;
+use tokio::task;
 use tracing::{
     debug,
     warn,
@@ -71,19 +83,75 @@ use tracing::{
 /// the transactions contained in the block and persist changes to the underlying database as needed.
 /// In production mode, block fields like transaction commitments are set based on the executed txs.
 /// In validation mode, the processed block commitments are compared with the proposed block.
+pub use fuel_core_interfaces::executor::ExecutionMode;
 
-#[derive(Debug, Clone, PartialEq, Eq)]
-pub enum ExecutionMode {
-    Production,
-    #[allow(dead_code)]
-    Validation,
-}
+mod parallel;
+pub mod trace;
 
 pub struct Executor {
     pub database: Database,
     pub config: Config,
 }
 
+/// Outcome of a transaction included into the block.
+struct ExecutedTransaction {
+    /// Canonical serialization of the transaction after execution.
+    tx_bytes: Vec<u8>,
+    fee: Word,
+    status: TransactionStatus,
+    /// Messages sent by the transaction, put into the outbox once their index in the
+    /// block is known.
+    outbox: Vec<(MessageId, OutboxMessage)>,
+    /// Owners of the inputs and outputs, indexed once the position of the transaction in
+    /// the block is known.
+    owners: Vec<Address>,
+    /// Changes of the transaction, if it was executed on another view than the block.
+    changes: Vec<WriteOperation>,
+}
+
+#[async_trait]
+impl ExecutorTrait for Executor {
+    async fn execute(
+        &self,
+        block: &mut FuelBlock,
+        mode: ExecutionMode,
+    ) -> anyhow::Result<()> {
+        Executor::execute(self, block, mode)
+            .await
+            .map_err(Into::into)
+    }
+
+    async fn dry_run(
+        &self,
+        block: &mut FuelBlock,
+        mode: ExecutionMode,
+    ) -> anyhow::Result<()> {
+        // execute on top of a storage transaction that is dropped without committing
+        let transaction = self.database.transaction();
+        let executor = Executor {
+            database: transaction.deref().clone(),
+            config: self.config.clone(),
+        };
+        let tx_ids = block
+            .transactions
+            .iter()
+            .map(|tx| tx.id())
+            .collect::<Vec<_>>();
+        Executor::execute(&executor, block, mode).await?;
+
+        // the transactions dropped by the producer stay rejected, even though the block
+        // itself is discarded
+        for tx_id in tx_ids {
+            if let Some(status @ TransactionStatus::Rejected { .. }) =
+                executor.database.get_tx_status(&tx_id)?
+            {
+                self.database.update_tx_status(&tx_id, status)?;
+            }
+        }
+        Ok(())
+    }
+}
+
 impl Executor {
     #[tracing::instrument(skip(self))]
     pub async fn submit_txs(&self, txs: Vec<Arc<Transaction>>) -> Result<(), Error> {
@@ -137,180 +205,87 @@ impl Executor {
         )?;
 
         let mut txs_merkle = MerkleTree::new();
+        let mut outbox = vec![];
         let mut tx_status = vec![];
         let mut coinbase = 0u64;
 
-        for (idx, tx) in block.transactions.iter_mut().enumerate() {
-            let tx_id = tx.id();
-
-            // Throw a clear error if the transaction id is a duplicate
-            if Storage::<Bytes32, Transaction>::contains_key(
-                block_db_transaction.deref_mut(),
-                &tx_id,
-            )? {
-                return Err(Error::TransactionIdCollision(tx_id))
-            }
-
-            self.compute_contract_input_utxo_ids(
-                tx,
+        let results = if self.config.parallel_execution {
+            self.execute_transactions_parallel(
+                &mut block.transactions,
+                &block.header,
+                &mode,
+                &block_db_transaction,
+            )
+        } else {
+            self.execute_transactions(
+                block.transactions.iter_mut().enumerate().collect(),
+                &block.header,
                 &mode,
-                block_db_transaction.deref(),
-            )?;
-
-            let checked_tx = CheckedTransaction::check_unsigned(
-                tx.clone(),
-                block.header.height.into(),
-                &self.config.chain_conf.transaction_parameters,
-            )?;
-            let min_fee = checked_tx.min_fee();
-            let max_fee = checked_tx.max_fee();
-
-            self.verify_tx_predicates(&checked_tx)?;
-
-            if self.config.utxo_validation {
-                // validate transaction has at least one coin
-                self.verify_tx_has_at_least_one_coin_or_message(tx)?;
-                // validate utxos exist and maturity is properly set
-                self.verify_input_state(
-                    block_db_transaction.deref(),
-                    tx,
-                    block.header.height,
-                    block.header.number,
-                )?;
-                // validate transaction signature
-                tx.validate_input_signature()
-                    .map_err(TransactionValidityError::from)?;
-            }
-
-            // index owners of inputs and outputs with tx-id, regardless of validity (hence block_tx instead of tx_db)
-            self.persist_owners_index(
-                block.header.height,
-                tx,
-                &tx_id,
-                idx,
                 block_db_transaction.deref_mut(),
-            )?;
-
-            // execute transaction
-            // setup database view that only lives for the duration of vm execution
-            let mut sub_block_db_commit = block_db_transaction.transaction();
-            let sub_db_view = sub_block_db_commit.deref_mut();
-            // execution vm
-            let mut vm = Interpreter::with_storage(
-                sub_db_view.clone(),
-                self.config.chain_conf.transaction_parameters,
-            );
-            let vm_result = vm
-                .transact(checked_tx)
-                .map_err(|error| Error::VmExecution {
-                    error,
-                    transaction_id: tx_id,
-                })?
-                .into_owned();
-
-            // only commit state changes if execution was a success
-            if !vm_result.should_revert() {
-                sub_block_db_commit.commit()?;
-            }
+                false,
+            )
+        };
 
-            // update block commitment
-            let tx_fee = self.total_fee_paid(
-                min_fee,
-                max_fee,
-                tx.gas_price(),
-                vm_result.receipts(),
-            )?;
-            coinbase = coinbase.checked_add(tx_fee).ok_or(Error::FeeOverflow)?;
-
-            // include the canonical serialization of the malleated tx into the commitment,
-            // including all witness data.
-            //
-            // TODO: reference the bytes directly from VM memory to save serialization. This isn't
-            //       possible atm because the change output values are set on the tx instance in the vm
-            //       and not also on the in-memory representation of the tx.
-            let tx_bytes = vm_result.tx().clone().to_bytes();
-            txs_merkle.push(&tx_bytes);
-
-            match mode {
-                ExecutionMode::Validation => {
-                    // ensure tx matches vm output exactly
-                    if vm_result.tx() != tx {
-                        return Err(Error::InvalidTransactionOutcome {
-                            transaction_id: tx_id,
-                        })
-                    }
+        let mut rejected = vec![];
+        for (idx, result) in results {
+            let tx_id = block.transactions[idx].id();
+            let executed = match result {
+                Ok(executed) => executed,
+                Err(error @ Error::CorruptedBlockState(_)) => return Err(error),
+                // the status of the id belongs to the transaction that used it first
+                Err(Error::TransactionIdCollision(_))
+                    if mode == ExecutionMode::Production =>
+                {
+                    warn!("Transaction {:#x} is dropped from the block: id was already used", tx_id);
+                    rejected.push(idx);
+                    continue
                 }
-                ExecutionMode::Production => {
-                    // malleate the block with the resultant tx from the vm
-                    *tx = vm_result.tx().clone()
+                // invalid transactions are skipped by the producer, but invalidate the
+                // block during validation
+                Err(error) if mode == ExecutionMode::Production => {
+                    warn!(
+                        "Transaction {:#x} is dropped from the block: {}",
+                        tx_id, error
+                    );
+                    tx_status.push((
+                        tx_id,
+                        TransactionStatus::Rejected {
+                            time: block.header.time,
+                            reason: error.to_string(),
+                        },
+                    ));
+                    rejected.push(idx);
+                    continue
                 }
+                Err(error) => return Err(error),
+            };
+            // merge the changes of transactions executed in parallel in block order
+            if !executed.changes.is_empty() {
+                block_db_transaction.batch_write(executed.changes)?;
             }
-
-            // Store tx into the block db transaction
-            Storage::<Bytes32, Transaction>::insert(
-                block_db_transaction.deref_mut(),
-                &tx_id,
-                vm_result.tx(),
-            )?;
-
-            // change the spent status of the tx inputs
-            self.spend_inputs(
-                vm_result.tx(),
-                block_db_transaction.deref_mut(),
-                block.header.height,
-            )?;
-
-            // persist any outputs
-            self.persist_outputs(
+            self.persist_owners_index(
                 block.header.height,
-                vm_result.tx(),
+                &executed.owners,
                 &tx_id,
+                idx - rejected.len(),
                 block_db_transaction.deref_mut(),
             )?;
 
-            // persist receipts
-            self.persist_receipts(
-                &tx_id,
-                vm_result.receipts(),
+            coinbase = coinbase
+                .checked_add(executed.fee)
+                .ok_or(Error::FeeOverflow)?;
+            txs_merkle.push(&executed.tx_bytes);
+            self.persist_outbox(
+                executed.outbox,
+                &mut outbox,
                 block_db_transaction.deref_mut(),
             )?;
-
-            let status = if vm_result.should_revert() {
-                self.log_backtrace(&vm, vm_result.receipts());
-                // get reason for revert
-                let reason = vm_result
-                    .receipts()
-                    .iter()
-                    .find_map(|receipt| {
-                        match receipt {
-                            // Format as `Revert($rA)`
-                            Receipt::Revert { ra, .. } => Some(format!("Revert({})", ra)),
-                            // Display PanicReason e.g. `OutOfGas`
-                            Receipt::Panic { reason, .. } => {
-                                Some(format!("{}", reason.reason()))
-                            }
-                            _ => None,
-                        }
-                    })
-                    .unwrap_or_else(|| format!("{:?}", vm_result.state()));
-
-                TransactionStatus::Failed {
-                    block_id: Default::default(),
-                    time: block.header.time,
-                    reason,
-                    result: Some(*vm_result.state()),
-                }
-            } else {
-                // else tx was a success
-                TransactionStatus::Success {
-                    block_id: Default::default(),
-                    time: block.header.time,
-                    result: *vm_result.state(),
-                }
-            };
-
             // queue up status for this tx to be stored once block id is finalized.
-            tx_status.push((tx_id, status));
+            tx_status.push((tx_id, executed.status));
+        }
+        // remove the skipped transactions from the produced block
+        for idx in rejected.into_iter().rev() {
+            block.transactions.remove(idx);
         }
 
         // check or set transaction commitment
@@ -326,9 +301,74 @@ impl Executor {
             }
         }
 
+        // check or set the commitment to the sent messages
+        let mut outbox_merkle = MerkleTree::new();
+        for message_id in &outbox {
+            outbox_merkle.push(message_id.as_ref());
+        }
+        let message_outbox_root = outbox_merkle.root().into();
+        match mode {
+            ExecutionMode::Production => {
+                block.header.message_outbox_root = message_outbox_root;
+            }
+            ExecutionMode::Validation => {
+                if block.header.message_outbox_root != message_outbox_root {
+                    return Err(Error::InvalidMessageOutboxRoot)
+                }
+            }
+        }
+
+        // check or set the commitment to the state of the touched contracts
+        let mut contracts = BTreeMap::new();
+        for contract_id in block.transactions.iter().flat_map(touched_contracts) {
+            if let Entry::Vacant(entry) = contracts.entry(contract_id) {
+                entry.insert(
+                    block_db_transaction
+                        .deref_mut()
+                        .contract_roots(&contract_id)?,
+                );
+            }
+        }
+        let contracts_root = contract_roots::contracts_root(contracts.iter());
+        match mode {
+            ExecutionMode::Production => {
+                block.header.contracts_root = contracts_root;
+            }
+            ExecutionMode::Validation => {
+                if block.header.contracts_root != contracts_root {
+                    return Err(Error::InvalidContractsRoot)
+                }
+            }
+        }
+
+        // check or set the root of all previous block ids
+        let prev_root = self.database.prev_root(block.header.height)?;
+        match mode {
+            ExecutionMode::Production => {
+                block.header.prev_root = prev_root;
+            }
+            ExecutionMode::Validation => {
+                if block.header.prev_root != prev_root {
+                    return Err(Error::InvalidPrevRoot)
+                }
+            }
+        }
+
+        // check or set the fees collected by the producer
+        match mode {
+            ExecutionMode::Production => {
+                block.header.coinbase = coinbase;
+            }
+            ExecutionMode::Validation => {
+                if block.header.coinbase != coinbase {
+                    return Err(Error::InvalidFeeAmount)
+                }
+            }
+        }
+
         let finalized_block_id = block.id();
 
-        debug!("Block {:#x} fees: {}", pre_exec_block_id, coinbase);
+        debug!("Block {:#x} fees: {}", finalized_block_id, coinbase);
 
         // check if block id doesn't match proposed block id
         if mode == ExecutionMode::Validation && pre_exec_block_id != finalized_block_id {
@@ -337,6 +377,27 @@ impl Executor {
             return Err(Error::InvalidBlockId)
         }
 
+        // mint the fees to the producer, there is no coin for a block without fees
+        if coinbase > 0 {
+            Executor::insert_coin(
+                block.header.height.into(),
+                Self::coinbase_utxo_id(&finalized_block_id),
+                &coinbase,
+                &AssetId::default(),
+                &block.header.producer,
+                block_db_transaction.deref_mut(),
+            )?;
+        }
+
+        // keep the roots of the touched contracts for storage proofs
+        for (contract_id, roots) in &contracts {
+            block_db_transaction.insert_block_contract_roots(
+                block.header.height,
+                contract_id,
+                roots,
+            )?;
+        }
+
         // save the status for every transaction using the finalized block id
         self.persist_transaction_status(
             finalized_block_id,
@@ -356,10 +417,263 @@ impl Executor {
             &finalized_block_id,
             &block.to_db_block(),
         )?;
+
+        // keep the previous values of everything the block wrote, so it can be rolled back
+        self.database
+            .record_reverse_diff(block.header.height, &block_db_transaction)?;
         block_db_transaction.commit()?;
         Ok(())
     }
 
+    /// Execute the transactions one after the other on top of `db`, each on its own storage
+    /// transaction so the changes of a transaction dropped from the block are discarded.
+    /// With `keep_changes`, the changes are also returned to be applied to the block later.
+    /// Stops at the first transaction that invalidates the block.
+    fn execute_transactions(
+        &self,
+        txs: Vec<(usize, &mut Transaction)>,
+        header: &FuelBlockHeader,
+        mode: &ExecutionMode,
+        db: &mut Database,
+        keep_changes: bool,
+    ) -> Vec<(usize, Result<ExecutedTransaction, Error>)> {
+        let mut results = vec![];
+        for (idx, tx) in txs {
+            let mut tx_db_transaction = db.transaction();
+            let result = self
+                .execute_transaction(tx, header, mode, tx_db_transaction.deref_mut())
+                .and_then(|mut executed| {
+                    if keep_changes {
+                        executed.changes = tx_db_transaction.changes();
+                    }
+                    tx_db_transaction.commit()?;
+                    Ok(executed)
+                });
+            let invalidates_block = match &result {
+                Ok(_) => false,
+                Err(Error::CorruptedBlockState(_)) => true,
+                Err(_) => *mode == ExecutionMode::Validation,
+            };
+            results.push((idx, result));
+            if invalidates_block {
+                break
+            }
+        }
+        results
+    }
+
+    /// Execute the transactions in groups that don't share inputs or contracts, every
+    /// worker on its own view of `db`. The results are in block order, their changes still
+    /// have to be applied to the block.
+    fn execute_transactions_parallel(
+        &self,
+        txs: &mut [Transaction],
+        header: &FuelBlockHeader,
+        mode: &ExecutionMode,
+        db: &Database,
+    ) -> Vec<(usize, Result<ExecutedTransaction, Error>)> {
+        let workers = thread::available_parallelism()
+            .map(NonZeroUsize::get)
+            .unwrap_or(1);
+        let worker_of = parallel::schedule(parallel::conflict_groups(txs), workers);
+        let mut batches: Vec<Vec<_>> = vec![];
+        for (idx, tx) in txs.iter_mut().enumerate() {
+            let worker = worker_of[idx];
+            if batches.len() <= worker {
+                batches.resize_with(worker + 1, Vec::new);
+            }
+            batches[worker].push((idx, tx));
+        }
+
+        // the workers are joined on this thread, let the runtime move its other tasks to
+        // another worker in the meantime
+        let mut results: Vec<_> = task::block_in_place(|| {
+            thread::scope(|scope| {
+                let workers: Vec<_> = batches
+                    .into_iter()
+                    .map(|batch| {
+                        scope.spawn(move || {
+                            let mut view = db.transaction();
+                            self.execute_transactions(
+                                batch,
+                                header,
+                                mode,
+                                view.deref_mut(),
+                                true,
+                            )
+                        })
+                    })
+                    .collect();
+                workers
+                    .into_iter()
+                    .flat_map(|worker| worker.join().expect("execution worker panicked"))
+                    .collect()
+            })
+        });
+        // a worker stops at a transaction that invalidates the block, which comes before
+        // any transaction missing from the results
+        results.sort_by_key(|(idx, _)| *idx);
+        results
+    }
+
+    /// Execute a single transaction of the block, the changes are written to `db`.
+    fn execute_transaction(
+        &self,
+        tx: &mut Transaction,
+        header: &FuelBlockHeader,
+        mode: &ExecutionMode,
+        db: &mut Database,
+    ) -> Result<ExecutedTransaction, Error> {
+        let tx_id = tx.id();
+
+        // Throw a clear error if the transaction id is a duplicate
+        if Storage::<Bytes32, Transaction>::contains_key(db, &tx_id)? {
+            return Err(Error::TransactionIdCollision(tx_id))
+        }
+
+        self.compute_contract_input_utxo_ids(tx, mode, db)?;
+
+        let checked_tx = CheckedTransaction::check_unsigned(
+            tx.clone(),
+            header.height.into(),
+            &self.config.chain_conf.transaction_parameters,
+        )?;
+        let min_fee = checked_tx.min_fee();
+        let max_fee = checked_tx.max_fee();
+
+        self.verify_tx_predicates(&checked_tx)?;
+
+        if self.config.utxo_validation {
+            // validate transaction has at least one coin
+            self.verify_tx_has_at_least_one_coin_or_message(tx)?;
+            // validate utxos exist and maturity is properly set
+            self.verify_input_state(db, tx, header.height, header.number)?;
+            // validate transaction signature
+            tx.validate_input_signature()
+                .map_err(TransactionValidityError::from)?;
+        }
+
+        // index owners of inputs and outputs with tx-id, regardless of validity
+        let owners = Self::owners(tx);
+
+        // execute transaction
+        // setup database view that only lives for the duration of vm execution
+        let mut sub_block_db_commit = db.transaction();
+        let sub_db_view = sub_block_db_commit.deref_mut();
+        // execution vm
+        let mut vm = Interpreter::with_storage(
+            sub_db_view.clone(),
+            self.config.chain_conf.transaction_parameters,
+        );
+        let vm_result = vm
+            .transact(checked_tx)
+            .map_err(|error| Error::VmExecution {
+                error,
+                transaction_id: tx_id,
+            })?
+            .into_owned();
+
+        // only commit state changes if execution was a success
+        if !vm_result.should_revert() {
+            sub_block_db_commit.commit()?;
+        }
+
+        // update block commitment
+        let fee =
+            self.total_fee_paid(min_fee, max_fee, tx.gas_price(), vm_result.receipts())?;
+
+        // include the canonical serialization of the malleated tx into the commitment,
+        // including all witness data.
+        //
+        // TODO: reference the bytes directly from VM memory to save serialization. This isn't
+        //       possible atm because the change output values are set on the tx instance in the vm
+        //       and not also on the in-memory representation of the tx.
+        let tx_bytes = vm_result.tx().clone().to_bytes();
+
+        match mode {
+            ExecutionMode::Validation => {
+                // ensure tx matches vm output exactly
+                if vm_result.tx() != tx {
+                    return Err(Error::InvalidTransactionOutcome {
+                        transaction_id: tx_id,
+                    })
+                }
+            }
+            ExecutionMode::Production => {
+                // malleate the block with the resultant tx from the vm
+                *tx = vm_result.tx().clone()
+            }
+        }
+
+        // Store tx into the block db transaction
+        Storage::<Bytes32, Transaction>::insert(db, &tx_id, vm_result.tx())?;
+
+        // change the spent status of the tx inputs
+        self.spend_inputs(vm_result.tx(), db, header.height)?;
+
+        let mut outbox = vec![];
+        // persist any outputs, messages of reverted transactions are not sent
+        let message_receipts = if vm_result.should_revert() {
+            &[]
+        } else {
+            vm_result.receipts()
+        };
+        self.persist_outputs(
+            header.height,
+            vm_result.tx(),
+            &tx_id,
+            message_receipts,
+            &mut outbox,
+            db,
+        )?;
+
+        // persist receipts
+        self.persist_receipts(&tx_id, vm_result.receipts(), db)?;
+
+        let status = if vm_result.should_revert() {
+            self.log_backtrace(&vm, vm_result.receipts());
+            // get reason for revert
+            let reason = vm_result
+                .receipts()
+                .iter()
+                .find_map(|receipt| {
+                    match receipt {
+                        // Format as `Revert($rA)`
+                        Receipt::Revert { ra, .. } => Some(format!("Revert({})", ra)),
+                        // Display PanicReason e.g. `OutOfGas`
+                        Receipt::Panic { reason, .. } => {
+                            Some(format!("{}", reason.reason()))
+                        }
+                        _ => None,
+                    }
+                })
+                .unwrap_or_else(|| format!("{:?}", vm_result.state()));
+
+            TransactionStatus::Failed {
+                block_id: Default::default(),
+                time: header.time,
+                reason,
+                result: Some(*vm_result.state()),
+            }
+        } else {
+            // else tx was a success
+            TransactionStatus::Success {
+                block_id: Default::default(),
+                time: header.time,
+                result: *vm_result.state(),
+            }
+        };
+
+        Ok(ExecutedTransaction {
+            tx_bytes,
+            fee,
+            status,
+            outbox,
+            owners,
+            changes: vec![],
+        })
+    }
+
     fn verify_input_state(
         &self,
         db: &Database,
@@ -588,6 +902,12 @@ impl Executor {
         Ok(min_fee)
     }
 
+    /// Id of the coin with the fees of the block, derived from the block id so it doesn't
+    /// collide with transaction outputs.
+    pub fn coinbase_utxo_id(block_id: &Bytes32) -> UtxoId {
+        UtxoId::new(*block_id, 0)
+    }
+
     /// In production mode, lookup and set the proper utxo ids for contract inputs
     /// In validation mode, verify the proposed utxo ids on contract inputs match the expected values.
     fn compute_contract_input_utxo_ids(
@@ -651,11 +971,15 @@ impl Executor {
         }
     }
 
+    /// Persist the outputs of the transaction. Messages are built from the data of their
+    /// `MessageOut` receipts and appended to `outbox`.
     fn persist_outputs(
         &self,
         block_height: BlockHeight,
         tx: &Transaction,
         tx_id: &Bytes32,
+        receipts: &[Receipt],
+        outbox: &mut Vec<(MessageId, OutboxMessage)>,
         db: &mut Database,
     ) -> Result<(), Error> {
         for (output_index, output) in tx.outputs().iter().enumerate() {
@@ -688,7 +1012,35 @@ impl Executor {
                     }
                 }
                 Output::Message { .. } => {
-                    // TODO: Handle message outputs somehow (new field on the block type?)
+                    // only messages sent by the script have a receipt
+                    let nonce = Output::message_nonce(tx_id, output_index as Word);
+                    if let Some(Receipt::MessageOut {
+                        message_id,
+                        sender,
+                        recipient,
+                        amount,
+                        data,
+                        ..
+                    }) = receipts.iter().find(|receipt| {
+                        matches!(receipt, Receipt::MessageOut { nonce: n, .. } if *n == nonce)
+                    }) {
+                        let message = OutboxMessage {
+                            sender: *sender,
+                            recipient: *recipient,
+                            nonce,
+                            amount: *amount,
+                            data: data.clone(),
+                            block_height,
+                            // set once the message is put into the outbox of the block
+                            index: 0,
+                        };
+                        if Storage::<MessageId, OutboxMessage>::contains_key(
+                            db, message_id,
+                        )? {
+                            return Err(Error::OutputAlreadyExists)
+                        }
+                        outbox.push((*message_id, message));
+                    }
                 }
                 Output::Change {
                     to,
@@ -765,21 +1117,14 @@ impl Executor {
         Ok(())
     }
 
-    /// Index the tx id by owner for all of the inputs and outputs
-    fn persist_owners_index(
-        &self,
-        block_height: BlockHeight,
-        tx: &Transaction,
-        tx_id: &Bytes32,
-        tx_idx: usize,
-        db: &mut Database,
-    ) -> Result<(), Error> {
+    /// Owners of the inputs and outputs of the transaction
+    fn owners(tx: &Transaction) -> Vec<Address> {
         let mut owners = vec![];
         for input in tx.inputs() {
             if let Input::CoinSigned { owner, .. } | Input::CoinPredicate { owner, .. } =
                 input
             {
-                owners.push(owner);
+                owners.push(*owner);
             }
         }
 
@@ -789,7 +1134,7 @@ impl Executor {
                 | Output::Message { recipient: to, .. }
                 | Output::Change { to, .. }
                 | Output::Variable { to, .. } => {
-                    owners.push(to);
+                    owners.push(*to);
                 }
                 Output::Contract { .. } | Output::ContractCreated { .. } => {}
             }
@@ -798,7 +1143,18 @@ impl Executor {
         // dedupe owners from inputs and outputs prior to indexing
         owners.sort();
         owners.dedup();
+        owners
+    }
 
+    /// Index the tx id by owner for all of the inputs and outputs
+    fn persist_owners_index(
+        &self,
+        block_height: BlockHeight,
+        owners: &[Address],
+        tx_id: &Bytes32,
+        tx_idx: usize,
+        db: &mut Database,
+    ) -> Result<(), Error> {
         for owner in owners {
             db.record_tx_id_owner(
                 owner,
@@ -811,6 +1167,27 @@ impl Executor {
         Ok(())
     }
 
+    /// Put the messages of a transaction into the outbox of the block, after the messages
+    /// of the previous transactions.
+    fn persist_outbox(
+        &self,
+        messages: Vec<(MessageId, OutboxMessage)>,
+        outbox: &mut Vec<MessageId>,
+        db: &mut Database,
+    ) -> Result<(), Error> {
+        for (message_id, mut message) in messages {
+            message.index = outbox.len() as u64;
+            if Storage::<MessageId, OutboxMessage>::insert(db, &message_id, &message)?
+                .is_some()
+            {
+                return Err(Error::OutputAlreadyExists)
+            }
+            outbox.push(message_id);
+        }
+
+        Ok(())
+    }
+
     fn persist_transaction_status(
         &self,
         finalized_block_id: Bytes32,
@@ -819,7 +1196,8 @@ impl Executor {
     ) -> Result<(), Error> {
         for (tx_id, status) in tx_status {
             match status {
-                TransactionStatus::Submitted { .. } => {}
+                TransactionStatus::Submitted { .. }
+                | TransactionStatus::Rejected { .. } => {}
                 TransactionStatus::Success { block_id, .. } => {
                     *block_id = finalized_block_id;
                 }
@@ -833,6 +1211,19 @@ impl Executor {
     }
 }
 
+/// Contracts used or created by the transaction.
+fn touched_contracts(tx: &Transaction) -> impl Iterator<Item = ContractId> + '_ {
+    let used = tx.inputs().iter().filter_map(|input| match input {
+        Input::Contract { contract_id, .. } => Some(*contract_id),
+        _ => None,
+    });
+    let created = tx.outputs().iter().filter_map(|output| match output {
+        Output::ContractCreated { contract_id, .. } => Some(*contract_id),
+        _ => None,
+    });
+    used.chain(created)
+}
+
 #[derive(Debug, Error)]
 #[non_exhaustive]
 pub enum TransactionValidityError {
@@ -905,6 +1296,12 @@ pub enum Error {
     InvalidTransactionOutcome { transaction_id: Bytes32 },
     #[error("Transaction root is invalid")]
     InvalidTransactionRoot,
+    #[error("Root of the previous blocks is invalid")]
+    InvalidPrevRoot,
+    #[error("Message outbox root is invalid")]
+    InvalidMessageOutboxRoot,
+    #[error("Contracts root is invalid")]
+    InvalidContractsRoot,
     #[error("The amount of charged fees is invalid")]
     InvalidFeeAmount,
     #[error("Block id is invalid")]
@@ -943,6 +1340,7 @@ mod tests {
             fuel_tx::{
                 self,
                 ConsensusParameters,
+                StorageSlot,
                 Transaction,
                 TransactionBuilder,
             },
@@ -1052,6 +1450,35 @@ mod tests {
         assert!(validation_result.is_ok());
     }
 
+    #[tokio::test]
+    async fn producer_skips_invalid_transactions() {
+        let producer = Executor {
+            database: Default::default(),
+            config: Config::local_node(),
+        };
+        let verifier = Executor {
+            database: Default::default(),
+            config: Config::local_node(),
+        };
+        let mut block = test_block(2);
+        // transaction without any input to pay for gas
+        let mut invalid_tx = Transaction::default();
+        invalid_tx.set_gas_limit(100);
+        invalid_tx.set_gas_price(1);
+        block.transactions.insert(1, invalid_tx);
+
+        producer
+            .execute(&mut block, ExecutionMode::Production)
+            .await
+            .unwrap();
+        assert_eq!(block.transactions.len(), 2);
+
+        let validation_result = verifier
+            .execute(&mut block, ExecutionMode::Validation)
+            .await;
+        assert!(validation_result.is_ok());
+    }
+
     // Ensure transaction commitment != default after execution
     #[tokio::test]
     async fn executor_commits_transactions_to_block() {
@@ -1097,17 +1524,22 @@ mod tests {
         tx.set_gas_limit(gas_limit);
         tx.set_gas_price(gas_price);
 
+        let tx_id = tx.id();
         let mut block = FuelBlock {
             header: Default::default(),
             transactions: vec![tx],
         };
 
-        let produce_result = producer
-            .execute(&mut block, ExecutionMode::Production)
-            .await;
+        // the producer drops the transaction from the block
+        let mut produced_block = block.clone();
+        producer
+            .execute(&mut produced_block, ExecutionMode::Production)
+            .await
+            .unwrap();
+        assert!(produced_block.transactions.is_empty());
         assert!(matches!(
-            produce_result,
-            Err(Error::InvalidTransaction(ValidationError::InsufficientFeeAmount { expected, .. })) if expected == (gas_limit as f64 / factor).ceil() as u64
+            producer.database.get_tx_status(&tx_id).unwrap(),
+            Some(TransactionStatus::Rejected { reason, .. }) if reason.contains("InsufficientFeeAmount")
         ));
 
         let verify_result = verifier
@@ -1131,18 +1563,24 @@ mod tests {
             config: Config::local_node(),
         };
 
-        let mut block = FuelBlock {
+        let block = FuelBlock {
             header: Default::default(),
             transactions: vec![Transaction::default(), Transaction::default()],
         };
 
-        let produce_result = producer
-            .execute(&mut block, ExecutionMode::Production)
-            .await;
-        assert!(matches!(
-            produce_result,
-            Err(Error::TransactionIdCollision(_))
-        ));
+        // the producer keeps the first transaction and drops the duplicate
+        let mut produced_block = block.clone();
+        producer
+            .execute(&mut produced_block, ExecutionMode::Production)
+            .await
+            .unwrap();
+        assert_eq!(produced_block.transactions.len(), 1);
+
+        // a block proposed with the duplicate is invalid
+        let mut block = produced_block.clone();
+        block
+            .transactions
+            .push(produced_block.transactions[0].clone());
 
         let verify_result = verifier
             .execute(&mut block, ExecutionMode::Validation)
@@ -1223,15 +1661,12 @@ mod tests {
             transactions: vec![tx],
         };
 
-        let produce_result = producer
-            .execute(&mut block, ExecutionMode::Production)
-            .await;
-        assert!(matches!(
-            produce_result,
-            Err(Error::TransactionValidity(
-                TransactionValidityError::CoinAlreadySpent(_)
-            ))
-        ));
+        let mut produced_block = block.clone();
+        producer
+            .execute(&mut produced_block, ExecutionMode::Production)
+            .await
+            .unwrap();
+        assert!(produced_block.transactions.is_empty());
 
         let verify_result = verifier
             .execute(&mut block, ExecutionMode::Validation)
@@ -1289,15 +1724,12 @@ mod tests {
             transactions: vec![tx],
         };
 
-        let produce_result = producer
-            .execute(&mut block, ExecutionMode::Production)
-            .await;
-        assert!(matches!(
-            produce_result,
-            Err(Error::TransactionValidity(
-                TransactionValidityError::CoinDoesNotExist(_)
-            ))
-        ));
+        let mut produced_block = block.clone();
+        producer
+            .execute(&mut produced_block, ExecutionMode::Production)
+            .await
+            .unwrap();
+        assert!(produced_block.transactions.is_empty());
 
         let verify_result = verifier
             .execute(&mut block, ExecutionMode::Validation)
@@ -1362,6 +1794,259 @@ mod tests {
         ));
     }
 
+    #[tokio::test]
+    async fn fees_are_minted_to_producer() {
+        let mut rng = StdRng::seed_from_u64(2322u64);
+        let producer_address: Address = rng.gen();
+        let tx: Transaction = TxBuilder::new(2322u64)
+            .gas_limit(100)
+            .gas_price(1)
+            .coin_input(Default::default(), 1000)
+            .change_output(Default::default())
+            .build()
+            .into();
+        let producer = Executor {
+            database: Default::default(),
+            config: Config::local_node(),
+        };
+        let mut block = FuelBlock {
+            header: FuelBlockHeader {
+                producer: producer_address,
+                ..Default::default()
+            },
+            transactions: vec![tx],
+        };
+
+        producer
+            .execute(&mut block, ExecutionMode::Production)
+            .await
+            .unwrap();
+
+        assert!(block.header.coinbase > 0);
+        let coin = Storage::<UtxoId, Coin>::get(
+            &producer.database,
+            &Executor::coinbase_utxo_id(&block.id()),
+        )
+        .unwrap()
+        .unwrap();
+        assert_eq!(coin.owner, producer_address);
+        assert_eq!(coin.amount, block.header.coinbase);
+        assert_eq!(coin.asset_id, AssetId::default());
+    }
+
+    #[tokio::test]
+    async fn no_coin_is_minted_for_a_block_without_fees() {
+        let producer = Executor {
+            database: Default::default(),
+            config: Config::local_node(),
+        };
+        let mut block = FuelBlock {
+            header: Default::default(),
+            transactions: vec![],
+        };
+
+        producer
+            .execute(&mut block, ExecutionMode::Production)
+            .await
+            .unwrap();
+
+        assert_eq!(block.header.coinbase, 0);
+        let coin = Storage::<UtxoId, Coin>::get(
+            &producer.database,
+            &Executor::coinbase_utxo_id(&block.id()),
+        )
+        .unwrap();
+        assert!(coin.is_none());
+    }
+
+    #[tokio::test]
+    async fn executor_invalidates_blocks_with_diverging_coinbase() {
+        let tx: Transaction = TxBuilder::new(2322u64)
+            .gas_limit(100)
+            .gas_price(1)
+            .coin_input(Default::default(), 1000)
+            .change_output(Default::default())
+            .build()
+            .into();
+        let producer = Executor {
+            database: Default::default(),
+            config: Config::local_node(),
+        };
+        let verifier = Executor {
+            database: Default::default(),
+            config: Config::local_node(),
+        };
+        let mut block = FuelBlock {
+            header: Default::default(),
+            transactions: vec![tx],
+        };
+
+        producer
+            .execute(&mut block, ExecutionMode::Production)
+            .await
+            .unwrap();
+        block.header.coinbase += 1;
+        block.header.recalculate_metadata();
+
+        let verify_result = verifier
+            .execute(&mut block, ExecutionMode::Validation)
+            .await;
+        assert!(matches!(verify_result, Err(Error::InvalidFeeAmount)));
+    }
+
+    #[tokio::test]
+    async fn message_outputs_are_put_into_outbox() {
+        let mut rng = StdRng::seed_from_u64(2322u64);
+        let recipient: Address = rng.gen();
+        let (script, _) = script_with_data_offset!(
+            data_offset,
+            vec![
+                // set reg 0x10 to the recipient
+                Opcode::MOVI(0x10, data_offset),
+                // set reg 0x11 to the amount of the message
+                Opcode::MOVI(0x11, 10),
+                // send the message through output 0 without data
+                Opcode::SMO(0x10, REG_ZERO, REG_ZERO, 0x11),
+                Opcode::RET(REG_ONE),
+            ],
+            ConsensusParameters::DEFAULT.tx_offset()
+        );
+        let tx: Transaction = TxBuilder::new(2322)
+            .gas_limit(ConsensusParameters::DEFAULT.max_gas_per_tx)
+            .start_script(script, recipient.to_vec())
+            .coin_input(AssetId::default(), 100)
+            .message_output()
+            .change_output(AssetId::default())
+            .build()
+            .into();
+        let producer = Executor {
+            database: Default::default(),
+            config: Config::local_node(),
+        };
+        let verifier = Executor {
+            database: Default::default(),
+            config: Config::local_node(),
+        };
+        let mut block = FuelBlock {
+            header: Default::default(),
+            transactions: vec![tx],
+        };
+
+        producer
+            .execute(&mut block, ExecutionMode::Production)
+            .await
+            .unwrap();
+
+        let receipts = Storage::<Bytes32, Vec<Receipt>>::get(
+            &producer.database,
+            &block.transactions[0].id(),
+        )
+        .unwrap()
+        .unwrap();
+        let message_id = *receipts.iter().find_map(Receipt::message_id).unwrap();
+        let proof = producer
+            .database
+            .message_proof(&message_id)
+            .unwrap()
+            .unwrap();
+        assert_eq!(proof.message.recipient, recipient);
+        assert_eq!(proof.message.amount, 10);
+        assert_eq!(proof.root, block.header.message_outbox_root);
+
+        let verify_result = verifier
+            .execute(&mut block, ExecutionMode::Validation)
+            .await;
+        assert!(verify_result.is_ok());
+
+        // corrupt the commitment to the messages
+        block.header.message_outbox_root = Default::default();
+        block.header.recalculate_metadata();
+        let verifier = Executor {
+            database: Default::default(),
+            config: Config::local_node(),
+        };
+        let verify_result = verifier
+            .execute(&mut block, ExecutionMode::Validation)
+            .await;
+        assert!(matches!(
+            verify_result,
+            Err(Error::InvalidMessageOutboxRoot)
+        ));
+    }
+
+    #[tokio::test]
+    async fn messages_of_a_block_are_indexed_across_transactions() {
+        let mut rng = StdRng::seed_from_u64(2322u64);
+        let (script, _) = script_with_data_offset!(
+            data_offset,
+            vec![
+                Opcode::MOVI(0x10, data_offset),
+                Opcode::MOVI(0x11, 10),
+                Opcode::SMO(0x10, REG_ZERO, REG_ZERO, 0x11),
+                Opcode::RET(REG_ONE),
+            ],
+            ConsensusParameters::DEFAULT.tx_offset()
+        );
+        // two transactions sending a message each
+        let transactions = (0..2)
+            .map(|seed| {
+                let recipient: Address = rng.gen();
+                TxBuilder::new(seed)
+                    .gas_limit(ConsensusParameters::DEFAULT.max_gas_per_tx)
+                    .start_script(script.clone(), recipient.to_vec())
+                    .coin_input(AssetId::default(), 100)
+                    .message_output()
+                    .change_output(AssetId::default())
+                    .build()
+                    .into()
+            })
+            .collect();
+        let producer = Executor {
+            database: Default::default(),
+            config: Config::local_node(),
+        };
+        let mut block = FuelBlock {
+            header: Default::default(),
+            transactions,
+        };
+
+        producer
+            .execute(&mut block, ExecutionMode::Production)
+            .await
+            .unwrap();
+
+        let message_ids = block
+            .transactions
+            .iter()
+            .map(|tx| {
+                let receipts =
+                    Storage::<Bytes32, Vec<Receipt>>::get(&producer.database, &tx.id())
+                        .unwrap()
+                        .unwrap();
+                *receipts.iter().find_map(Receipt::message_id).unwrap()
+            })
+            .collect::<Vec<_>>();
+        let mut tree = MerkleTree::new();
+        for message_id in &message_ids {
+            tree.push(message_id.as_ref());
+        }
+        for (index, message_id) in message_ids.iter().enumerate() {
+            let proof = producer
+                .database
+                .message_proof(message_id)
+                .unwrap()
+                .unwrap();
+            let (root, proof_set) = tree.prove(index as u64).unwrap();
+            assert_eq!(proof.message.index, index as u64);
+            assert_eq!(proof.root, block.header.message_outbox_root);
+            assert_eq!(proof.root, Bytes32::from(root));
+            assert_eq!(
+                proof.proof_set,
+                proof_set.into_iter().map(Bytes32::from).collect::<Vec<_>>()
+            );
+        }
+    }
+
     // corrupt the merkle sum tree commitment from a produced block and verify that the
     // validation logic will reject the block
     #[tokio::test]
@@ -1423,17 +2108,22 @@ mod tests {
             transactions: vec![tx],
         };
 
-        let err = executor
+        executor
             .execute(&mut block, ExecutionMode::Production)
             .await
-            .err()
             .unwrap();
 
-        // assert block failed to validate when transaction didn't contain any coin inputs
-        assert!(matches!(
-            err,
-            Error::TransactionValidity(TransactionValidityError::NoCoinOrMessageInput(id)) if id == tx_id
-        ));
+        // assert the transaction is dropped when it didn't contain any coin inputs
+        assert!(block.transactions.is_empty());
+        assert_eq!(
+            rejection_reason(&executor, &tx_id),
+            Some(
+                Error::TransactionValidity(
+                    TransactionValidityError::NoCoinOrMessageInput(tx_id)
+                )
+                .to_string()
+            )
+        );
     }
 
     #[tokio::test]
@@ -1708,6 +2398,150 @@ mod tests {
         ));
     }
 
+    #[tokio::test]
+    async fn contract_storage_is_provable_against_block() {
+        let mut rng = StdRng::seed_from_u64(2322);
+        let slot = StorageSlot::new(rng.gen(), rng.gen());
+        let salt: Salt = rng.gen();
+        let contract = fuel_tx::Contract::from(vec![]);
+        let state_root = fuel_tx::Contract::initial_state_root([&slot].into_iter());
+        let contract_id = contract.id(&salt, &contract.root(), &state_root);
+        let tx = Transaction::create(
+            0,
+            0,
+            0,
+            0,
+            salt,
+            vec![slot.clone()],
+            vec![],
+            vec![Output::ContractCreated {
+                contract_id,
+                state_root,
+            }],
+            vec![vec![].into()],
+        );
+
+        let producer = Executor {
+            database: Default::default(),
+            config: Config::local_node(),
+        };
+        let verifier = Executor {
+            database: Default::default(),
+            config: Config::local_node(),
+        };
+        let mut block = FuelBlock {
+            header: FuelBlockHeader {
+                height: 1u32.into(),
+                ..Default::default()
+            },
+            transactions: vec![tx],
+        };
+
+        producer
+            .execute(&mut block, ExecutionMode::Production)
+            .await
+            .unwrap();
+
+        let proof = producer
+            .database
+            .storage_proof(1u32.into(), &contract_id, slot.key())
+            .unwrap()
+            .unwrap();
+        assert_eq!(proof.value, *slot.value());
+        assert_eq!(proof.roots.state_root, state_root);
+        assert_eq!(proof.contracts_root, block.header.contracts_root);
+        assert!(proof.verify());
+
+        // the block commits to the state of the contract
+        block.header.contracts_root = rng.gen();
+        let verify_result = verifier
+            .execute(&mut block, ExecutionMode::Validation)
+            .await;
+        assert!(matches!(verify_result, Err(Error::InvalidContractsRoot)));
+    }
+
+    #[tokio::test]
+    async fn contract_storage_is_provable_against_older_block() {
+        let mut rng = StdRng::seed_from_u64(2322);
+        // the contract sets the slot keyed by its id to the block height
+        let (create, contract_id) = create_contract(
+            vec![
+                Opcode::BHEI(0x10),
+                Opcode::SWW(REG_FP, 0x10),
+                Opcode::RET(REG_ONE),
+            ]
+            .into_iter()
+            .collect::<Vec<u8>>(),
+            &mut rng,
+        );
+        let (script, data_offset) = script_with_data_offset!(
+            data_offset,
+            vec![
+                Opcode::MOVI(0x10, data_offset),
+                Opcode::CALL(0x10, REG_ZERO, REG_ZERO, REG_CGAS),
+                Opcode::RET(REG_ONE),
+            ],
+            ConsensusParameters::DEFAULT.tx_offset()
+        );
+        let call = |seed| -> Transaction {
+            TxBuilder::new(seed)
+                .gas_limit(ConsensusParameters::DEFAULT.max_gas_per_tx)
+                .start_script(
+                    script.clone(),
+                    Call::new(contract_id, 0, data_offset as Word).to_bytes(),
+                )
+                .contract_input(contract_id)
+                .coin_input(AssetId::default(), 100)
+                .change_output(AssetId::default())
+                .contract_output(&contract_id)
+                .build()
+                .into()
+        };
+
+        let producer = Executor {
+            database: Default::default(),
+            config: Config::local_node(),
+        };
+        let mut first_block = FuelBlock {
+            header: FuelBlockHeader {
+                height: 1u32.into(),
+                ..Default::default()
+            },
+            transactions: vec![create, call(1)],
+        };
+        producer
+            .execute(&mut first_block, ExecutionMode::Production)
+            .await
+            .unwrap();
+        // write the slot again in a later block
+        let mut second_block = FuelBlock {
+            header: FuelBlockHeader {
+                height: 2u32.into(),
+                parent_hash: first_block.id(),
+                ..Default::default()
+            },
+            transactions: vec![call(2)],
+        };
+        producer
+            .execute(&mut second_block, ExecutionMode::Production)
+            .await
+            .unwrap();
+
+        let key = Bytes32::from(*contract_id);
+        for (height, block) in [(1u64, &first_block), (2, &second_block)] {
+            let proof = producer
+                .database
+                .storage_proof((height as u32).into(), &contract_id, &key)
+                .unwrap()
+                .unwrap();
+            let mut value = Bytes32::zeroed();
+            value[..8].copy_from_slice(&height.to_be_bytes());
+            assert_eq!(proof.value, value);
+            assert_eq!(proof.contracts_root, block.header.contracts_root);
+            assert!(proof.verify());
+        }
+    }
+
     #[tokio::test]
     async fn outputs_with_amount_are_included_utxo_set() {
         let mut rng = StdRng::seed_from_u64(2322);
@@ -1888,6 +2722,14 @@ mod tests {
         (tx, message.check())
     }
 
+    /// Reason of the producer for dropping the transaction from the block
+    fn rejection_reason(executor: &Executor, tx_id: &Bytes32) -> Option<String> {
+        match executor.database.get_tx_status(tx_id).unwrap() {
+            Some(TransactionStatus::Rejected { reason, .. }) => Some(reason),
+            _ => None,
+        }
+    }
+
     /// Helper to build database and executor for some of the message tests
     async fn make_executor(messages: &[&CheckedMessage]) -> Executor {
         let mut database = Database::default();
@@ -1933,23 +2775,29 @@ mod tests {
     async fn message_fails_when_spending_nonexistent_message_id() {
         let mut rng = StdRng::seed_from_u64(2322);
 
-        let (tx, _message) = make_tx_and_message(&mut rng, 0);
+        let (tx, message) = make_tx_and_message(&mut rng, 0);
 
         let mut block = FuelBlock {
             header: Default::default(),
             transactions: vec![tx.clone()],
         };
 
-        let res = make_executor(&[]) // No messages in the db
+        let producer = make_executor(&[]).await; // No messages in the db
+        let mut produced_block = block.clone();
+        producer
+            .execute(&mut produced_block, ExecutionMode::Production)
             .await
-            .execute(&mut block, ExecutionMode::Production)
-            .await;
-        assert!(matches!(
-            res,
-            Err(Error::TransactionValidity(
-                TransactionValidityError::MessageDoesNotExist(_)
-            ))
-        ));
+            .unwrap();
+        assert!(produced_block.transactions.is_empty());
+        assert_eq!(
+            rejection_reason(&producer, &tx.id()),
+            Some(
+                Error::TransactionValidity(
+                    TransactionValidityError::MessageDoesNotExist(*message.id())
+                )
+                .to_string()
+            )
+        );
 
         let res = make_executor(&[]) // No messages in the db
             .await
@@ -1971,19 +2819,25 @@ mod tests {
 
         let mut block = FuelBlock {
             header: Default::default(),
-            transactions: vec![tx],
+            transactions: vec![tx.clone()],
         };
 
-        let res = make_executor(&[&message])
+        let producer = make_executor(&[&message]).await;
+        let mut produced_block = block.clone();
+        producer
+            .execute(&mut produced_block, ExecutionMode::Production)
             .await
-            .execute(&mut block, ExecutionMode::Production)
-            .await;
-        assert!(matches!(
-            res,
-            Err(Error::TransactionValidity(
-                TransactionValidityError::MessageSpendTooEarly(_)
-            ))
-        ));
+            .unwrap();
+        assert!(produced_block.transactions.is_empty());
+        assert_eq!(
+            rejection_reason(&producer, &tx.id()),
+            Some(
+                Error::TransactionValidity(
+                    TransactionValidityError::MessageSpendTooEarly(*message.id())
+                )
+                .to_string()
+            )
+        );
 
         let res = make_executor(&[&message])
             .await
@@ -2008,19 +2862,28 @@ mod tests {
 
         let mut block = FuelBlock {
             header: Default::default(),
-            transactions: vec![tx1, tx2],
+            transactions: vec![tx1, tx2.clone()],
         };
 
-        let res = make_executor(&[&message])
-            .await
+        // the producer only includes the first spend of the message
+        let producer = make_executor(&[&message]).await;
+        producer
             .execute(&mut block, ExecutionMode::Production)
-            .await;
-        assert!(matches!(
-            res,
-            Err(Error::TransactionValidity(
-                TransactionValidityError::MessageAlreadySpent(_)
-            ))
-        ));
+            .await
+            .unwrap();
+        assert_eq!(block.transactions.len(), 1);
+        assert_eq!(
+            rejection_reason(&producer, &tx2.id()),
+            Some(
+                Error::TransactionValidity(
+                    TransactionValidityError::MessageAlreadySpent(*message.id())
+                )
+                .to_string()
+            )
+        );
+
+        // a block proposed with the second spend is invalid
+        block.transactions.push(tx2);
 
         let res = make_executor(&[&message])
             .await
@@ -2172,4 +3035,179 @@ mod tests {
 
         assert_eq!(time as u64, receipts[0].val().unwrap());
     }
+
+    /// Transfers, a contract created and used in the same block, a transfer spending an
+    /// output of an earlier transaction of the block and a transaction without inputs to
+    /// pay for gas, which is dropped by the producer. Returns the owners of the coins.
+    fn block_for_parallel_execution(rng: &mut StdRng) -> (FuelBlock, Vec<Address>) {
+        let mut owners = vec![];
+        let mut transfer = |rng: &mut StdRng, utxo_id: UtxoId| {
+            let secret = SecretKey::random(rng);
+            let owner = Input::owner(&secret.public_key());
+            owners.push(owner);
+            TransactionBuilder::script(
+                vec![Opcode::RET(REG_ONE)].into_iter().collect(),
+                vec![],
+            )
+            .gas_limit(100)
+            .add_unsigned_coin_input(
+                secret,
+                utxo_id,
+                100,
+                AssetId::default(),
+                Default::default(),
+                0,
+            )
+            .add_output(Output::coin(rng.gen(), 50, AssetId::default()))
+            .add_output(Output::change(owner, 0, AssetId::default()))
+            .finalize()
+        };
+        let utxo_id = rng.gen();
+        let first = transfer(rng, utxo_id);
+        let spend_first = transfer(rng, UtxoId::new(first.id(), 0));
+        let utxo_id = rng.gen();
+        let second = transfer(rng, utxo_id);
+        let utxo_id = rng.gen();
+        let third = transfer(rng, utxo_id);
+
+        let (create, contract_id) =
+            create_contract(vec![Opcode::RET(REG_ONE)].into_iter().collect(), rng);
+        let use_contract = TransactionBuilder::script(
+            vec![Opcode::RET(REG_ONE)].into_iter().collect(),
+            vec![],
+        )
+        .gas_limit(100)
+        .add_input(Input::contract(
+            Default::default(),
+            Default::default(),
+            Default::default(),
+            Default::default(),
+            contract_id,
+        ))
+        .add_output(Output::contract(0, Default::default(), Default::default()))
+        .finalize_without_signature();
+
+        let mut invalid = Transaction::default();
+        invalid.set_gas_limit(100);
+        invalid.set_gas_price(1);
+
+        let block = FuelBlock {
+            header: FuelBlockHeader {
+                height: 1u32.into(),
+                ..Default::default()
+            },
+            transactions: vec![
+                invalid,
+                first,
+                create,
+                second,
+                spend_first,
+                use_contract,
+                third,
+            ],
+        };
+        (block, owners)
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn parallel_execution_matches_serial_execution() {
+        let mut rng = StdRng::seed_from_u64(2322);
+        let (block, owners) = block_for_parallel_execution(&mut rng);
+        let parallel_config = Config {
+            parallel_execution: true,
+            ..Config::local_node()
+        };
+        let serial = Executor {
+            database: Default::default(),
+            config: Config::local_node(),
+        };
+        let parallel = Executor {
+            database: Default::default(),
+            config: parallel_config.clone(),
+        };
+
+        let mut serial_block = block.clone();
+        serial
+            .execute(&mut serial_block, ExecutionMode::Production)
+            .await
+            .unwrap();
+        let mut parallel_block = block;
+        parallel
+            .execute(&mut parallel_block, ExecutionMode::Production)
+            .await
+            .unwrap();
+
+        assert_eq!(parallel_block.transactions.len(), 6);
+        assert_eq!(parallel_block.transactions, serial_block.transactions);
+        assert_eq!(parallel_block.id(), serial_block.id());
+        for owner in owners {
+            let coins = |db: &Database| {
+                db.owned_coins(owner, None, None)
+                    .try_collect::<_, Vec<_>, _>()
+                    .unwrap()
+            };
+            assert_eq!(coins(&parallel.database), coins(&serial.database));
+            let txs = |db: &Database| {
+                db.owned_transactions(&owner, None, None)
+                    .try_collect::<_, Vec<_>, _>()
+                    .unwrap()
+            };
+            assert_eq!(txs(&parallel.database), txs(&serial.database));
+        }
+        for tx in &serial_block.transactions {
+            let status = |db: &Database| format!("{:?}", db.get_tx_status(&tx.id()));
+            assert_eq!(status(&parallel.database), status(&serial.database));
+        }
+
+        // the block produced in parallel is valid in both modes
+        for config in [Config::local_node(), parallel_config] {
+            let verifier = Executor {
+                database: Default::default(),
+                config,
+            };
+            verifier
+                .execute(&mut parallel_block.clone(), ExecutionMode::Validation)
+                .await
+                .unwrap();
+        }
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn parallel_validation_matches_serial_validation() {
+        let mut rng = StdRng::seed_from_u64(2322);
+        let (mut block, _) = block_for_parallel_execution(&mut rng);
+        let producer = Executor {
+            database: Default::default(),
+            config: Config::local_node(),
+        };
+        producer
+            .execute(&mut block, ExecutionMode::Production)
+            .await
+            .unwrap();
+
+        // claim a change that the last transfer doesn't produce
+        let tx = block.transactions.last_mut().unwrap();
+        if let Some(Output::Change { amount, .. }) = tx.outputs_mut().last_mut() {
+            *amount -= 1;
+        }
+        let tampered_id = tx.id();
+
+        for parallel_execution in [false, true] {
+            let verifier = Executor {
+                database: Default::default(),
+                config: Config {
+                    parallel_execution,
+                    ..Config::local_node()
+                },
+            };
+            let result = verifier
+                .execute(&mut block.clone(), ExecutionMode::Validation)
+                .await;
+            assert!(matches!(
+                result,
+                Err(Error::InvalidTransactionOutcome { transaction_id })
+                    if transaction_id == tampered_id
+            ));
+        }
+    }
 }
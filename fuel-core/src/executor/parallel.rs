@@ -0,0 +1,209 @@
+use super::touched_contracts;
+use fuel_core_interfaces::common::{
+    fuel_tx::{
+        Input,
+        Transaction,
+        TxId,
+        UtxoId,
+    },
+    fuel_types::{
+        ContractId,
+        MessageId,
+    },
+};
+use std::collections::{
+    hash_map::Entry,
+    BTreeMap,
+    HashMap,
+    HashSet,
+};
+
+/// State that more than one transaction of a block may access. Transactions sharing any
+/// of it are executed by the same worker, in block order.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+enum Conflict {
+    Coin(UtxoId),
+    Message(MessageId),
+    Contract(ContractId),
+    /// The id of the transaction, which is also used by the transactions of the block
+    /// that spend its outputs.
+    Transaction(TxId),
+}
+
+/// Split the transactions of a block into groups that can be executed independently of
+/// each other. The indices of every group are in block order.
+pub(super) fn conflict_groups(txs: &[Transaction]) -> Vec<Vec<usize>> {
+    let ids: HashSet<TxId> = txs.iter().map(Transaction::id).collect();
+    let mut parents: Vec<usize> = (0..txs.len()).collect();
+    let mut first_user = HashMap::new();
+    for (idx, tx) in txs.iter().enumerate() {
+        for conflict in conflicts(tx, &ids) {
+            match first_user.entry(conflict) {
+                Entry::Occupied(entry) => union(&mut parents, *entry.get(), idx),
+                Entry::Vacant(entry) => {
+                    entry.insert(idx);
+                }
+            }
+        }
+    }
+
+    let mut groups: BTreeMap<usize, Vec<usize>> = BTreeMap::new();
+    for idx in 0..txs.len() {
+        let root = find(&mut parents, idx);
+        groups.entry(root).or_default().push(idx);
+    }
+    groups.into_values().collect()
+}
+
+/// Assign the groups to at most `workers` workers, balancing the number of transactions.
+/// Returns the worker of every transaction, workers are numbered from zero without gaps.
+pub(super) fn schedule(mut groups: Vec<Vec<usize>>, workers: usize) -> Vec<usize> {
+    let len = groups.iter().map(Vec::len).sum();
+    let workers = workers.clamp(1, groups.len().max(1));
+    // largest groups first, so the smaller ones even out the load
+    groups.sort_by_key(|group| std::cmp::Reverse(group.len()));
+
+    let mut load = vec![0; workers];
+    let mut worker_of = vec![0; len];
+    for group in groups {
+        let (worker, _) = load
+            .iter()
+            .enumerate()
+            .min_by_key(|(_, load)| **load)
+            .expect("there is at least one worker");
+        load[worker] += group.len();
+        for idx in group {
+            worker_of[idx] = worker;
+        }
+    }
+    worker_of
+}
+
+fn conflicts<'a>(
+    tx: &'a Transaction,
+    block_tx_ids: &'a HashSet<TxId>,
+) -> impl Iterator<Item = Conflict> + 'a {
+    let inputs = tx.inputs().iter().flat_map(move |input| {
+        let (first, second) = match input {
+            Input::CoinSigned { utxo_id, .. } | Input::CoinPredicate { utxo_id, .. } => (
+                Some(Conflict::Coin(*utxo_id)),
+                // the coin is created by a transaction of the same block
+                block_tx_ids
+                    .get(utxo_id.tx_id())
+                    .map(|tx_id| Conflict::Transaction(*tx_id)),
+            ),
+            Input::MessageSigned { message_id, .. }
+            | Input::MessagePredicate { message_id, .. } => {
+                (Some(Conflict::Message(*message_id)), None)
+            }
+            Input::Contract { .. } => (None, None),
+        };
+        first.into_iter().chain(second)
+    });
+    std::iter::once(Conflict::Transaction(tx.id()))
+        .chain(inputs)
+        .chain(touched_contracts(tx).map(Conflict::Contract))
+}
+
+fn find(parents: &mut [usize], mut idx: usize) -> usize {
+    while parents[idx] != idx {
+        parents[idx] = parents[parents[idx]];
+        idx = parents[idx];
+    }
+    idx
+}
+
+fn union(parents: &mut [usize], a: usize, b: usize) {
+    let (a, b) = (find(parents, a), find(parents, b));
+    // the earliest transaction is the root
+    parents[a.max(b)] = a.min(b);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fuel_core_interfaces::common::{
+        fuel_tx::TransactionBuilder,
+        fuel_types::{
+            Address,
+            AssetId,
+        },
+    };
+
+    fn tx(inputs: Vec<Input>) -> Transaction {
+        let mut builder = TransactionBuilder::script(vec![], vec![]);
+        for input in inputs {
+            builder.add_input(input);
+        }
+        builder.finalize_without_signature()
+    }
+
+    fn coin(utxo_id: UtxoId) -> Input {
+        Input::coin_signed(
+            utxo_id,
+            Address::zeroed(),
+            100,
+            AssetId::default(),
+            Default::default(),
+            0,
+            0,
+        )
+    }
+
+    fn contract(contract_id: ContractId) -> Input {
+        Input::contract(
+            Default::default(),
+            Default::default(),
+            Default::default(),
+            Default::default(),
+            contract_id,
+        )
+    }
+
+    #[test]
+    fn independent_transactions_are_split() {
+        let txs = vec![
+            tx(vec![coin(UtxoId::new([1; 32].into(), 0))]),
+            tx(vec![coin(UtxoId::new([2; 32].into(), 0))]),
+            tx(vec![contract([3; 32].into())]),
+        ];
+        assert_eq!(conflict_groups(&txs), vec![vec![0], vec![1], vec![2]]);
+    }
+
+    #[test]
+    fn shared_inputs_and_contracts_are_grouped() {
+        let shared_coin = UtxoId::new([1; 32].into(), 0);
+        let shared_contract = [2; 32].into();
+        let txs = vec![
+            tx(vec![coin(shared_coin)]),
+            tx(vec![contract(shared_contract)]),
+            tx(vec![coin(UtxoId::new([3; 32].into(), 0))]),
+            tx(vec![
+                coin(UtxoId::new([4; 32].into(), 0)),
+                contract(shared_contract),
+            ]),
+            tx(vec![coin(shared_coin)]),
+        ];
+        assert_eq!(conflict_groups(&txs), vec![vec![0, 4], vec![1, 3], vec![2]]);
+    }
+
+    #[test]
+    fn spending_outputs_of_the_block_is_grouped() {
+        let first = tx(vec![coin(UtxoId::new([1; 32].into(), 0))]);
+        let second = tx(vec![coin(UtxoId::new(first.id(), 0))]);
+        let third = tx(vec![coin(UtxoId::new(first.id(), 1))]);
+        let txs = vec![first, tx(vec![]), second, third];
+        assert_eq!(conflict_groups(&txs), vec![vec![0, 2, 3], vec![1]]);
+    }
+
+    #[test]
+    fn schedule_balances_workers() {
+        let groups = vec![vec![0, 1, 2], vec![3], vec![4], vec![5, 6]];
+        let worker_of = schedule(groups, 2);
+        assert_eq!(worker_of, vec![0, 0, 0, 1, 0, 1, 1]);
+
+        // never more workers than groups
+        let worker_of = schedule(vec![vec![0], vec![1]], 8);
+        assert_eq!(worker_of, vec![0, 1]);
+    }
+}
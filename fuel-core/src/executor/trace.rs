@@ -0,0 +1,354 @@
+use fuel_core_interfaces::common::{
+    fuel_asm::{
+        Opcode,
+        Word,
+    },
+    fuel_tx::{
+        AssetId,
+        Bytes32,
+        Receipt,
+    },
+    fuel_types::ContractId,
+};
+#[cfg(feature = "debug")]
+use {
+    super::{
+        Error,
+        ExecutionMode,
+        Executor,
+    },
+    crate::{
+        database::Database,
+        model::FuelBlockDb,
+        tx_pool::TransactionStatus,
+    },
+    fuel_core_interfaces::common::{
+        fuel_storage::Storage,
+        fuel_tx::{
+            CheckedTransaction,
+            Transaction,
+        },
+        fuel_vm::{
+            consts::{
+                REG_GGAS,
+                REG_PC,
+                WORD_SIZE,
+            },
+            prelude::{
+                DebugEval,
+                Interpreter,
+                InterpreterError,
+                MerkleStorage,
+                ProgramState,
+            },
+        },
+    },
+    std::ops::{
+        Deref,
+        DerefMut,
+    },
+};
+
+/// Structured trace of the replay of a committed transaction.
+#[derive(Clone, Debug)]
+pub struct TransactionTrace {
+    /// Receipts of the replay, executed without single stepping.
+    pub receipts: Vec<Receipt>,
+    /// Calls between contracts in the order they were made.
+    pub calls: Vec<CallTrace>,
+    /// Every executed instruction.
+    pub steps: Vec<TraceStep>,
+    /// Storage slots read and written by the contracts.
+    pub storage: Vec<StorageAccess>,
+    /// Instructions that reverted the transaction.
+    pub reverts: Vec<RevertTrace>,
+}
+
+#[derive(Clone, Debug)]
+pub struct CallTrace {
+    /// Number of calls on the stack before this one, zero for calls made by the script.
+    pub depth: usize,
+    /// Zero when the caller is the script.
+    pub caller: ContractId,
+    pub contract_id: ContractId,
+    pub amount: Word,
+    pub asset_id: AssetId,
+    /// Gas forwarded to the call.
+    pub gas: Word,
+}
+
+#[derive(Clone, Debug)]
+pub struct TraceStep {
+    /// Contract running the instruction, `None` for the script.
+    pub contract_id: Option<ContractId>,
+    /// Offset of the instruction from the start of the code.
+    pub pc: Word,
+    pub opcode: Opcode,
+    /// Remaining global gas before the instruction.
+    pub gas: Word,
+    /// Gas charged for the instruction.
+    pub gas_used: Word,
+}
+
+#[derive(Clone, Debug)]
+pub struct StorageAccess {
+    /// Index of the step that accessed the slot.
+    pub step: usize,
+    pub contract_id: ContractId,
+    pub key: Bytes32,
+    /// Value read, or written by the instruction.
+    pub value: Bytes32,
+    pub write: bool,
+}
+
+#[derive(Clone, Debug)]
+pub struct RevertTrace {
+    /// Index of the step that reverted.
+    pub step: usize,
+    pub reason: String,
+    /// Registers before the instruction was executed.
+    pub registers: Vec<Word>,
+}
+
+#[cfg(feature = "debug")]
+impl Executor {
+    /// Replay a committed transaction on the state it was executed on, in a view of the
+    /// database that is never committed. Returns `None` if the transaction isn't part of a
+    /// block.
+    pub fn trace_transaction(
+        &self,
+        tx_id: &Bytes32,
+    ) -> Result<Option<TransactionTrace>, Error> {
+        let block_id = match self.database.get_tx_status(tx_id)? {
+            Some(
+                TransactionStatus::Success { block_id, .. }
+                | TransactionStatus::Failed { block_id, .. },
+            ) => block_id,
+            _ => return Ok(None),
+        };
+        let block = Storage::<Bytes32, FuelBlockDb>::get(&self.database, &block_id)?
+            .ok_or(Error::MissingTransactionData {
+                block_id,
+                transaction_id: *tx_id,
+            })?
+            .into_owned();
+        let get_tx = |transaction_id: &Bytes32| {
+            Storage::<Bytes32, Transaction>::get(&self.database, transaction_id)?
+                .map(|tx| tx.into_owned())
+                .ok_or(Error::MissingTransactionData {
+                    block_id,
+                    transaction_id: *transaction_id,
+                })
+        };
+        let tx = get_tx(tx_id)?;
+
+        // the state before the transaction: the parent block, the headers of the block and
+        // the transactions that come before it in the block
+        let mut view = self.database.transaction();
+        view.rollback_to(u32::from(block.headers.height).saturating_sub(1).into())?;
+        Storage::<Bytes32, FuelBlockDb>::insert(
+            view.deref_mut(),
+            &Bytes32::zeroed(),
+            &block,
+        )?;
+        for earlier_id in block.transactions.iter().take_while(|id| *id != tx_id) {
+            self.execute_transaction(
+                &mut get_tx(earlier_id)?,
+                &block.headers,
+                &ExecutionMode::Validation,
+                view.deref_mut(),
+            )?;
+        }
+
+        let params = self.config.chain_conf.transaction_parameters;
+        let checked_tx =
+            CheckedTransaction::check_unsigned(tx, block.headers.height.into(), &params)?;
+        let vm_error = |error| Error::VmExecution {
+            error,
+            transaction_id: *tx_id,
+        };
+
+        // receipts are taken from a regular run, single stepping interrupts the script
+        // before its result is known
+        let receipts = {
+            let db = view.transaction();
+            Interpreter::with_storage(db.deref().clone(), params)
+                .transact(checked_tx.clone())
+                .map_err(vm_error)?
+                .receipts()
+                .to_vec()
+        };
+        let calls = call_tree(&receipts);
+
+        let db = view.transaction();
+        let mut vm = Interpreter::with_storage(db.deref().clone(), params);
+        vm.set_single_stepping(true);
+        let mut state = vm
+            .transact(checked_tx)
+            .map(|state| *state.state())
+            .map_err(vm_error)?;
+
+        let mut steps: Vec<TraceStep> = vec![];
+        let mut storage = vec![];
+        let mut reverts = vec![];
+        let mut registers = vec![];
+        loop {
+            let breakpoint = match state {
+                ProgramState::RunProgram(DebugEval::Breakpoint(breakpoint)) => breakpoint,
+                ProgramState::Revert(ra) => {
+                    if let Some(step) = steps.len().checked_sub(1) {
+                        reverts.push(RevertTrace {
+                            step,
+                            reason: format!("Revert({})", ra),
+                            registers,
+                        });
+                    }
+                    break
+                }
+                _ => break,
+            };
+            registers = vm.registers().to_vec();
+            let pc = registers[REG_PC] as usize;
+            let opcode = match vm.memory().get(pc..pc + Opcode::LEN) {
+                Some(bytes) => Opcode::from_bytes(bytes)
+                    .map_err(|e| vm_error(InterpreterError::Io(e)))?,
+                None => break,
+            };
+            if let Some(last) = steps.last_mut() {
+                last.gas_used = last.gas.saturating_sub(registers[REG_GGAS]);
+            }
+            let contract_id =
+                Some(*breakpoint.contract()).filter(|id| id != &Default::default());
+            if let Some(contract_id) = contract_id {
+                if let Some(access) = storage_access(
+                    db.deref(),
+                    vm.memory(),
+                    &registers,
+                    contract_id,
+                    opcode,
+                )? {
+                    storage.push(StorageAccess {
+                        step: steps.len(),
+                        ..access
+                    });
+                }
+            }
+            steps.push(TraceStep {
+                contract_id,
+                pc: breakpoint.pc(),
+                opcode,
+                gas: registers[REG_GGAS],
+                gas_used: 0,
+            });
+
+            state = match vm.resume() {
+                Ok(state) => state,
+                Err(error) => match error.instruction_result() {
+                    Some(result) => {
+                        reverts.push(RevertTrace {
+                            step: steps.len() - 1,
+                            reason: format!("{}", result.reason()),
+                            registers,
+                        });
+                        break
+                    }
+                    None => return Err(vm_error(error)),
+                },
+            };
+        }
+        if let Some(last) = steps.last_mut() {
+            last.gas_used = last.gas.saturating_sub(vm.registers()[REG_GGAS]);
+        }
+
+        Ok(Some(TransactionTrace {
+            receipts,
+            calls,
+            steps,
+            storage,
+            reverts,
+        }))
+    }
+}
+
+/// Calls made by the transaction, with their depth in the call stack.
+#[cfg(feature = "debug")]
+fn call_tree(receipts: &[Receipt]) -> Vec<CallTrace> {
+    let mut calls = vec![];
+    let mut stack: Vec<ContractId> = vec![];
+    for receipt in receipts {
+        match receipt {
+            Receipt::Call {
+                id,
+                to,
+                amount,
+                asset_id,
+                gas,
+                ..
+            } => {
+                calls.push(CallTrace {
+                    depth: stack.len(),
+                    caller: *id,
+                    contract_id: *to,
+                    amount: *amount,
+                    asset_id: *asset_id,
+                    gas: *gas,
+                });
+                stack.push(*to);
+            }
+            Receipt::Return { id, .. } | Receipt::ReturnData { id, .. }
+                if stack.last() == Some(id) =>
+            {
+                stack.pop();
+            }
+            _ => {}
+        }
+    }
+    calls
+}
+
+/// Storage slot accessed by `opcode`, read before the instruction is executed.
+#[cfg(feature = "debug")]
+fn storage_access(
+    db: &Database,
+    memory: &[u8],
+    registers: &[Word],
+    contract_id: ContractId,
+    opcode: Opcode,
+) -> Result<Option<StorageAccess>, Error> {
+    let bytes32_at = |register: usize| {
+        let start = registers[register] as usize;
+        memory
+            .get(start..start.saturating_add(Bytes32::LEN))
+            .and_then(|bytes| Bytes32::try_from(bytes).ok())
+    };
+    let (key, value, write) = match opcode {
+        Opcode::SRW(_, rb) | Opcode::SRWQ(_, rb) => {
+            let key = match bytes32_at(rb) {
+                Some(key) => key,
+                None => return Ok(None),
+            };
+            let value = MerkleStorage::<ContractId, Bytes32, Bytes32>::get(
+                db,
+                &contract_id,
+                &key,
+            )?
+            .map(|value| value.into_owned())
+            .unwrap_or_default();
+            (Some(key), Some(value), false)
+        }
+        Opcode::SWW(ra, rb) => {
+            let mut value = Bytes32::zeroed();
+            value[..WORD_SIZE].copy_from_slice(&registers[rb].to_be_bytes());
+            (bytes32_at(ra), Some(value), true)
+        }
+        Opcode::SWWQ(ra, rb) => (bytes32_at(ra), bytes32_at(rb), true),
+        _ => return Ok(None),
+    };
+    Ok(key.zip(value).map(|(key, value)| StorageAccess {
+        step: 0,
+        contract_id,
+        key,
+        value,
+        write,
+    }))
+}
@@ -5,6 +5,7 @@ pub mod executor;
 pub mod model;
 pub mod schema;
 pub mod service;
+pub mod signer;
 pub mod state;
 pub mod tx_pool;
 
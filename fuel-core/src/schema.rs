@@ -15,6 +15,7 @@ pub mod health;
 pub mod message;
 pub mod node_info;
 pub mod scalars;
+pub mod sync;
 pub mod tx;
 
 #[derive(MergedObject, Default)]
@@ -30,6 +31,7 @@ pub struct Query(
     contract::ContractBalanceQuery,
     node_info::NodeQuery,
     message::MessageQuery,
+    sync::SyncQuery,
 );
 
 #[derive(MergedObject, Default)]
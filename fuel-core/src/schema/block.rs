@@ -3,15 +3,10 @@ use crate::{
         Database,
         KvStoreError,
     },
-    executor::{
-        ExecutionMode,
-        Executor,
-    },
     model::{
         BlockHeight,
         FuelBlock,
         FuelBlockDb,
-        FuelBlockHeader,
     },
     schema::{
         scalars::{
@@ -38,16 +33,26 @@ use chrono::{
     DateTime,
     Utc,
 };
-use fuel_core_interfaces::common::{
-    fuel_storage::Storage,
-    fuel_tx,
-    fuel_types,
+use fuel_block_importer::Service as BlockImporterService;
+use fuel_block_producer::Service as BlockProducerService;
+use fuel_core_bft::Service as BftService;
+use fuel_core_interfaces::{
+    bft::BftMpsc,
+    block_importer::ImportBlockMpsc,
+    block_producer::BlockProducerMpsc,
+    common::{
+        fuel_storage::Storage,
+        fuel_tx,
+        fuel_types,
+    },
 };
 use itertools::Itertools;
 use std::{
     borrow::Cow,
     convert::TryInto,
+    sync::Arc,
 };
+use tokio::sync::oneshot;
 
 use super::scalars::Address;
 
@@ -247,31 +252,10 @@ impl BlockMutation {
             )
         }
 
-        let executor = Executor {
-            database: db.clone(),
-            config: cfg.clone(),
-        };
-
         let iterate: u64 = blocks_to_produce.into();
 
         for _ in 0..iterate {
-            let current_height = db.get_block_height()?.unwrap_or_default();
-            let current_hash = db.get_block_id(current_height)?.unwrap_or_default();
-            let new_block_height = current_height + 1u32.into();
-
-            let mut block = FuelBlock {
-                header: FuelBlockHeader {
-                    height: new_block_height,
-                    parent_hash: current_hash,
-                    time: Utc::now(),
-                    ..Default::default()
-                },
-                transactions: vec![],
-            };
-
-            executor
-                .execute(&mut block, ExecutionMode::Production)
-                .await?;
+            produce_block(ctx).await?;
         }
 
         db.get_block_height()?
@@ -279,3 +263,52 @@ impl BlockMutation {
             .ok_or("Block height not found")?
     }
 }
+
+/// Request the next block from the block producer and import it through the
+/// block importer. The txpool prunes the transactions of the block on the import event.
+pub(crate) async fn produce_block(ctx: &Context<'_>) -> anyhow::Result<FuelBlock> {
+    let db = ctx.data_unchecked::<Database>();
+    let block_producer = ctx.data_unchecked::<Arc<BlockProducerService>>();
+    let block_importer = ctx.data_unchecked::<Arc<BlockImporterService>>();
+    let bft = ctx.data_unchecked::<Arc<BftService>>();
+
+    let height = db.get_block_height()?.unwrap_or_default() + 1u32.into();
+    let (response, receiver) = oneshot::channel();
+    block_producer
+        .sender()
+        .send(BlockProducerMpsc::Produce { height, response })
+        .await?;
+    let block = Arc::new(*receiver.await??);
+
+    let (response, receiver) = oneshot::channel();
+    block_importer
+        .sender()
+        .send(ImportBlockMpsc::ImportFuelBlock {
+            block: block.clone(),
+            response,
+        })
+        .await?;
+    receiver.await??;
+
+    let (ret, receiver) = oneshot::channel();
+    bft.sender()
+        .send(BftMpsc::SealBlock {
+            block: block.clone(),
+            ret,
+        })
+        .await?;
+    let consensus = receiver.await??;
+
+    let (response, receiver) = oneshot::channel();
+    block_importer
+        .sender()
+        .send(ImportBlockMpsc::SealFuelBlock {
+            consensus,
+            block_id: block.id(),
+            response,
+        })
+        .await?;
+    receiver.await??;
+
+    Ok(block.as_ref().clone())
+}
@@ -11,10 +11,14 @@ use async_graphql::{
     Context,
     Object,
 };
-use fuel_core_interfaces::common::{
-    fuel_storage::Storage,
-    fuel_tx,
-    fuel_types,
+use fuel_core_interfaces::{
+    common::{
+        fuel_storage::Storage,
+        fuel_tx,
+        fuel_types,
+    },
+    db::ChainDb,
+    p2p::PeerInfo as PeerInfoModel,
 };
 
 pub const DEFAULT_NAME: &str = "Fuel.testnet";
@@ -97,12 +101,14 @@ impl ChainInfo {
         Ok(Block(block.into_owned()))
     }
 
-    async fn base_chain_height(&self) -> U64 {
-        0.into()
+    /// Finalized height of the DA layer, as seen by the relayer.
+    async fn base_chain_height(&self, ctx: &Context<'_>) -> async_graphql::Result<U64> {
+        let db = ctx.data_unchecked::<Database>();
+        Ok(db.finalized_da_height()?.into())
     }
 
-    async fn peer_count(&self) -> u16 {
-        0
+    async fn peer_count(&self, ctx: &Context<'_>) -> async_graphql::Result<u16> {
+        Ok(u16::try_from(peers(ctx).await?.len()).unwrap_or(u16::MAX))
     }
 
     async fn consensus_parameters(
@@ -117,6 +123,28 @@ impl ChainInfo {
     }
 }
 
+pub struct PeerInfo(PeerInfoModel);
+
+#[Object]
+impl PeerInfo {
+    async fn id(&self) -> &str {
+        &self.0.id
+    }
+
+    async fn addresses(&self) -> &Vec<String> {
+        &self.0.addresses
+    }
+
+    async fn client_version(&self) -> Option<&str> {
+        self.0.client_version.as_deref()
+    }
+
+    /// Round trip time of the latest ping in milliseconds.
+    async fn latest_ping(&self) -> Option<U64> {
+        self.0.latest_ping.map(|ping| U64(ping.as_millis() as u64))
+    }
+}
+
 #[derive(Default)]
 pub struct ChainQuery;
 
@@ -125,4 +153,20 @@ impl ChainQuery {
     async fn chain(&self) -> ChainInfo {
         ChainInfo
     }
+
+    /// Peers the node is connected to.
+    async fn peers(&self, ctx: &Context<'_>) -> async_graphql::Result<Vec<PeerInfo>> {
+        Ok(peers(ctx).await?.into_iter().map(PeerInfo).collect())
+    }
+}
+
+#[cfg(feature = "p2p")]
+async fn peers(ctx: &Context<'_>) -> anyhow::Result<Vec<PeerInfoModel>> {
+    let network = ctx.data_unchecked::<std::sync::Arc<fuel_p2p::orchestrator::Service>>();
+    network.peers().await
+}
+
+#[cfg(not(feature = "p2p"))]
+async fn peers(_ctx: &Context<'_>) -> anyhow::Result<Vec<PeerInfoModel>> {
+    Ok(vec![])
 }
@@ -1,10 +1,12 @@
 use crate::{
     database::{
+        contract_roots::StorageProof as StorageProofModel,
         Database,
         KvStoreError,
     },
     schema::scalars::{
         AssetId,
+        Bytes32,
         ContractId,
         HexString,
         Salt,
@@ -72,6 +74,66 @@ impl Contract {
     }
 }
 
+/// Proof of a storage slot of a contract against the contracts root of a block.
+pub struct StorageProof {
+    proof: StorageProofModel,
+    block_height: u64,
+}
+
+#[Object]
+impl StorageProof {
+    async fn contract_id(&self) -> ContractId {
+        self.proof.contract_id.into()
+    }
+
+    async fn key(&self) -> Bytes32 {
+        self.proof.key.into()
+    }
+
+    async fn value(&self) -> Bytes32 {
+        self.proof.value.into()
+    }
+
+    async fn block_height(&self) -> U64 {
+        self.block_height.into()
+    }
+
+    /// Root of the state of the contract after the block.
+    async fn state_root(&self) -> Bytes32 {
+        self.proof.roots.state_root.into()
+    }
+
+    /// Root of the balances of the contract after the block.
+    async fn balance_root(&self) -> Bytes32 {
+        self.proof.roots.balance_root.into()
+    }
+
+    /// Side nodes of the slot in the state tree of the contract, from the root down.
+    async fn state_proof_set(&self) -> Vec<Bytes32> {
+        self.proof
+            .state_proof_set
+            .iter()
+            .copied()
+            .map(Into::into)
+            .collect()
+    }
+
+    /// Contracts root of the block.
+    async fn contracts_root(&self) -> Bytes32 {
+        self.proof.contracts_root.into()
+    }
+
+    /// Side nodes of the contract in the contracts tree of the block, from the root down.
+    async fn contract_proof_set(&self) -> Vec<Bytes32> {
+        self.proof
+            .contract_proof_set
+            .iter()
+            .copied()
+            .map(Into::into)
+            .collect()
+    }
+}
+
 #[derive(Default)]
 pub struct ContractQuery;
 
@@ -92,6 +154,35 @@ impl ContractQuery {
         let contract = Contract(id);
         Ok(Some(contract))
     }
+
+    /// Proof of a storage slot of a contract touched by the block at `block_height`,
+    /// null if the contract isn't touched by the block or the slot isn't set.
+    async fn storage_proof(
+        &self,
+        ctx: &Context<'_>,
+        block_height: U64,
+        contract: ContractId,
+        key: Bytes32,
+    ) -> async_graphql::Result<Option<StorageProof>> {
+        let db = ctx.data_unchecked::<Database>();
+        let block_height: u64 = block_height.into();
+        let proof =
+            match db.storage_proof(block_height.into(), &contract.into(), &key.into())? {
+                Some(proof) => proof,
+                None => return Ok(None),
+            };
+        if !proof.verify() {
+            return Err(anyhow!(
+                "Storage proof doesn't match the roots of block {}",
+                block_height
+            )
+            .into())
+        }
+        Ok(Some(StorageProof {
+            proof,
+            block_height,
+        }))
+    }
 }
 
 pub struct ContractBalance {
@@ -1,10 +1,16 @@
 use super::scalars::{
     Address,
+    BlockId,
+    Bytes32,
+    HexString,
     MessageId,
     U64,
 };
 use crate::{
-    database::Database,
+    database::{
+        outbox::MessageProof as MessageProofModel,
+        Database,
+    },
     state::IterDirection,
 };
 use anyhow::anyhow;
@@ -65,6 +71,68 @@ impl Message {
     }
 }
 
+/// Proof that a message sent to the Da layer is part of the outbox of its block.
+pub struct MessageProof {
+    proof: MessageProofModel,
+    block_id: fuel_types::Bytes32,
+}
+
+#[Object]
+impl MessageProof {
+    async fn message_id(&self) -> MessageId {
+        self.proof.message.id().into()
+    }
+
+    async fn sender(&self) -> Address {
+        self.proof.message.sender.into()
+    }
+
+    async fn recipient(&self) -> Address {
+        self.proof.message.recipient.into()
+    }
+
+    async fn nonce(&self) -> Bytes32 {
+        self.proof.message.nonce.into()
+    }
+
+    async fn amount(&self) -> U64 {
+        self.proof.message.amount.into()
+    }
+
+    async fn data(&self) -> HexString {
+        HexString(self.proof.message.data.clone())
+    }
+
+    /// Block that sent the message.
+    async fn block_id(&self) -> BlockId {
+        self.block_id.into()
+    }
+
+    async fn block_height(&self) -> U64 {
+        self.proof.message.block_height.into()
+    }
+
+    /// Message outbox root of the block.
+    async fn root(&self) -> Bytes32 {
+        self.proof.root.into()
+    }
+
+    /// Index of the message in the outbox of the block.
+    async fn proof_index(&self) -> U64 {
+        self.proof.message.index.into()
+    }
+
+    /// Hashes from the leaf of the message up to the root.
+    async fn proof_set(&self) -> Vec<Bytes32> {
+        self.proof
+            .proof_set
+            .iter()
+            .copied()
+            .map(Into::into)
+            .collect()
+    }
+}
+
 #[derive(Default)]
 pub struct MessageQuery {}
 
@@ -177,4 +245,21 @@ impl MessageQuery {
         )
         .await
     }
+
+    /// Inclusion proof of a message sent to the Da layer, used by the bridge to relay it.
+    async fn message_proof(
+        &self,
+        ctx: &Context<'_>,
+        message_id: MessageId,
+    ) -> async_graphql::Result<Option<MessageProof>> {
+        let db = ctx.data_unchecked::<Database>();
+        let proof = match db.message_proof(&message_id.into())? {
+            Some(proof) => proof,
+            None => return Ok(None),
+        };
+        let block_id = db
+            .get_block_id(proof.message.block_height)?
+            .ok_or(KvStoreError::NotFound)?;
+        Ok(Some(MessageProof { proof, block_id }))
+    }
 }
@@ -0,0 +1,69 @@
+use crate::schema::scalars::U64;
+use async_graphql::{
+    Context,
+    Enum,
+    Object,
+};
+use fuel_core_interfaces::sync::{
+    SyncMpsc,
+    SyncProgress,
+    SyncStatus as SyncStatusModel,
+};
+use fuel_sync::Service as SyncService;
+use std::sync::Arc;
+use tokio::sync::oneshot;
+
+#[derive(Enum, Copy, Clone, Eq, PartialEq)]
+#[graphql(remote = "SyncStatusModel")]
+pub enum SyncState {
+    Stopped,
+    InitialSync,
+    Synced,
+    NoPeers,
+}
+
+pub struct SyncStatus(SyncProgress);
+
+#[Object]
+impl SyncStatus {
+    async fn state(&self) -> SyncState {
+        self.0.status.into()
+    }
+
+    /// Height of the local chain.
+    async fn current_height(&self) -> U64 {
+        self.0.current_height.into()
+    }
+
+    /// Highest block height known to exist on other nodes.
+    async fn peer_height(&self) -> U64 {
+        self.0.peer_height.into()
+    }
+
+    /// Blocks imported per second since the node started to catch up.
+    async fn blocks_per_second(&self) -> f64 {
+        self.0.blocks_per_second
+    }
+
+    /// Estimated seconds to catch up with the peers, null if the node doesn't import
+    /// blocks while behind.
+    async fn estimated_time_to_sync(&self) -> Option<U64> {
+        self.0
+            .time_to_sync()
+            .map(|time| U64(time.as_secs_f64().ceil() as u64))
+    }
+}
+
+#[derive(Default)]
+pub struct SyncQuery;
+
+#[Object]
+impl SyncQuery {
+    async fn sync_status(&self, ctx: &Context<'_>) -> async_graphql::Result<SyncStatus> {
+        let sync = ctx.data_unchecked::<Arc<SyncService>>();
+
+        let (ret, receiver) = oneshot::channel();
+        sync.sender().send(SyncMpsc::Status { ret }).await?;
+        Ok(SyncStatus(receiver.await?))
+    }
+}
@@ -9,15 +9,19 @@ use crate::{
         BlockHeight,
         FuelBlockDb,
     },
-    schema::scalars::{
-        Address,
-        Bytes32,
-        HexString,
-        SortedTxCursor,
-        TransactionId,
+    schema::{
+        block::produce_block,
+        scalars::{
+            Address,
+            Bytes32,
+            HexString,
+            SortedTxCursor,
+            TransactionId,
+        },
     },
     service::Config,
     state::IterDirection,
+    tx_pool::TransactionStatus as TxStatus,
 };
 use anyhow::anyhow;
 use async_graphql::{
@@ -38,13 +42,17 @@ use fuel_core_interfaces::{
             Receipt as FuelReceipt,
             Transaction as FuelTx,
         },
-        fuel_types,
+        fuel_types::{
+            self,
+            Word,
+        },
         fuel_vm::prelude::Deserializable,
     },
     txpool::TxPoolMpsc,
 };
 use fuel_txpool::Service as TxPoolService;
 use itertools::Itertools;
+use state_override::StateOverrides;
 use std::{
     borrow::Cow,
     iter,
@@ -55,11 +63,18 @@ use tokio::sync::{
     oneshot,
     Mutex,
 };
-use types::Transaction;
+use trace::TransactionTrace;
+use types::{
+    DryRunTransactionResult,
+    GasEstimate,
+    Transaction,
+};
 
 pub mod input;
 pub mod output;
 pub mod receipt;
+pub mod state_override;
+pub mod trace;
 pub mod types;
 
 #[derive(Default)]
@@ -305,6 +320,76 @@ impl TxQuery {
         )
         .await
     }
+
+    /// Estimate the smallest gas limit with which the transaction succeeds, by searching
+    /// between the gas used with the maximum gas limit and the maximum. Inputs are not
+    /// validated since changing the gas limit invalidates the signatures.
+    async fn estimate_gas(
+        &self,
+        ctx: &Context<'_>,
+        tx: HexString,
+    ) -> async_graphql::Result<GasEstimate> {
+        let mut tx = FuelTx::from_bytes(&tx.0)?;
+        // the price doesn't change the execution, only the fee paid by the inputs
+        tx.set_gas_price(0);
+        let max_gas = ctx
+            .data_unchecked::<Config>()
+            .chain_conf
+            .transaction_parameters
+            .max_gas_per_tx;
+
+        let gas_used = estimate_gas_run(ctx, &tx, max_gas)
+            .await?
+            .map_err(|reason| anyhow!(reason))?;
+        let (mut low, mut high) = (gas_used, max_gas);
+        while low < high {
+            let gas_limit = low + (high - low) / 2;
+            if estimate_gas_run(ctx, &tx, gas_limit).await?.is_ok() {
+                high = gas_limit;
+            } else {
+                low = gas_limit + 1;
+            }
+        }
+
+        let gas_price = ctx
+            .data_unchecked::<Arc<TxPoolService>>()
+            .sender()
+            .gas_price()
+            .await?;
+        Ok(GasEstimate {
+            gas_limit: high,
+            gas_used,
+            gas_price,
+        })
+    }
+
+    /// Replay a committed transaction instruction by instruction. The replay is never
+    /// committed.
+    #[cfg(feature = "debug")]
+    async fn trace_transaction(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "The ID of the transaction")] id: TransactionId,
+    ) -> async_graphql::Result<Option<TransactionTrace>> {
+        let executor = Executor {
+            database: ctx.data_unchecked::<Database>().clone(),
+            config: ctx.data_unchecked::<Config>().clone(),
+        };
+        Ok(executor.trace_transaction(&id.0)?.map(TransactionTrace))
+    }
+
+    /// Replay a committed transaction instruction by instruction. The replay is never
+    /// committed.
+    #[cfg(not(feature = "debug"))]
+    async fn trace_transaction(
+        &self,
+        _ctx: &Context<'_>,
+        #[graphql(desc = "The ID of the transaction")] _id: TransactionId,
+    ) -> async_graphql::Result<Option<TransactionTrace>> {
+        Err(async_graphql::Error::new(
+            "Feature 'debug' is not compiled in",
+        ))
+    }
 }
 
 #[derive(Default)]
@@ -323,23 +408,23 @@ impl TxMutation {
         // This allows for non-existent inputs to be used without signature validation
         // for read-only calls.
         utxo_validation: Option<bool>,
+        // State injected into the database view before the execution.
+        overrides: Option<StateOverrides>,
     ) -> async_graphql::Result<Vec<receipt::Receipt>> {
         let transaction = ctx.data_unchecked::<Database>().transaction();
-        let mut cfg = ctx.data_unchecked::<Config>().clone();
-        // override utxo_validation if set
-        if let Some(utxo_validation) = utxo_validation {
-            cfg.utxo_validation = utxo_validation;
-        }
         let mut tx = FuelTx::from_bytes(&tx.0)?;
         tx.precompute_metadata();
         let id = tx.id();
 
+        if let Some(overrides) = overrides {
+            overrides.apply(&mut transaction.deref().clone())?;
+        }
         // make executor from transaction database view.
-        let executor = Executor {
-            database: transaction.deref().clone(),
-            config: cfg.clone(),
-        };
+        let executor = dry_run_executor(ctx, transaction.deref(), utxo_validation);
         executor.submit_txs(vec![Arc::new(tx)]).await?;
+        if let Some(TxStatus::Rejected { reason, .. }) = transaction.get_tx_status(&id)? {
+            return Err(anyhow!(reason).into())
+        }
         // get receipts from db transaction
         let receipts =
             Storage::<FuelBytes32, Vec<FuelReceipt>>::get(transaction.deref(), &id)?
@@ -347,49 +432,145 @@ impl TxMutation {
         Ok(receipts.iter().map(Into::into).collect())
     }
 
+    /// Execute a dry-run of the transactions in order in one block, later transactions may
+    /// spend the outputs of earlier ones. No changes are committed.
+    async fn dry_run_batch(
+        &self,
+        ctx: &Context<'_>,
+        txs: Vec<HexString>,
+        // If set to false, disable input utxo validation, overriding the configuration of the node.
+        utxo_validation: Option<bool>,
+    ) -> async_graphql::Result<Vec<DryRunTransactionResult>> {
+        let transaction = ctx.data_unchecked::<Database>().transaction();
+        let txs = txs
+            .iter()
+            .map(|tx| {
+                let mut tx = FuelTx::from_bytes(&tx.0)?;
+                tx.precompute_metadata();
+                Ok(Arc::new(tx))
+            })
+            .collect::<Result<Vec<_>, std::io::Error>>()?;
+        let ids = txs.iter().map(|tx| tx.id()).collect_vec();
+
+        let executor = dry_run_executor(ctx, transaction.deref(), utxo_validation);
+        executor.submit_txs(txs).await?;
+
+        ids.into_iter()
+            .map(|id| {
+                let receipts = Storage::<FuelBytes32, Vec<FuelReceipt>>::get(
+                    transaction.deref(),
+                    &id,
+                )?
+                .map(Cow::into_owned)
+                .unwrap_or_default();
+                let status = transaction
+                    .get_tx_status(&id)?
+                    .ok_or(KvStoreError::NotFound)?
+                    .try_into()?;
+                Ok(DryRunTransactionResult {
+                    id,
+                    receipts,
+                    status,
+                })
+            })
+            .collect()
+    }
+
     /// Submits transaction to the txpool
     async fn submit(
         &self,
         ctx: &Context<'_>,
         tx: HexString,
     ) -> async_graphql::Result<Transaction> {
-        let db = ctx.data_unchecked::<Database>();
         let txpool = ctx.data_unchecked::<Arc<TxPoolService>>();
-        let cfg = ctx.data_unchecked::<Config>().clone();
         let mut tx = FuelTx::from_bytes(&tx.0)?;
         tx.precompute_metadata();
 
         // only allow one block to be produced at a time
         let _block_production_guard = self.block_production_lock.lock().await;
 
-        let includable = if cfg.utxo_validation {
-            // include transaction
-            let ret = txpool.sender().insert(vec![Arc::new(tx.clone())]).await?;
-            ret.get(0).unwrap().as_ref()?;
-
-            // get includable transactions
-            let txs = txpool.sender().includable().await?;
-
-            txpool
-                .sender()
-                .remove(txs.iter().map(|tx| tx.id()).collect())
-                .await?;
-            txs
-        } else {
-            vec![Arc::new(tx.clone())]
-        };
+        // include transaction
+        let ret = txpool.sender().insert(vec![Arc::new(tx.clone())]).await?;
+        ret.get(0).unwrap().as_ref()?;
 
         // next part can be extracted to separate endpoint that will trigger block building
-        // just include call to txpool.includable
-
-        let executor = Executor {
-            database: db.clone(),
-            config: cfg.clone(),
+        let block = match produce_block(ctx).await {
+            Ok(block) => block,
+            Err(e) => {
+                // don't let a transaction that failed block production stay in the txpool
+                txpool.sender().remove(vec![tx.id()]).await?;
+                return Err(e.into())
+            }
         };
-        executor.submit_txs(includable).await?;
+        if !block
+            .transactions
+            .iter()
+            .any(|included| included.id() == tx.id())
+        {
+            let db = ctx.data_unchecked::<Database>();
+            let reason = match db.get_tx_status(&tx.id())? {
+                Some(TxStatus::Rejected { reason, .. }) => reason,
+                _ => "Transaction wasn't included into the block".to_string(),
+            };
+            return Err(anyhow!(reason).into())
+        }
 
         // probably need to fetch executed tx that is now in db.
         let tx = Transaction(tx);
         Ok(tx)
     }
 }
+
+/// Executor for dry runs on top of `database`, usually a database transaction that is
+/// never committed.
+fn dry_run_executor(
+    ctx: &Context<'_>,
+    database: &Database,
+    utxo_validation: Option<bool>,
+) -> Executor {
+    let mut config = ctx.data_unchecked::<Config>().clone();
+    // override utxo_validation if set
+    if let Some(utxo_validation) = utxo_validation {
+        config.utxo_validation = utxo_validation;
+    }
+    Executor {
+        database: database.clone(),
+        config,
+    }
+}
+
+/// Dry run `tx` with `gas_limit` and return the gas used, or the reason why the transaction
+/// didn't succeed.
+async fn estimate_gas_run(
+    ctx: &Context<'_>,
+    tx: &FuelTx,
+    gas_limit: Word,
+) -> anyhow::Result<Result<Word, String>> {
+    let transaction = ctx.data_unchecked::<Database>().transaction();
+    let mut tx = tx.clone();
+    tx.set_gas_limit(gas_limit);
+    tx.precompute_metadata();
+    let id = tx.id();
+
+    let executor = dry_run_executor(ctx, transaction.deref(), Some(false));
+    executor.submit_txs(vec![Arc::new(tx)]).await?;
+    match transaction.get_tx_status(&id)? {
+        Some(TxStatus::Success { .. }) => {
+            let receipts =
+                Storage::<FuelBytes32, Vec<FuelReceipt>>::get(transaction.deref(), &id)?
+                    .unwrap_or_default();
+            let gas_used = receipts
+                .iter()
+                .find_map(|receipt| match receipt {
+                    FuelReceipt::ScriptResult { gas_used, .. } => Some(*gas_used),
+                    _ => None,
+                })
+                .unwrap_or_default();
+            Ok(Ok(gas_used))
+        }
+        Some(TxStatus::Failed { reason, .. } | TxStatus::Rejected { reason, .. }) => {
+            Ok(Err(reason))
+        }
+        _ => Err(anyhow!("Transaction wasn't executed")),
+    }
+}
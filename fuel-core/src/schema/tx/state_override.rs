@@ -0,0 +1,156 @@
+use crate::{
+    database::Database,
+    model::{
+        Coin,
+        CoinStatus,
+    },
+    schema::scalars,
+};
+use async_graphql::InputObject;
+use fuel_core_interfaces::{
+    common::{
+        fuel_storage::Storage,
+        fuel_tx::{
+            Contract,
+            UtxoId,
+        },
+        fuel_types::{
+            AssetId,
+            Bytes32,
+            ContractId,
+            MessageId,
+            Salt,
+            Word,
+        },
+        fuel_vm::prelude::MerkleStorage,
+    },
+    model::Message,
+};
+
+/// State injected into the database view of a dry run before the execution, it is never
+/// committed.
+#[derive(InputObject)]
+pub struct StateOverrides {
+    /// Coins to add, replacing the coins with the same utxo ids
+    coins: Option<Vec<CoinOverride>>,
+    /// Messages to add, their ids are computed from the fields
+    messages: Option<Vec<MessageOverride>>,
+    /// Code, balances and storage slots of contracts
+    contracts: Option<Vec<ContractOverride>>,
+}
+
+#[derive(InputObject)]
+pub struct CoinOverride {
+    utxo_id: scalars::UtxoId,
+    owner: scalars::Address,
+    amount: scalars::U64,
+    asset_id: scalars::AssetId,
+    maturity: Option<scalars::U64>,
+}
+
+#[derive(InputObject)]
+pub struct MessageOverride {
+    sender: scalars::Address,
+    recipient: scalars::Address,
+    owner: scalars::Address,
+    nonce: scalars::U64,
+    amount: scalars::U64,
+    data: scalars::HexString,
+    da_height: Option<scalars::U64>,
+}
+
+#[derive(InputObject)]
+pub struct ContractOverride {
+    contract_id: scalars::ContractId,
+    /// Bytecode of the contract, the contract is created if it doesn't exist
+    bytecode: Option<scalars::HexString>,
+    /// Balances of the contract, other assets keep their balances
+    balances: Option<Vec<ContractBalanceOverride>>,
+    /// Storage slots of the contract, other slots keep their values
+    storage: Option<Vec<StorageSlotOverride>>,
+}
+
+#[derive(InputObject)]
+pub struct ContractBalanceOverride {
+    asset_id: scalars::AssetId,
+    amount: scalars::U64,
+}
+
+#[derive(InputObject)]
+pub struct StorageSlotOverride {
+    key: scalars::Bytes32,
+    value: scalars::Bytes32,
+}
+
+impl StateOverrides {
+    /// Write the overrides into `db`, which must be a database transaction that is never
+    /// committed.
+    pub(crate) fn apply(&self, db: &mut Database) -> anyhow::Result<()> {
+        for coin in self.coins.iter().flatten() {
+            let _ = Storage::<UtxoId, Coin>::insert(
+                db,
+                &coin.utxo_id.0,
+                &Coin {
+                    owner: coin.owner.0,
+                    amount: coin.amount.0,
+                    asset_id: coin.asset_id.0,
+                    maturity: coin.maturity.map(|h| h.0).unwrap_or_default().into(),
+                    status: CoinStatus::Unspent,
+                    block_created: Default::default(),
+                },
+            )?;
+        }
+
+        for message in self.messages.iter().flatten() {
+            let message = Message {
+                sender: message.sender.0,
+                recipient: message.recipient.0,
+                owner: message.owner.0,
+                nonce: message.nonce.0,
+                amount: message.amount.0,
+                data: message.data.0.clone(),
+                da_height: message.da_height.map(|h| h.0).unwrap_or_default(),
+                fuel_block_spend: None,
+            };
+            let _ = Storage::<MessageId, Message>::insert(db, &message.id(), &message)?;
+        }
+
+        for contract in self.contracts.iter().flatten() {
+            let contract_id = &contract.contract_id.0;
+            if let Some(bytecode) = &contract.bytecode {
+                let code = Contract::from(bytecode.0.as_slice());
+                let _ = Storage::<ContractId, Contract>::insert(db, contract_id, &code)?;
+                let _ = Storage::<ContractId, (Salt, Bytes32)>::insert(
+                    db,
+                    contract_id,
+                    &(Salt::zeroed(), code.root()),
+                )?;
+                // contract inputs must refer to the utxo of the contract
+                if Storage::<ContractId, UtxoId>::get(db, contract_id)?.is_none() {
+                    let _ = Storage::<ContractId, UtxoId>::insert(
+                        db,
+                        contract_id,
+                        &Default::default(),
+                    )?;
+                }
+            }
+            for balance in contract.balances.iter().flatten() {
+                let _ = MerkleStorage::<ContractId, AssetId, Word>::insert(
+                    db,
+                    contract_id,
+                    &balance.asset_id.0,
+                    &balance.amount.0,
+                )?;
+            }
+            for slot in contract.storage.iter().flatten() {
+                let _ = MerkleStorage::<ContractId, Bytes32, Bytes32>::insert(
+                    db,
+                    contract_id,
+                    &slot.key.0,
+                    &slot.value.0,
+                )?;
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,148 @@
+use super::receipt::Receipt;
+use crate::{
+    executor::trace,
+    schema::scalars::{
+        AssetId,
+        Bytes32,
+        ContractId,
+        U64,
+    },
+};
+use async_graphql::Object;
+
+pub struct TransactionTrace(pub(crate) trace::TransactionTrace);
+
+#[Object]
+impl TransactionTrace {
+    /// Receipts of the replay.
+    async fn receipts(&self) -> Vec<Receipt> {
+        self.0.receipts.iter().map(Into::into).collect()
+    }
+
+    /// Calls between contracts in the order they were made.
+    async fn calls(&self) -> Vec<CallTrace> {
+        self.0.calls.iter().cloned().map(CallTrace).collect()
+    }
+
+    /// Every executed instruction.
+    async fn steps(&self) -> Vec<TraceStep> {
+        self.0.steps.iter().cloned().map(TraceStep).collect()
+    }
+
+    /// Storage slots read and written by the contracts.
+    async fn storage(&self) -> Vec<StorageAccess> {
+        self.0.storage.iter().cloned().map(StorageAccess).collect()
+    }
+
+    /// Instructions that reverted the transaction.
+    async fn reverts(&self) -> Vec<RevertTrace> {
+        self.0.reverts.iter().cloned().map(RevertTrace).collect()
+    }
+}
+
+pub struct CallTrace(trace::CallTrace);
+
+#[Object]
+impl CallTrace {
+    /// Number of calls on the stack before this one, zero for calls made by the script.
+    async fn depth(&self) -> U64 {
+        U64(self.0.depth as u64)
+    }
+
+    /// Zero when the caller is the script.
+    async fn caller(&self) -> ContractId {
+        self.0.caller.into()
+    }
+
+    async fn contract_id(&self) -> ContractId {
+        self.0.contract_id.into()
+    }
+
+    async fn amount(&self) -> U64 {
+        self.0.amount.into()
+    }
+
+    async fn asset_id(&self) -> AssetId {
+        self.0.asset_id.into()
+    }
+
+    /// Gas forwarded to the call.
+    async fn gas(&self) -> U64 {
+        self.0.gas.into()
+    }
+}
+
+pub struct TraceStep(trace::TraceStep);
+
+#[Object]
+impl TraceStep {
+    /// Contract running the instruction, null for the script.
+    async fn contract_id(&self) -> Option<ContractId> {
+        self.0.contract_id.map(Into::into)
+    }
+
+    /// Offset of the instruction from the start of the code.
+    async fn pc(&self) -> U64 {
+        self.0.pc.into()
+    }
+
+    async fn opcode(&self) -> String {
+        format!("{:?}", self.0.opcode)
+    }
+
+    /// Remaining global gas before the instruction.
+    async fn gas(&self) -> U64 {
+        self.0.gas.into()
+    }
+
+    /// Gas charged for the instruction.
+    async fn gas_used(&self) -> U64 {
+        self.0.gas_used.into()
+    }
+}
+
+pub struct StorageAccess(trace::StorageAccess);
+
+#[Object]
+impl StorageAccess {
+    /// Index of the step that accessed the slot.
+    async fn step(&self) -> U64 {
+        U64(self.0.step as u64)
+    }
+
+    async fn contract_id(&self) -> ContractId {
+        self.0.contract_id.into()
+    }
+
+    async fn key(&self) -> Bytes32 {
+        self.0.key.into()
+    }
+
+    /// Value read, or written by the instruction.
+    async fn value(&self) -> Bytes32 {
+        self.0.value.into()
+    }
+
+    async fn write(&self) -> bool {
+        self.0.write
+    }
+}
+
+pub struct RevertTrace(trace::RevertTrace);
+
+#[Object]
+impl RevertTrace {
+    /// Index of the step that reverted.
+    async fn step(&self) -> U64 {
+        U64(self.0.step as u64)
+    }
+
+    async fn reason(&self) -> String {
+        self.0.reason.clone()
+    }
+
+    /// Registers before the instruction was executed.
+    async fn registers(&self) -> Vec<U64> {
+        self.0.registers.iter().copied().map(Into::into).collect()
+    }
+}
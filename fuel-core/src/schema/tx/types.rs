@@ -96,6 +96,7 @@ pub enum TransactionStatus {
     Submitted(SubmittedStatus),
     Success(SuccessStatus),
     Failed(FailureStatus),
+    Rejected(RejectedStatus),
 }
 
 pub struct SubmittedStatus(DateTime<Utc>);
@@ -164,6 +165,126 @@ impl FailureStatus {
     }
 }
 
+pub struct RejectedStatus {
+    time: DateTime<Utc>,
+    reason: String,
+}
+
+#[Object]
+impl RejectedStatus {
+    async fn time(&self) -> DateTime<Utc> {
+        self.time
+    }
+
+    async fn reason(&self) -> String {
+        self.reason.clone()
+    }
+}
+
+/// Status of a transaction executed by a dry run, its block is never committed.
+#[derive(Union)]
+pub enum DryRunTransactionStatus {
+    Success(DryRunSuccessStatus),
+    Failed(DryRunFailureStatus),
+    Rejected(RejectedStatus),
+}
+
+pub struct DryRunSuccessStatus {
+    result: VmProgramState,
+}
+
+#[Object]
+impl DryRunSuccessStatus {
+    async fn program_state(&self) -> ProgramState {
+        self.result.into()
+    }
+}
+
+pub struct DryRunFailureStatus {
+    reason: String,
+    state: Option<VmProgramState>,
+}
+
+#[Object]
+impl DryRunFailureStatus {
+    async fn reason(&self) -> String {
+        self.reason.clone()
+    }
+
+    async fn program_state(&self) -> Option<ProgramState> {
+        self.state.map(Into::into)
+    }
+}
+
+impl TryFrom<TxStatus> for DryRunTransactionStatus {
+    type Error = anyhow::Error;
+
+    fn try_from(status: TxStatus) -> Result<Self, Self::Error> {
+        Ok(match status {
+            TxStatus::Submitted { .. } => {
+                return Err(anyhow::anyhow!("Transaction wasn't executed"))
+            }
+            TxStatus::Success { result, .. } => {
+                DryRunTransactionStatus::Success(DryRunSuccessStatus { result })
+            }
+            TxStatus::Failed { reason, result, .. } => {
+                DryRunTransactionStatus::Failed(DryRunFailureStatus {
+                    reason,
+                    state: result,
+                })
+            }
+            TxStatus::Rejected { time, reason } => {
+                DryRunTransactionStatus::Rejected(RejectedStatus { time, reason })
+            }
+        })
+    }
+}
+
+pub struct DryRunTransactionResult {
+    pub(crate) id: fuel_types::Bytes32,
+    pub(crate) receipts: Vec<fuel_tx::Receipt>,
+    pub(crate) status: DryRunTransactionStatus,
+}
+
+#[Object]
+impl DryRunTransactionResult {
+    async fn id(&self) -> TransactionId {
+        TransactionId(self.id)
+    }
+
+    async fn receipts(&self) -> Vec<Receipt> {
+        self.receipts.iter().map(Into::into).collect()
+    }
+
+    async fn status(&self) -> &DryRunTransactionStatus {
+        &self.status
+    }
+}
+
+pub struct GasEstimate {
+    pub(crate) gas_limit: fuel_types::Word,
+    pub(crate) gas_used: fuel_types::Word,
+    pub(crate) gas_price: fuel_types::Word,
+}
+
+#[Object]
+impl GasEstimate {
+    /// Smallest gas limit with which the transaction succeeds.
+    async fn gas_limit(&self) -> U64 {
+        self.gas_limit.into()
+    }
+
+    /// Gas used by the transaction when executed with the maximum gas limit.
+    async fn gas_used(&self) -> U64 {
+        self.gas_used.into()
+    }
+
+    /// Gas price for the transaction to be accepted by the txpool.
+    async fn gas_price(&self) -> U64 {
+        self.gas_price.into()
+    }
+}
+
 impl From<TxStatus> for TransactionStatus {
     fn from(s: TxStatus) -> Self {
         match s {
@@ -190,6 +311,9 @@ impl From<TxStatus> for TransactionStatus {
                 time,
                 state: result,
             }),
+            TxStatus::Rejected { time, reason } => {
+                TransactionStatus::Rejected(RejectedStatus { time, reason })
+            }
         }
     }
 }
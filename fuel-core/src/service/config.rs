@@ -1,4 +1,7 @@
-use crate::chain_config::ChainConfig;
+use crate::{
+    chain_config::ChainConfig,
+    signer::SignerConfig,
+};
 use std::{
     net::{
         Ipv4Addr,
@@ -26,12 +29,20 @@ pub struct Config {
     // default to false until predicates have fully stabilized
     pub predicates: bool,
     pub manual_blocks_enabled: bool,
+    /// Execute the transactions of a block that don't share inputs or contracts
+    /// concurrently. Requires a multi-threaded tokio runtime.
+    pub parallel_execution: bool,
     pub vm: VMConfig,
     pub txpool: fuel_txpool::Config,
+    /// Keep the transactions of the txpool in the database, so they are restored after a
+    /// restart.
+    pub txpool_journal: bool,
     pub block_importer: fuel_block_importer::Config,
     pub block_producer: fuel_block_producer::Config,
     pub block_executor: fuel_block_executor::Config,
     pub bft: fuel_core_bft::Config,
+    /// Key that signs the blocks, consensus votes and block commits of this node.
+    pub signer: SignerConfig,
     pub sync: fuel_sync::Config,
     #[cfg(feature = "relayer")]
     pub relayer: fuel_relayer::Config,
@@ -47,14 +58,17 @@ impl Config {
             database_type: DbType::InMemory,
             chain_conf: ChainConfig::local_testnet(),
             manual_blocks_enabled: false,
+            parallel_execution: false,
             vm: Default::default(),
             utxo_validation: false,
             predicates: false,
             txpool: Default::default(),
+            txpool_journal: false,
             block_importer: Default::default(),
             block_producer: Default::default(),
             block_executor: Default::default(),
             bft: Default::default(),
+            signer: Default::default(),
             sync: Default::default(),
             #[cfg(feature = "relayer")]
             relayer: Default::default(),
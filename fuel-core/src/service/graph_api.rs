@@ -73,6 +73,8 @@ pub async fn start_server(
         .data(modules.block_producer.clone())
         .data(modules.sync.clone())
         .data(modules.bft.clone());
+    #[cfg(feature = "p2p")]
+    let schema = schema.data(modules.network_service.clone());
     let schema = dap::init(schema, params).extension(Tracing).finish();
 
     let router = Router::new()
@@ -1,6 +1,7 @@
-#![allow(clippy::let_unit_value)]
 use crate::{
+    chain_config::ProductionStrategy,
     database::Database,
+    executor::Executor,
     service::Config,
 };
 use anyhow::Result;
@@ -8,7 +9,13 @@ use anyhow::Result;
 use fuel_core_interfaces::p2p::P2pDb;
 #[cfg(feature = "relayer")]
 use fuel_core_interfaces::relayer::RelayerDb;
-use fuel_core_interfaces::txpool::TxPoolDb;
+use fuel_core_interfaces::{
+    bft::BftDb,
+    block_importer::BlockImporterDb,
+    block_producer::BlockProducerDb,
+    sync::SyncDb,
+    txpool::TxPoolDb,
+};
 use futures::future::join_all;
 use std::sync::Arc;
 use tokio::{
@@ -48,14 +55,45 @@ impl Modules {
 }
 
 pub async fn start_modules(config: &Config, database: &Database) -> Result<Modules> {
-    let db = ();
+    let signer = config.signer.signer().await?;
+    let bft_config = fuel_core_bft::Config {
+        // the proof-of-authority producers are part of the chain config
+        producers: match &config.chain_conf.block_production {
+            ProductionStrategy::RoundRobin { producers } => producers.clone(),
+            _ => vec![],
+        },
+        proof_of_stake: matches!(
+            config.chain_conf.block_production,
+            ProductionStrategy::ProofOfStake
+        ),
+        consensus_key: signer.clone(),
+        ..config.bft.clone()
+    };
+
     // Initialize and bind all components
-    let block_importer =
-        fuel_block_importer::Service::new(&config.block_importer, db).await?;
-    let block_producer =
-        fuel_block_producer::Service::new(&config.block_producer, db).await?;
-    let bft = fuel_core_bft::Service::new(&config.bft, db).await?;
-    let sync = fuel_sync::Service::new(&config.sync).await?;
+    let block_importer = fuel_block_importer::Service::new(
+        &config.block_importer,
+        Arc::new(database.clone()) as Arc<dyn BlockImporterDb>,
+    )
+    .await?;
+    let block_producer = fuel_block_producer::Service::new(
+        &fuel_block_producer::Config {
+            producer: bft_config.producer_address().unwrap_or_default(),
+            ..config.block_producer.clone()
+        },
+        Arc::new(database.clone()) as Arc<dyn BlockProducerDb>,
+    )
+    .await?;
+    let bft = fuel_core_bft::Service::new(
+        &bft_config,
+        Arc::new(database.clone()) as Arc<dyn BftDb>,
+    )
+    .await?;
+    let sync = fuel_sync::Service::new(
+        &config.sync,
+        Arc::new(database.clone()) as Arc<dyn SyncDb>,
+    )
+    .await?;
 
     // create builders
     #[cfg(feature = "relayer")]
@@ -67,13 +105,11 @@ pub async fn start_modules(config: &Config, database: &Database) -> Result<Modul
     relayer_builder
         .config(config.relayer.clone())
         .db(Box::new(database.clone()) as Box<dyn RelayerDb>)
-        .import_block_event(block_importer.subscribe())
-        .private_key(
-            hex::decode(
-                "c6bd905dcac2a0b1c43f574ab6933df14d7ceee0194902bce523ed054e8e798b",
-            )
-            .unwrap(),
-        );
+        .import_block_event(block_importer.subscribe());
+    #[cfg(feature = "relayer")]
+    if let Some(signer) = signer {
+        relayer_builder.signer(signer);
+    }
 
     let relayer_sender = {
         #[cfg(feature = "relayer")]
@@ -87,26 +123,45 @@ pub async fn start_modules(config: &Config, database: &Database) -> Result<Modul
     };
 
     txpool_builder
-        .config(config.txpool.clone())
+        .config(fuel_txpool::Config {
+            // the txpool follows the validation settings of the node
+            utxo_validation: config.utxo_validation,
+            predicates: config.predicates,
+            consensus_params: config.chain_conf.transaction_parameters,
+            ..config.txpool.clone()
+        })
         .db(Box::new(database.clone()) as Box<dyn TxPoolDb>)
         .import_block_event(block_importer.subscribe());
+    if config.txpool_journal {
+        txpool_builder.journal(Box::new(database.clone()));
+    }
 
     #[cfg(feature = "p2p")]
     let (tx_request_event, rx_request_event) = mpsc::channel(100);
     #[cfg(feature = "p2p")]
     let (tx_block, rx_block) = mpsc::channel(100);
+    #[cfg(feature = "p2p")]
+    let (tx_consensus, rx_consensus) = mpsc::channel(100);
 
     #[cfg(not(feature = "p2p"))]
     let (tx_request_event, _) = mpsc::channel(100);
     #[cfg(not(feature = "p2p"))]
     let (_, rx_block) = mpsc::channel(100);
-
-    block_importer.start().await;
-
-    block_producer.start(txpool_builder.sender().clone()).await;
+    #[cfg(not(feature = "p2p"))]
+    let (_, rx_consensus) = mpsc::channel(100);
+
+    let executor = Arc::new(Executor {
+        database: database.clone(),
+        config: config.clone(),
+    });
+    block_importer.start(executor.clone()).await;
+    block_producer
+        .start(txpool_builder.sender().clone(), executor)
+        .await;
     bft.start(
-        relayer_sender.clone(),
+        relayer_sender,
         tx_request_event.clone(),
+        rx_consensus,
         block_producer.sender().clone(),
         block_importer.sender().clone(),
         block_importer.subscribe(),
@@ -116,7 +171,6 @@ pub async fn start_modules(config: &Config, database: &Database) -> Result<Modul
     sync.start(
         rx_block,
         tx_request_event.clone(),
-        relayer_sender,
         bft.sender().clone(),
         block_importer.sender().clone(),
     )
@@ -125,7 +179,7 @@ pub async fn start_modules(config: &Config, database: &Database) -> Result<Modul
     // build services
     #[cfg(feature = "relayer")]
     let relayer = relayer_builder.build()?;
-    let txpool = txpool_builder.build()?;
+    let txpool = txpool_builder.build().await?;
 
     // start services
     #[cfg(feature = "relayer")]
@@ -137,8 +191,6 @@ pub async fn start_modules(config: &Config, database: &Database) -> Result<Modul
     #[cfg(feature = "p2p")]
     let p2p_db: Arc<dyn P2pDb> = Arc::new(database.clone());
     #[cfg(feature = "p2p")]
-    let (tx_consensus, _) = mpsc::channel(100);
-    #[cfg(feature = "p2p")]
     let (tx_transaction, _) = mpsc::channel(100);
 
     #[cfg(feature = "p2p")]
@@ -0,0 +1,66 @@
+use fuel_core_interfaces::{
+    common::fuel_crypto::SecretKey,
+    signer::{
+        InMemorySigner,
+        Signer,
+    },
+};
+use std::{
+    fmt,
+    path::PathBuf,
+    sync::Arc,
+};
+
+pub mod keystore;
+#[cfg(unix)]
+pub mod remote;
+
+/// Where the node gets the key that signs its blocks, consensus votes and block
+/// commits from.
+#[derive(Clone, Default)]
+pub enum SignerConfig {
+    /// The node doesn't sign anything.
+    #[default]
+    None,
+    /// Secret key held in memory.
+    Key(SecretKey),
+    /// Encrypted JSON keystore file, decrypted with the passphrase at startup.
+    Keystore { path: PathBuf, passphrase: String },
+    /// Signer process listening on the Unix socket at the path.
+    #[cfg(unix)]
+    Remote(PathBuf),
+}
+
+impl SignerConfig {
+    pub async fn signer(&self) -> anyhow::Result<Option<Arc<dyn Signer>>> {
+        let signer: Arc<dyn Signer> = match self {
+            SignerConfig::None => return Ok(None),
+            SignerConfig::Key(key) => Arc::new(InMemorySigner::new(*key)),
+            SignerConfig::Keystore { path, passphrase } => {
+                Arc::new(InMemorySigner::new(keystore::decrypt(path, passphrase)?))
+            }
+            #[cfg(unix)]
+            SignerConfig::Remote(path) => {
+                Arc::new(remote::RemoteSigner::connect(path).await?)
+            }
+        };
+        Ok(Some(signer))
+    }
+}
+
+// do not log the secret key or the passphrase
+impl fmt::Debug for SignerConfig {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SignerConfig::None => f.write_str("None"),
+            SignerConfig::Key(key) => {
+                f.debug_tuple("Key").field(&key.public_key()).finish()
+            }
+            SignerConfig::Keystore { path, .. } => {
+                f.debug_struct("Keystore").field("path", path).finish()
+            }
+            #[cfg(unix)]
+            SignerConfig::Remote(path) => f.debug_tuple("Remote").field(path).finish(),
+        }
+    }
+}
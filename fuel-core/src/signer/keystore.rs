@@ -0,0 +1,56 @@
+use fuel_core_interfaces::{
+    common::fuel_crypto::SecretKey,
+    signer::SignerError,
+};
+use rand::rngs::OsRng;
+use std::path::{
+    Path,
+    PathBuf,
+};
+
+/// Decrypt the secret key of an encrypted JSON keystore file. Keystores use the
+/// format of Ethereum keystores, so existing tooling can create them.
+pub fn decrypt(
+    path: impl AsRef<Path>,
+    passphrase: &str,
+) -> Result<SecretKey, SignerError> {
+    let bytes = eth_keystore::decrypt_key(path, passphrase)
+        .map_err(|e| SignerError::Keystore(e.to_string()))?;
+    SecretKey::try_from(bytes.as_slice())
+        .map_err(|e| SignerError::Keystore(e.to_string()))
+}
+
+/// Encrypt the secret key into a new keystore file in `dir` and return its path.
+pub fn encrypt(
+    dir: impl AsRef<Path>,
+    key: &SecretKey,
+    passphrase: &str,
+) -> Result<PathBuf, SignerError> {
+    let name =
+        eth_keystore::encrypt_key(&dir, &mut OsRng, key.as_ref(), passphrase, None)
+            .map_err(|e| SignerError::Keystore(e.to_string()))?;
+    Ok(dir.as_ref().join(name))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::{
+        rngs::StdRng,
+        SeedableRng,
+    };
+
+    #[test]
+    fn decrypts_encrypted_key() {
+        let dir = tempfile::tempdir().unwrap();
+        let key = SecretKey::random(&mut StdRng::seed_from_u64(2322));
+
+        let path = encrypt(dir.path(), &key, "passphrase").unwrap();
+
+        assert_eq!(decrypt(&path, "passphrase").unwrap(), key);
+        assert!(matches!(
+            decrypt(&path, "wrong passphrase"),
+            Err(SignerError::Keystore(_))
+        ));
+    }
+}
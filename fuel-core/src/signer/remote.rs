@@ -0,0 +1,240 @@
+use crate::chain_config::serialization::{
+    HexPublicKey,
+    HexType,
+};
+use async_trait::async_trait;
+use fuel_core_interfaces::{
+    common::{
+        fuel_crypto::{
+            Message,
+            PublicKey,
+            Signature,
+        },
+        fuel_types::{
+            Bytes32,
+            Bytes64,
+        },
+    },
+    signer::{
+        Signer,
+        SignerError,
+    },
+};
+use serde::{
+    Deserialize,
+    Serialize,
+};
+use serde_with::serde_as;
+use std::{
+    path::{
+        Path,
+        PathBuf,
+    },
+    sync::Arc,
+};
+use tokio::{
+    io::{
+        AsyncBufReadExt,
+        AsyncWriteExt,
+        BufReader,
+    },
+    net::{
+        UnixListener,
+        UnixStream,
+    },
+};
+use tracing::warn;
+
+/// Request to the remote signer, sent as one line of JSON.
+#[serde_as]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "method", rename_all = "snake_case")]
+pub enum Request {
+    PublicKey,
+    Sign {
+        #[serde_as(as = "HexType")]
+        message: Bytes32,
+    },
+}
+
+/// Response of the remote signer, sent as one line of JSON.
+#[serde_as]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Response {
+    PublicKey(#[serde_as(as = "HexPublicKey")] PublicKey),
+    Signature(#[serde_as(as = "HexType")] Bytes64),
+    Error(String),
+}
+
+/// Signer that asks a local signer process to sign messages over a Unix socket, so
+/// the key never enters the node.
+///
+/// Each request opens a new connection, writes a `Request` line and reads back a
+/// `Response` line:
+///
+/// ```text
+/// {"method":"public_key"}                -> {"public_key":"0x..."}
+/// {"method":"sign","message":"0x..."}    -> {"signature":"0x..."}
+///                                        -> {"error":"..."}
+/// ```
+#[derive(Debug)]
+pub struct RemoteSigner {
+    path: PathBuf,
+    public_key: PublicKey,
+}
+
+impl RemoteSigner {
+    /// Connect to the signer listening on the socket at `path` and fetch its public key.
+    pub async fn connect(path: impl Into<PathBuf>) -> Result<Self, SignerError> {
+        let path = path.into();
+        match send(&path, &Request::PublicKey).await? {
+            Response::PublicKey(public_key) => Ok(Self { path, public_key }),
+            response => Err(unexpected(response)),
+        }
+    }
+}
+
+#[async_trait]
+impl Signer for RemoteSigner {
+    fn public_key(&self) -> PublicKey {
+        self.public_key
+    }
+
+    async fn sign(&self, message: &Message) -> Result<Signature, SignerError> {
+        let request = Request::Sign {
+            message: Bytes32::new(**message),
+        };
+        match send(&self.path, &request).await? {
+            Response::Signature(signature) => {
+                let signature = Signature::from(signature);
+                // don't pass on signatures of another key
+                signature.verify(&self.public_key, message).map_err(|_| {
+                    SignerError::Remote(
+                        "Signature doesn't match the public key".to_string(),
+                    )
+                })?;
+                Ok(signature)
+            }
+            response => Err(unexpected(response)),
+        }
+    }
+}
+
+/// Answer the requests of remote signers connecting to the listener with `signer`,
+/// used by the `signer` subcommand.
+pub async fn serve(listener: UnixListener, signer: Arc<dyn Signer>) {
+    loop {
+        let stream = match listener.accept().await {
+            Ok((stream, _)) => stream,
+            Err(e) => {
+                warn!("Remote signer failed to accept connection: {}", e);
+                continue
+            }
+        };
+        let signer = signer.clone();
+        tokio::spawn(async move {
+            if let Err(e) = handle_connection(stream, signer.as_ref()).await {
+                warn!("Remote signer connection failed: {}", e);
+            }
+        });
+    }
+}
+
+async fn handle_connection(
+    stream: UnixStream,
+    signer: &dyn Signer,
+) -> anyhow::Result<()> {
+    let (reader, mut writer) = stream.into_split();
+    let mut lines = BufReader::new(reader).lines();
+    while let Some(line) = lines.next_line().await? {
+        let response = match serde_json::from_str(&line) {
+            Ok(Request::PublicKey) => Response::PublicKey(signer.public_key()),
+            Ok(Request::Sign { message }) => {
+                // Safety: anyone who can connect to the socket may sign any message
+                // with the key, access is restricted by the permissions of the socket.
+                // Nodes only send hashes, so the signer doesn't have to hash again.
+                let message = unsafe { Message::from_bytes_unchecked(*message) };
+                match signer.sign(&message).await {
+                    Ok(signature) => Response::Signature(signature.into()),
+                    Err(e) => Response::Error(e.to_string()),
+                }
+            }
+            Err(e) => Response::Error(format!("Invalid request: {}", e)),
+        };
+        write_line(&mut writer, &response).await?;
+    }
+    Ok(())
+}
+
+async fn send(path: &Path, request: &Request) -> Result<Response, SignerError> {
+    let remote =
+        |e: std::io::Error| SignerError::Remote(format!("{}: {}", path.display(), e));
+    let stream = UnixStream::connect(path).await.map_err(remote)?;
+    let (reader, mut writer) = stream.into_split();
+    write_line(&mut writer, request).await.map_err(remote)?;
+
+    let line = BufReader::new(reader)
+        .lines()
+        .next_line()
+        .await
+        .map_err(remote)?
+        .ok_or_else(|| SignerError::Remote("Connection closed".to_string()))?;
+    serde_json::from_str(&line)
+        .map_err(|e| SignerError::Remote(format!("Invalid response: {}", e)))
+}
+
+async fn write_line<W, T>(writer: &mut W, value: &T) -> std::io::Result<()>
+where
+    W: AsyncWriteExt + Unpin,
+    T: Serialize,
+{
+    let mut line = serde_json::to_vec(value)?;
+    line.push(b'\n');
+    writer.write_all(&line).await
+}
+
+fn unexpected(response: Response) -> SignerError {
+    match response {
+        Response::Error(e) => SignerError::Remote(e),
+        response => SignerError::Remote(format!("Unexpected response {:?}", response)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fuel_core_interfaces::{
+        common::fuel_crypto::SecretKey,
+        signer::InMemorySigner,
+    };
+    use rand::{
+        rngs::StdRng,
+        SeedableRng,
+    };
+
+    #[tokio::test]
+    async fn signs_with_remote_key() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("signer.sock");
+        let key = SecretKey::random(&mut StdRng::seed_from_u64(2322));
+        let listener = UnixListener::bind(&path).unwrap();
+        tokio::spawn(serve(listener, Arc::new(InMemorySigner::new(key))));
+
+        let signer = RemoteSigner::connect(&path).await.unwrap();
+        let message = Message::new(b"block");
+        let signature = signer.sign(&message).await.unwrap();
+
+        assert_eq!(signer.public_key(), key.public_key());
+        assert!(signature.verify(&key.public_key(), &message).is_ok());
+    }
+
+    #[tokio::test]
+    async fn fails_without_signer_listening() {
+        let dir = tempfile::tempdir().unwrap();
+
+        let result = RemoteSigner::connect(dir.path().join("signer.sock")).await;
+
+        assert!(matches!(result, Err(SignerError::Remote(_))));
+    }
+}
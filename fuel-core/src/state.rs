@@ -99,7 +99,7 @@ pub trait BatchOperations: KeyValueStore {
     }
 }
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub enum WriteOperation {
     Insert(Vec<u8>, ColumnId, Vec<u8>),
     Remove(Vec<u8>, ColumnId),
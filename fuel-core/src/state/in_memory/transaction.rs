@@ -46,6 +46,16 @@ impl MemoryTransactionView {
         }
     }
 
+    /// Copy of the pending changes, in no particular order.
+    pub fn changes(&self) -> Vec<WriteOperation> {
+        self.changes
+            .lock()
+            .expect("poisoned lock")
+            .values()
+            .cloned()
+            .collect()
+    }
+
     pub fn commit(&self) -> crate::state::Result<()> {
         self.data_source.batch_write(
             &mut self
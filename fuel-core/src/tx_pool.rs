@@ -27,4 +27,9 @@ pub enum TransactionStatus {
         reason: String,
         result: Option<ProgramState>,
     },
+    /// The transaction was dropped by the block producer without being executed.
+    Rejected {
+        time: DateTime<Utc>,
+        reason: String,
+    },
 }
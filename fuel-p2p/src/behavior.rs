@@ -411,7 +411,21 @@ impl<Codec: NetworkCodec>
                             Some(ResponseChannelItem::ResponseBlock(channel)),
                             Ok(ResponseMessage::ResponseBlock(block)),
                         ) => {
-                            if channel.send(block).is_err() {
+                            let sent = channel.send(*block);
+                            if sent.is_err() {
+                                debug!(
+                                    "Failed to send through the channel for {:?}",
+                                    request_id
+                                );
+                            }
+                        }
+
+                        (
+                            Some(ResponseChannelItem::ResponseHeight(channel)),
+                            Ok(ResponseMessage::ResponseHeight(height)),
+                        ) => {
+                            let sent = channel.send(height);
+                            if sent.is_err() {
                                 debug!(
                                     "Failed to send through the channel for {:?}",
                                     request_id
@@ -196,8 +196,11 @@ impl RequestResponseConverter for BincodeCodec {
     ) -> Result<Self::ResponseMessage, io::Error> {
         match inter_msg {
             IntermediateResponse::ResponseBlock(block_bytes) => Ok(
-                ResponseMessage::ResponseBlock(self.deserialize(block_bytes)?),
+                ResponseMessage::ResponseBlock(Box::new(self.deserialize(block_bytes)?)),
             ),
+            IntermediateResponse::ResponseHeight(height) => {
+                Ok(ResponseMessage::ResponseHeight(*height))
+            }
         }
     }
 
@@ -209,6 +212,9 @@ impl RequestResponseConverter for BincodeCodec {
             OutboundResponse::ResponseBlock(sealed_block) => Ok(
                 IntermediateResponse::ResponseBlock(self.serialize(&**sealed_block)?),
             ),
+            OutboundResponse::ResponseHeight(height) => {
+                Ok(IntermediateResponse::ResponseHeight(*height))
+            }
         }
     }
 }
@@ -6,6 +6,7 @@ use fuel_core_interfaces::p2p::{
     ConsensusBroadcast,
     P2pDb,
     P2pRequestEvent,
+    PeerInfo,
     TransactionBroadcast,
 };
 
@@ -16,6 +17,7 @@ use tokio::{
             Receiver,
             Sender,
         },
+        oneshot,
         Mutex,
     },
     task::JoinHandle,
@@ -98,13 +100,15 @@ impl NetworkOrchestrator {
                             FuelBehaviourEvent::GossipsubMessage { message, .. } => {
                                 match message {
                                     GossipsubMessage::NewTx(tx) => {
-                                        let _ = self.tx_transaction.send(TransactionBroadcast::NewTransaction(tx));
+                                        let _ = self.tx_transaction.send(TransactionBroadcast::NewTransaction(tx)).await;
                                     },
                                     GossipsubMessage::NewBlock(block) => {
-                                        let _ = self.tx_block.send(BlockBroadcast::NewBlock(block));
+                                        // blocks are proposals of the validators as well
+                                        let _ = self.tx_consensus.send(ConsensusBroadcast::NewBlock(block.clone())).await;
+                                        let _ = self.tx_block.send(BlockBroadcast::NewBlock(block)).await;
                                     },
                                     GossipsubMessage::ConsensusVote(vote) => {
-                                        let _ = self.tx_consensus.send(ConsensusBroadcast::NewVote(vote));
+                                        let _ = self.tx_consensus.send(ConsensusBroadcast::NewVote(vote)).await;
                                     },
                                 }
                             },
@@ -119,6 +123,10 @@ impl NetworkOrchestrator {
                                             let _ = tx_outbound_response.send(res);
                                         });
                                     }
+                                    RequestMessage::RequestHeight => {
+                                        let res = self.db.current_height().ok().map(|height| (OutboundResponse::ResponseHeight(height), request_id));
+                                        let _ = self.tx_outbound_responses.send(res).await;
+                                    }
                                 }
                             },
                             _ => {}
@@ -133,6 +141,19 @@ impl NetworkOrchestrator {
                                 let channel_item = ResponseChannelItem::ResponseBlock(response);
                                 let _ = p2p_service.send_request_msg(None, request_msg, channel_item);
                             },
+                            P2pRequestEvent::RequestHeight { response } => {
+                                let channel_item = ResponseChannelItem::ResponseHeight(response);
+                                let _ = p2p_service.send_request_msg(None, RequestMessage::RequestHeight, channel_item);
+                            },
+                            P2pRequestEvent::GetPeers { response } => {
+                                let peers = p2p_service.get_peers().iter().map(|(peer_id, info)| PeerInfo {
+                                    id: peer_id.to_string(),
+                                    addresses: info.peer_addresses.iter().map(|address| address.to_string()).collect(),
+                                    client_version: info.client_version.clone(),
+                                    latest_ping: info.latest_ping,
+                                }).collect();
+                                let _ = response.send(peers);
+                            },
                             P2pRequestEvent::BroadcastNewBlock { block } => {
                                 let broadcast = GossipsubBroadcastRequest::NewBlock(block);
                                 let _ = p2p_service.publish_message(broadcast);
@@ -211,6 +232,19 @@ impl Service {
         }
     }
 
+    /// Peers the node is connected to, none if the network isn't started.
+    pub async fn peers(&self) -> anyhow::Result<Vec<PeerInfo>> {
+        if self.join.lock().await.is_none() {
+            return Ok(vec![])
+        }
+        let (response, receiver) = oneshot::channel();
+        self.tx_request_event
+            .send(P2pRequestEvent::GetPeers { response })
+            .await
+            .map_err(|_| anyhow!("Network Orchestrator is stopped"))?;
+        Ok(receiver.await?)
+    }
+
     pub async fn stop(&self) -> Option<JoinHandle<()>> {
         let join_handle = self.join.lock().await.take();
 
@@ -232,11 +266,16 @@ impl Service {
 pub mod tests {
     use super::*;
     use async_trait::async_trait;
-    use fuel_core_interfaces::model::{
-        BlockHeight,
-        FuelBlock,
-        FuelBlockConsensus,
-        SealedFuelBlock,
+    use fuel_core_interfaces::{
+        common::fuel_types::Bytes32,
+        db::ChainDb,
+        model::{
+            BlockHeight,
+            DaBlockHeight,
+            FuelBlock,
+            FuelBlockConsensus,
+            SealedFuelBlock,
+        },
     };
     use tokio::time::{
         sleep,
@@ -246,6 +285,20 @@ pub mod tests {
     #[derive(Clone, Debug)]
     struct FakeDb;
 
+    impl ChainDb for FakeDb {
+        fn current_height(&self) -> anyhow::Result<BlockHeight> {
+            Ok(Default::default())
+        }
+
+        fn block_id(&self, _height: BlockHeight) -> anyhow::Result<Option<Bytes32>> {
+            Ok(None)
+        }
+
+        fn finalized_da_height(&self) -> anyhow::Result<DaBlockHeight> {
+            Ok(0)
+        }
+    }
+
     #[async_trait]
     impl P2pDb for FakeDb {
         async fn get_sealed_block(
@@ -262,6 +315,8 @@ pub mod tests {
                 consensus: FuelBlockConsensus {
                     required_stake: 100_000,
                     validators: Default::default(),
+                    signature: None,
+                    votes: vec![],
                 },
             }))
         }
@@ -297,4 +352,28 @@ pub mod tests {
         // Node with p2p service successfully restarted
         assert!(service.start().await.is_ok());
     }
+
+    #[tokio::test]
+    async fn lists_peers_of_started_service() {
+        let p2p_config = P2PConfig::default_with_network("lists_peers");
+        let (tx_request_event, rx_request_event) = tokio::sync::mpsc::channel(100);
+        let (tx_consensus, _) = tokio::sync::mpsc::channel(100);
+        let (tx_transaction, _) = tokio::sync::mpsc::channel(100);
+        let (tx_block, _) = tokio::sync::mpsc::channel(100);
+        let service = Service::new(
+            p2p_config,
+            Arc::new(FakeDb),
+            tx_request_event,
+            rx_request_event,
+            tx_consensus,
+            tx_transaction,
+            tx_block,
+        );
+
+        // not started services answer without waiting for the orchestrator
+        assert!(service.peers().await.unwrap().is_empty());
+
+        service.start().await.unwrap();
+        assert!(service.peers().await.unwrap().is_empty());
+    }
 }
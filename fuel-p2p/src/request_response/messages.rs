@@ -19,18 +19,21 @@ pub(crate) const MAX_REQUEST_SIZE: usize = 8;
 #[derive(Serialize, Deserialize, Eq, PartialEq, Debug, Clone, Copy)]
 pub enum RequestMessage {
     RequestBlock(BlockHeight),
+    RequestHeight,
 }
 
 /// Final Response Message that p2p service sends to the Orchestrator
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub enum ResponseMessage {
-    ResponseBlock(SealedFuelBlock),
+    ResponseBlock(Box<SealedFuelBlock>),
+    ResponseHeight(BlockHeight),
 }
 
 /// Holds oneshot channels for specific responses
 #[derive(Debug)]
 pub enum ResponseChannelItem {
     ResponseBlock(oneshot::Sender<SealedFuelBlock>),
+    ResponseHeight(oneshot::Sender<BlockHeight>),
 }
 
 /// Response that is sent over the wire
@@ -38,6 +41,7 @@ pub enum ResponseChannelItem {
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub enum IntermediateResponse {
     ResponseBlock(Vec<u8>),
+    ResponseHeight(BlockHeight),
 }
 
 /// Initial state of the `ResponseMessage` prior to having its inner value serialized
@@ -45,6 +49,7 @@ pub enum IntermediateResponse {
 #[derive(Debug, Clone)]
 pub enum OutboundResponse {
     ResponseBlock(Arc<SealedFuelBlock>),
+    ResponseHeight(BlockHeight),
 }
 
 #[derive(Debug)]
@@ -585,7 +585,9 @@ mod tests {
                             block,
                             consensus: FuelBlockConsensus {
                                 required_stake: 100_000,
-                                validators: HashMap::default()
+                                validators: HashMap::default(),
+                                signature: None,
+                                votes: vec![],
                             }
                         };
 
@@ -31,6 +31,7 @@ use fuel_core_interfaces::{
         StakingDiff,
         ValidatorDiff,
     },
+    signer::Signer,
 };
 use std::{
     collections::{
@@ -89,14 +90,14 @@ impl FinalizationQueue {
     pub fn new(
         chain_id: u64,
         contract_address: Option<H160>,
-        private_key: &[u8],
+        signer: Option<Arc<dyn Signer>>,
         chain_height: BlockHeight,
         last_committed_finalized_fuel_height: BlockHeight,
     ) -> Self {
         let blocks = PendingBlocks::new(
             chain_id,
             contract_address,
-            private_key,
+            signer,
             chain_height,
             last_committed_finalized_fuel_height,
         );
@@ -398,10 +399,7 @@ mod tests {
         let mut queue = FinalizationQueue::new(
             0,
             Some(H160::zero()),
-            &(hex::decode(
-                "79afbf7147841fca72b45a1978dd7669470ba67abbe5c220062924380c9c364b",
-            )
-            .unwrap()),
+            None,
             BlockHeight::from(10u64),
             BlockHeight::from(0u64),
         );
@@ -449,10 +447,7 @@ mod tests {
         let mut queue = FinalizationQueue::new(
             0,
             Some(H160::zero()),
-            &(hex::decode(
-                "79afbf7147841fca72b45a1978dd7669470ba67abbe5c220062924380c9c364b",
-            )
-            .unwrap()),
+            None,
             BlockHeight::from(10u64),
             BlockHeight::from(0u64),
         );
@@ -487,10 +482,7 @@ mod tests {
         let mut queue = FinalizationQueue::new(
             0,
             Some(H160::zero()),
-            &(hex::decode(
-                "79afbf7147841fca72b45a1978dd7669470ba67abbe5c220062924380c9c364b",
-            )
-            .unwrap()),
+            None,
             BlockHeight::from(10u64),
             BlockHeight::from(0u64),
         );
@@ -579,10 +571,7 @@ mod tests {
         let mut queue = FinalizationQueue::new(
             0,
             Some(H160::zero()),
-            &(hex::decode(
-                "79afbf7147841fca72b45a1978dd7669470ba67abbe5c220062924380c9c364b",
-            )
-            .unwrap()),
+            None,
             BlockHeight::from(10u64),
             BlockHeight::from(0u64),
         );
@@ -646,10 +635,7 @@ mod tests {
         let mut queue = FinalizationQueue::new(
             0,
             Some(H160::zero()),
-            &(hex::decode(
-                "79afbf7147841fca72b45a1978dd7669470ba67abbe5c220062924380c9c364b",
-            )
-            .unwrap()),
+            None,
             BlockHeight::from(10u64),
             BlockHeight::from(0u64),
         );
@@ -702,10 +688,7 @@ mod tests {
         let mut queue = FinalizationQueue::new(
             0,
             Some(H160::zero()),
-            &(hex::decode(
-                "79afbf7147841fca72b45a1978dd7669470ba67abbe5c220062924380c9c364b",
-            )
-            .unwrap()),
+            None,
             BlockHeight::from(10u64),
             BlockHeight::from(0u64),
         );
@@ -742,10 +725,7 @@ mod tests {
         let mut queue = FinalizationQueue::new(
             0,
             Some(H160::zero()),
-            &(hex::decode(
-                "79afbf7147841fca72b45a1978dd7669470ba67abbe5c220062924380c9c364b",
-            )
-            .unwrap()),
+            None,
             BlockHeight::from(10u64),
             BlockHeight::from(0u64),
         );
@@ -789,10 +769,7 @@ mod tests {
         let mut queue = FinalizationQueue::new(
             0,
             Some(H160::zero()),
-            &(hex::decode(
-                "79afbf7147841fca72b45a1978dd7669470ba67abbe5c220062924380c9c364b",
-            )
-            .unwrap()),
+            None,
             BlockHeight::from(0u64),
             BlockHeight::from(0u64),
         );
@@ -3,6 +3,7 @@ pub(crate) mod config;
 pub(crate) mod finalization_queue;
 pub(crate) mod log;
 pub(crate) mod pending_blocks;
+pub(crate) mod signer;
 pub(crate) mod validators;
 
 #[cfg(test)]
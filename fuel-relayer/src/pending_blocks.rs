@@ -1,11 +1,8 @@
 use anyhow::Error;
-use ethers_core::{
-    k256::ecdsa::SigningKey,
-    types::{
-        TransactionRequest,
-        H160,
-        U256,
-    },
+use ethers_core::types::{
+    TransactionRequest,
+    H160,
+    U256,
 };
 use ethers_middleware::{
     gas_escalator::{
@@ -25,6 +22,7 @@ use fuel_core_interfaces::{
         SealedFuelBlock,
     },
     relayer::RelayerDb,
+    signer::Signer,
 };
 use std::{
     cmp::max,
@@ -32,12 +30,11 @@ use std::{
     sync::Arc,
 };
 
-// use the ethers_signers crate to manage LocalWallet and Signer
-use crate::abi;
-use ethers_signers::{
-    LocalWallet,
-    Signer,
+use crate::{
+    abi,
+    signer::EthSigner,
 };
+use ethers_signers::Signer as _;
 use tracing::{
     debug,
     error,
@@ -48,7 +45,8 @@ use tracing::{
 /// Pending Fuel Blocks waiting to be finalized inside client. Until then
 /// there is possibility that they are going to be reverted
 pub struct PendingBlocks {
-    signer: LocalWallet,
+    /// Signs block commits, they are not sent without it.
+    signer: Option<EthSigner>,
     contract_address: Option<H160>,
     /// Pending block commits seen on DA layer and waiting to be finalized
     pending_block_commits: VecDeque<PendingBlock>,
@@ -121,14 +119,11 @@ impl PendingBlocks {
     pub fn new(
         chain_id: u64,
         contract_address: Option<H160>,
-        private_key: &[u8],
+        signer: Option<Arc<dyn Signer>>,
         chain_height: BlockHeight,
         last_committed_finalized_fuel_height: BlockHeight,
     ) -> Self {
-        // it is some random key for now
-        let sk = SigningKey::from_bytes(private_key).unwrap();
-        let signer: LocalWallet = sk.into();
-        let signer = signer.with_chain_id(chain_id);
+        let signer = signer.map(|signer| EthSigner::new(signer, chain_id));
 
         Self {
             signer,
@@ -226,8 +221,9 @@ impl PendingBlocks {
         self.set_chain_height(height);
         debug!("Handle new created_block {}", height);
 
-        // if contract is not set there is no point to bundle and send block commits
-        if self.contract_address.is_none() {
+        // if contract is not set there is no point to bundle and send block commits,
+        // the relayer service doesn't start with a contract but without a signer
+        if self.contract_address.is_none() || self.signer.is_none() {
             return
         }
 
@@ -362,7 +358,9 @@ impl PendingBlocks {
     where
         P: Middleware + 'static,
     {
-        if let Some(contract_address) = self.contract_address {
+        if let (Some(contract_address), Some(signer)) =
+            (self.contract_address, &self.signer)
+        {
             let wrapped_block = from_fuel_to_block_header(block);
             let wrapped_parent = from_fuel_to_block_header(parent);
 
@@ -420,9 +418,9 @@ impl PendingBlocks {
                 Frequency::PerBlock,
             );
 
-            // Sign transactions with a private key
-            let address = self.signer.address();
-            let provider = SignerMiddleware::new(provider, self.signer.clone());
+            // Sign transactions with the signer of the node
+            let address = signer.address();
+            let provider = SignerMiddleware::new(provider, signer.clone());
 
             // Use EthGasStation as the gas oracle
             // https://github.com/FuelLabs/fuel-core/issues/363
@@ -457,14 +455,10 @@ mod tests {
     use tracing_test::traced_test;
 
     pub fn block_commit(last_committed_fuel_block: BlockHeight) -> PendingBlocks {
-        let private_key = hex::decode(
-            "c6bd905dcac2a0b1c43f574ab6933df14d7ceee0194902bce523ed054e8e798b",
-        )
-        .unwrap();
         PendingBlocks::new(
             0,
             Some(H160::zero()),
-            &private_key,
+            None,
             10u64.into(),
             last_committed_fuel_block,
         )
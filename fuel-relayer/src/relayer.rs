@@ -89,7 +89,7 @@ impl Relayer {
         let queue = FinalizationQueue::new(
             ctx.config.eth_chain_id(),
             ctx.config.eth_v2_commit_contract(),
-            &ctx.private_key,
+            ctx.signer.clone(),
             chain_height,
             last_committed_finalized_fuel_height,
         );
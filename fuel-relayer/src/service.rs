@@ -20,6 +20,7 @@ use fuel_core_interfaces::{
         RelayerDb,
         RelayerRequest,
     },
+    signer::Signer,
 };
 use std::sync::Arc;
 use tokio::{
@@ -37,7 +38,7 @@ const PROVIDER_INTERVAL: u64 = 1000;
 pub struct ServiceBuilder {
     sender: relayer::Sender,
     receiver: mpsc::Receiver<RelayerRequest>,
-    private_key: Option<Vec<u8>>,
+    signer: Option<Arc<dyn Signer>>,
     db: Option<Box<dyn RelayerDb>>,
     import_block_events: Option<broadcast::Receiver<ImportBlockBroadcast>>,
     config: Config,
@@ -55,7 +56,7 @@ impl ServiceBuilder {
         Self {
             sender: relayer::Sender::new(sender),
             receiver,
-            private_key: None,
+            signer: None,
             db: None,
             import_block_events: None,
             config: Default::default(),
@@ -65,8 +66,9 @@ impl ServiceBuilder {
         &self.sender
     }
 
-    pub fn private_key(&mut self, private_key: Vec<u8>) -> &mut Self {
-        self.private_key = Some(private_key);
+    /// Signer of the block commits, blocks are not committed to the DA layer without it.
+    pub fn signer(&mut self, signer: Arc<dyn Signer>) -> &mut Self {
+        self.signer = Some(signer);
         self
     }
     pub fn db(&mut self, db: Box<dyn RelayerDb>) -> &mut Self {
@@ -88,17 +90,19 @@ impl ServiceBuilder {
     }
 
     pub fn build(self) -> anyhow::Result<Service> {
-        if self.private_key.is_none()
-            || self.db.is_none()
-            || self.import_block_events.is_none()
-        {
+        if self.db.is_none() || self.import_block_events.is_none() {
             return Err(anyhow!("One of context items are not set"))
         }
+        if self.config.eth_v2_commit_contract().is_some() && self.signer.is_none() {
+            return Err(anyhow!(
+                "Block commit contract is set but there is no signer to commit blocks"
+            ))
+        }
         let service = Service::new(
             self.sender,
             Context {
                 receiver: self.receiver,
-                private_key: self.private_key.unwrap(),
+                signer: self.signer,
                 db: self.db.unwrap(),
                 new_block_event: self.import_block_events.unwrap(),
                 config: self.config,
@@ -117,8 +121,8 @@ pub struct Service {
 pub struct Context {
     /// Request channel.
     pub receiver: mpsc::Receiver<RelayerRequest>,
-    /// Private.
-    pub private_key: Vec<u8>,
+    /// Signer of the block commits.
+    pub signer: Option<Arc<dyn Signer>>,
     /// Db connector to apply stake and messages.
     pub db: Box<dyn RelayerDb>,
     /// Notification of new block event.
@@ -0,0 +1,161 @@
+use async_trait::async_trait;
+use ethers_core::{
+    types::{
+        transaction::{
+            eip2718::TypedTransaction,
+            eip712::Eip712,
+        },
+        Signature,
+        H160,
+        H256,
+        U256,
+    },
+    utils::{
+        hash_message,
+        keccak256,
+    },
+};
+use ethers_signers::to_eip155_v;
+use fuel_core_interfaces::{
+    common::fuel_crypto::Message,
+    signer::{
+        Signer,
+        SignerError,
+    },
+};
+use std::sync::Arc;
+
+/// Signs the Ethereum transactions of the relayer with the signer of the node.
+#[derive(Clone, Debug)]
+pub struct EthSigner {
+    signer: Arc<dyn Signer>,
+    address: H160,
+    chain_id: u64,
+}
+
+impl EthSigner {
+    pub fn new(signer: Arc<dyn Signer>, chain_id: u64) -> Self {
+        // ethereum address is the last 20 bytes of the keccak hash of the public key
+        let address = H160::from_slice(&keccak256(*signer.public_key())[12..]);
+        Self {
+            signer,
+            address,
+            chain_id,
+        }
+    }
+
+    /// Sign the hash, `v` is the recovery id plus 27.
+    async fn sign_hash(&self, hash: H256) -> Result<Signature, SignerError> {
+        // Safety: the hash is the keccak hash of the signed data
+        let message = unsafe { Message::from_bytes_unchecked(hash.0) };
+        let signature = self.signer.sign(&message).await?;
+
+        // the recovery id is stored in the highest bit of `s`
+        let mut bytes = *signature;
+        let recovery_id = bytes[32] >> 7;
+        bytes[32] &= 0x7f;
+        Ok(Signature {
+            r: U256::from_big_endian(&bytes[..32]),
+            s: U256::from_big_endian(&bytes[32..]),
+            v: recovery_id as u64 + 27,
+        })
+    }
+}
+
+#[async_trait]
+impl ethers_signers::Signer for EthSigner {
+    type Error = SignerError;
+
+    async fn sign_message<S: Send + Sync + AsRef<[u8]>>(
+        &self,
+        message: S,
+    ) -> Result<Signature, Self::Error> {
+        self.sign_hash(hash_message(message)).await
+    }
+
+    async fn sign_transaction(
+        &self,
+        tx: &TypedTransaction,
+    ) -> Result<Signature, Self::Error> {
+        // rlp (for sighash) must have the same chain id as v in the signature
+        let chain_id = tx.chain_id().map(|id| id.as_u64()).unwrap_or(self.chain_id);
+        let mut tx = tx.clone();
+        tx.set_chain_id(chain_id);
+
+        let mut signature = self.sign_hash(tx.sighash()).await?;
+        signature.v = to_eip155_v(signature.v as u8 - 27, chain_id);
+        Ok(signature)
+    }
+
+    async fn sign_typed_data<T: Eip712 + Send + Sync>(
+        &self,
+        payload: &T,
+    ) -> Result<Signature, Self::Error> {
+        let encoded = payload
+            .encode_eip712()
+            .map_err(|e| SignerError::Encoding(e.to_string()))?;
+        self.sign_hash(H256::from(encoded)).await
+    }
+
+    fn address(&self) -> H160 {
+        self.address
+    }
+
+    fn chain_id(&self) -> u64 {
+        self.chain_id
+    }
+
+    fn with_chain_id<T: Into<u64>>(mut self, chain_id: T) -> Self {
+        self.chain_id = chain_id.into();
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ethers_core::{
+        k256::ecdsa::SigningKey,
+        types::TransactionRequest,
+    };
+    use ethers_signers::{
+        LocalWallet,
+        Signer as _,
+    };
+    use fuel_core_interfaces::{
+        common::fuel_crypto::SecretKey,
+        signer::InMemorySigner,
+    };
+    use rand::{
+        prelude::StdRng,
+        SeedableRng,
+    };
+
+    fn signers() -> (EthSigner, LocalWallet) {
+        let key = SecretKey::random(&mut StdRng::seed_from_u64(59));
+        let signer = EthSigner::new(Arc::new(InMemorySigner::new(key)), 1337);
+        let wallet: LocalWallet = SigningKey::from_bytes(key.as_ref()).unwrap().into();
+        (signer, wallet.with_chain_id(1337u64))
+    }
+
+    #[tokio::test]
+    async fn signs_like_local_wallet() {
+        let (signer, wallet) = signers();
+        assert_eq!(signer.address(), wallet.address());
+
+        let tx: TypedTransaction = TransactionRequest::new()
+            .from(signer.address())
+            .to(H160::zero())
+            .gas_price(20000000001u64)
+            .nonce(3)
+            .into();
+        assert_eq!(
+            signer.sign_transaction(&tx).await.unwrap(),
+            wallet.sign_transaction(&tx).await.unwrap()
+        );
+
+        let signature = signer.sign_message("commit").await.unwrap();
+        assert_eq!(signature, wallet.sign_message("commit").await.unwrap());
+        assert_eq!(signature.recover("commit").unwrap(), signer.address());
+    }
+}
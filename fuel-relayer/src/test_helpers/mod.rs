@@ -27,12 +27,9 @@ pub async fn relayer(
     let db = Box::new(MockDb::default());
     let (request_sender, receiver) = mpsc::channel(10);
     let (broadcast_tx, new_block_event) = broadcast::channel(100);
-    let private_key =
-        hex::decode("c6bd905dcac2a0b1c43f574ab6933df14d7ceee0194902bce523ed054e8e798b")
-            .unwrap();
     let ctx = Context {
         receiver,
-        private_key,
+        signer: None,
         db,
         new_block_event,
         config,
@@ -1,2 +1,22 @@
-#[derive(Default, Clone, Debug)]
-pub struct Config {}
+use std::time::Duration;
+
+#[derive(Clone, Debug)]
+pub struct Config {
+    /// Number of blocks requested from peers at the same time
+    pub max_parallel_requests: usize,
+    /// How long to wait for a peer to respond with a requested block
+    pub block_request_timeout: Duration,
+    /// How long to wait before syncing again after peers didn't answer or a block
+    /// failed to import
+    pub sync_interval: Duration,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            max_parallel_requests: 10,
+            block_request_timeout: Duration::from_secs(5),
+            sync_interval: Duration::from_secs(1),
+        }
+    }
+}
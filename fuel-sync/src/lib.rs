@@ -1,5 +1,7 @@
 pub mod config;
 pub mod service;
+pub mod synchronizer;
 
 pub use config::Config;
 pub use service::Service;
+pub use synchronizer::Synchronizer;
@@ -1,4 +1,7 @@
-use crate::Config;
+use crate::{
+    Config,
+    Synchronizer,
+};
 use fuel_core_interfaces::{
     bft::BftMpsc,
     block_importer::ImportBlockMpsc,
@@ -6,47 +9,127 @@ use fuel_core_interfaces::{
         BlockBroadcast,
         P2pRequestEvent,
     },
-    relayer,
-    sync::SyncMpsc,
+    sync::{
+        SyncDb,
+        SyncMpsc,
+        SyncStatus,
+    },
 };
 use parking_lot::Mutex;
+use std::sync::Arc;
 use tokio::{
     sync::mpsc,
     task::JoinHandle,
+    time::Instant,
 };
 
 pub struct Service {
     join: Mutex<Option<JoinHandle<()>>>,
     sender: mpsc::Sender<SyncMpsc>,
+    receiver: Mutex<Option<mpsc::Receiver<SyncMpsc>>>,
+    config: Config,
+    db: Arc<dyn SyncDb>,
 }
 
 impl Service {
-    pub async fn new(_config: &Config) -> anyhow::Result<Self> {
-        let (sender, _receiver) = mpsc::channel(100);
+    pub async fn new(config: &Config, db: Arc<dyn SyncDb>) -> anyhow::Result<Self> {
+        let (sender, receiver) = mpsc::channel(100);
         Ok(Self {
             sender,
+            receiver: Mutex::new(Some(receiver)),
+            config: config.clone(),
+            db,
             join: Mutex::new(None),
         })
     }
 
     pub async fn start(
         &self,
-        _p2p_block: mpsc::Receiver<BlockBroadcast>,
-        _p2p_request: mpsc::Sender<P2pRequestEvent>,
-        _relayer: relayer::Sender,
-        _bft: mpsc::Sender<BftMpsc>,
-        _block_importer: mpsc::Sender<ImportBlockMpsc>,
+        p2p_block: mpsc::Receiver<BlockBroadcast>,
+        p2p_request: mpsc::Sender<P2pRequestEvent>,
+        bft: mpsc::Sender<BftMpsc>,
+        block_importer: mpsc::Sender<ImportBlockMpsc>,
     ) {
         let mut join = self.join.lock();
         if join.is_none() {
-            *join = Some(tokio::spawn(async {}));
+            if let Some(receiver) = self.receiver.lock().take() {
+                let synchronizer = Synchronizer::new(
+                    self.config.clone(),
+                    self.db.clone(),
+                    p2p_request,
+                    bft,
+                    block_importer,
+                );
+                *join = Some(tokio::spawn(Self::run(
+                    receiver,
+                    p2p_block,
+                    synchronizer,
+                    self.config.clone(),
+                )));
+            }
+        }
+    }
+
+    async fn run(
+        mut receiver: mpsc::Receiver<SyncMpsc>,
+        mut p2p_block: mpsc::Receiver<BlockBroadcast>,
+        synchronizer: Synchronizer,
+        config: Config,
+    ) {
+        let synchronizer = Arc::new(synchronizer);
+        // ranges are synced in the background, so status requests are answered right away
+        let mut sync: Option<JoinHandle<bool>> = None;
+        // when to sync next, once synced only gossiped blocks trigger a new sync
+        let mut next_sync = Some(Instant::now());
+        let mut p2p_closed = false;
+        loop {
+            tokio::select! {
+                event = receiver.recv() => {
+                    match event {
+                        Some(SyncMpsc::Status { ret }) => {
+                            let _ = ret.send(synchronizer.progress());
+                        }
+                        Some(SyncMpsc::Start) => next_sync = Some(Instant::now()),
+                        Some(SyncMpsc::Stop) | None => break,
+                    }
+                }
+                block = p2p_block.recv(), if !p2p_closed => {
+                    match block {
+                        Some(BlockBroadcast::NewBlock(block)) => {
+                            if synchronizer.on_peer_block(&block) {
+                                next_sync = Some(Instant::now());
+                            }
+                        }
+                        None => p2p_closed = true,
+                    }
+                }
+                synced = async { sync.as_mut().expect("checked by the precondition").await }, if sync.is_some() => {
+                    sync = None;
+                    next_sync = match synced {
+                        // keep requesting ranges right away while peers have more blocks
+                        Ok(true) => Some(Instant::now()),
+                        _ if synchronizer.status() == SyncStatus::Synced => None,
+                        _ => Some(Instant::now() + config.sync_interval),
+                    };
+                }
+                _ = tokio::time::sleep_until(next_sync.unwrap_or_else(Instant::now)), if sync.is_none() && next_sync.is_some() => {
+                    next_sync = None;
+                    let synchronizer = synchronizer.clone();
+                    sync = Some(tokio::spawn(async move {
+                        synchronizer.sync_next_range().await
+                    }));
+                }
+            }
+        }
+        if let Some(sync) = sync {
+            sync.abort();
         }
     }
 
     pub async fn stop(&self) -> Option<JoinHandle<()>> {
         let join = self.join.lock().take();
         if join.is_some() {
-            let _ = self.sender.send(SyncMpsc::Stop);
+            let _ = self.sender.send(SyncMpsc::Stop).await;
         }
         join
     }
@@ -0,0 +1,496 @@
+use crate::Config;
+use anyhow::anyhow;
+use fuel_core_interfaces::{
+    bft::BftMpsc,
+    block_importer::ImportBlockMpsc,
+    model::{
+        BlockHeight,
+        FuelBlock,
+        SealedFuelBlock,
+    },
+    p2p::P2pRequestEvent,
+    sync::{
+        SyncDb,
+        SyncProgress,
+        SyncStatus,
+    },
+};
+use futures::future::join_all;
+use parking_lot::Mutex;
+use std::{
+    sync::Arc,
+    time::Instant,
+};
+use tokio::{
+    sync::{
+        mpsc,
+        oneshot,
+    },
+    time::timeout,
+};
+use tracing::warn;
+
+pub struct Synchronizer {
+    config: Config,
+    db: Arc<dyn SyncDb>,
+    p2p_request: mpsc::Sender<P2pRequestEvent>,
+    bft: mpsc::Sender<BftMpsc>,
+    block_importer: mpsc::Sender<ImportBlockMpsc>,
+    /// Height of the chain of the peers, only trusted once the block at that height passed
+    /// the consensus check.
+    peer_height: Mutex<BlockHeight>,
+    status: Mutex<SyncStatus>,
+    /// When and from which height the node started to catch up with the peers.
+    catch_up: Mutex<Option<(Instant, BlockHeight)>>,
+}
+
+impl Synchronizer {
+    pub fn new(
+        config: Config,
+        db: Arc<dyn SyncDb>,
+        p2p_request: mpsc::Sender<P2pRequestEvent>,
+        bft: mpsc::Sender<BftMpsc>,
+        block_importer: mpsc::Sender<ImportBlockMpsc>,
+    ) -> Self {
+        Self {
+            config,
+            db,
+            p2p_request,
+            bft,
+            block_importer,
+            peer_height: Mutex::new(BlockHeight::default()),
+            status: Mutex::new(SyncStatus::InitialSync),
+            catch_up: Mutex::new(None),
+        }
+    }
+
+    pub fn status(&self) -> SyncStatus {
+        *self.status.lock()
+    }
+
+    pub fn peer_height(&self) -> BlockHeight {
+        *self.peer_height.lock()
+    }
+
+    pub fn progress(&self) -> SyncProgress {
+        let current_height = self.db.current_height().unwrap_or_default();
+        let blocks_per_second = match *self.catch_up.lock() {
+            Some((started, height)) => {
+                let imported = u32::from(current_height).saturating_sub(height.into());
+                let elapsed = started.elapsed().as_secs_f64();
+                if elapsed > 0.0 {
+                    imported as f64 / elapsed
+                } else {
+                    0.0
+                }
+            }
+            None => 0.0,
+        };
+        SyncProgress {
+            status: self.status(),
+            current_height,
+            peer_height: self.peer_height(),
+            blocks_per_second,
+        }
+    }
+
+    /// Check a block gossiped by other nodes. Gossiped blocks have no consensus data, so
+    /// their height only triggers a sync, which verifies the height of the peers. Returns
+    /// true if the block is ahead of the local chain.
+    pub fn on_peer_block(&self, block: &FuelBlock) -> bool {
+        let behind = match self.db.current_height() {
+            Ok(height) => height < block.header.height,
+            Err(_) => true,
+        };
+        if behind {
+            *self.status.lock() = SyncStatus::InitialSync;
+        }
+        behind
+    }
+
+    /// Ask a peer for its height, then request the next range of missing blocks from
+    /// peers in parallel, verify and import them in order. Returns true if the whole
+    /// range was imported and peers have more blocks.
+    pub async fn sync_next_range(&self) -> bool {
+        let current_height = match self.db.current_height() {
+            Ok(height) => height,
+            Err(e) => {
+                warn!("Failed to read current height: {}", e);
+                return false
+            }
+        };
+        // a height that isn't answered or verified is dropped, so a peer can't keep the
+        // node catching up with a chain that doesn't exist
+        let peer_height = match self.request_peer_height().await {
+            Some(height) => self.verify_peer_height(height, current_height).await,
+            None => None,
+        };
+        *self.peer_height.lock() = peer_height.unwrap_or(current_height);
+        if peer_height.is_none() {
+            *self.status.lock() = SyncStatus::NoPeers;
+            return false
+        }
+        if self.peer_height() > current_height {
+            self.catch_up
+                .lock()
+                .get_or_insert_with(|| (Instant::now(), current_height));
+        }
+
+        let missing = u32::from(self.peer_height()).saturating_sub(current_height.into());
+        let range = missing.min(self.config.max_parallel_requests as u32);
+        let requests =
+            (1..=range).map(|offset| self.request_block(current_height + offset.into()));
+        let blocks = join_all(requests).await;
+
+        let mut imported = 0;
+        for block in blocks {
+            let block = match block {
+                Some(block) => block,
+                None => break,
+            };
+            if let Err(e) = self.import(block.clone()).await {
+                warn!("Failed to import block {}: {}", block.header.height, e);
+                break
+            }
+            imported += 1;
+        }
+
+        let synced = matches!(self.db.current_height(), Ok(height) if height >= self.peer_height());
+        *self.status.lock() = if synced {
+            *self.catch_up.lock() = None;
+            SyncStatus::Synced
+        } else {
+            SyncStatus::InitialSync
+        };
+        imported == range && !synced
+    }
+
+    /// Check the height reported by a peer against the consensus of the block at that
+    /// height. Returns `None` if the block is missing or invalid.
+    async fn verify_peer_height(
+        &self,
+        height: BlockHeight,
+        current_height: BlockHeight,
+    ) -> Option<BlockHeight> {
+        if height <= current_height {
+            return Some(height)
+        }
+        let block = self.request_block(height).await?;
+        match self.check_consensus(block).await {
+            Ok(()) => Some(height),
+            Err(e) => {
+                warn!("Failed to verify peer height {}: {}", height, e);
+                None
+            }
+        }
+    }
+
+    /// Ask a random peer for the height of its chain. Returns `None` if there are no
+    /// peers or the peer didn't answer.
+    async fn request_peer_height(&self) -> Option<BlockHeight> {
+        let request = async {
+            let (response, receiver) = oneshot::channel();
+            self.p2p_request
+                .send(P2pRequestEvent::RequestHeight { response })
+                .await
+                .ok()?;
+            receiver.await.ok()
+        };
+        timeout(self.config.block_request_timeout, request)
+            .await
+            .ok()?
+    }
+
+    /// Ask a peer for the sealed block at `height`. Returns `None` if no peer answered
+    /// with the block.
+    async fn request_block(&self, height: BlockHeight) -> Option<Arc<SealedFuelBlock>> {
+        let request = async {
+            let (response, receiver) = oneshot::channel();
+            self.p2p_request
+                .send(P2pRequestEvent::RequestBlock { height, response })
+                .await
+                .ok()?;
+            receiver.await.ok()
+        };
+        let block = timeout(self.config.block_request_timeout, request)
+            .await
+            .ok()??;
+        if block.header.height != height {
+            warn!(
+                "Peer responded with block {} instead of {}",
+                block.header.height, height
+            );
+            return None
+        }
+        Some(Arc::new(block))
+    }
+
+    async fn import(&self, block: Arc<SealedFuelBlock>) -> anyhow::Result<()> {
+        let da_height = self.db.finalized_da_height()?;
+        if block.header.number > da_height.into() {
+            return Err(anyhow!(
+                "DA height {} of the block is not finalized yet, finalized DA height is {}",
+                block.header.number,
+                da_height
+            ))
+        }
+
+        self.check_consensus(block.clone()).await?;
+
+        let (response, receiver) = oneshot::channel();
+        self.block_importer
+            .send(ImportBlockMpsc::ImportSealedFuelBlock { block, response })
+            .await?;
+        receiver.await?
+    }
+
+    async fn check_consensus(&self, block: Arc<SealedFuelBlock>) -> anyhow::Result<()> {
+        let (ret, receiver) = oneshot::channel();
+        self.bft
+            .send(BftMpsc::CheckBlockConsensus { block, ret })
+            .await?;
+        receiver.await?
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fuel_core_interfaces::{
+        common::fuel_types::Bytes32,
+        db::ChainDb,
+        model::{
+            DaBlockHeight,
+            FuelBlockHeader,
+        },
+    };
+    use std::time::Duration;
+
+    struct MockDb {
+        height: Mutex<BlockHeight>,
+        da_height: DaBlockHeight,
+    }
+
+    impl ChainDb for MockDb {
+        fn current_height(&self) -> anyhow::Result<BlockHeight> {
+            Ok(*self.height.lock())
+        }
+
+        fn block_id(&self, _height: BlockHeight) -> anyhow::Result<Option<Bytes32>> {
+            Ok(None)
+        }
+
+        fn finalized_da_height(&self) -> anyhow::Result<DaBlockHeight> {
+            Ok(self.da_height)
+        }
+    }
+
+    impl SyncDb for MockDb {}
+
+    fn peer_chain(length: u32, da_height: u64) -> Vec<SealedFuelBlock> {
+        (1..=length)
+            .map(|height| SealedFuelBlock {
+                block: FuelBlock {
+                    header: FuelBlockHeader {
+                        height: height.into(),
+                        number: da_height.into(),
+                        ..Default::default()
+                    },
+                    transactions: vec![],
+                },
+                consensus: Default::default(),
+            })
+            .collect()
+    }
+
+    /// Answers block requests from `chain` and drops requests for unknown heights.
+    fn p2p(chain: Vec<SealedFuelBlock>) -> mpsc::Sender<P2pRequestEvent> {
+        let (sender, mut receiver) = mpsc::channel(100);
+        tokio::spawn(async move {
+            while let Some(event) = receiver.recv().await {
+                match event {
+                    P2pRequestEvent::RequestBlock { height, response } => {
+                        if let Some(block) = chain.get(height.as_usize().wrapping_sub(1))
+                        {
+                            let _ = response.send(block.clone());
+                        }
+                    }
+                    P2pRequestEvent::RequestHeight { response } => {
+                        let _ = response.send(chain.len().into());
+                    }
+                    _ => {}
+                }
+            }
+        });
+        sender
+    }
+
+    /// Accepts consensus of every block except the one at `invalid_height`.
+    fn bft(invalid_height: Option<BlockHeight>) -> mpsc::Sender<BftMpsc> {
+        let (sender, mut receiver) = mpsc::channel(100);
+        tokio::spawn(async move {
+            while let Some(event) = receiver.recv().await {
+                if let BftMpsc::CheckBlockConsensus { block, ret } = event {
+                    let result = if Some(block.header.height) == invalid_height {
+                        Err(anyhow!("Invalid consensus"))
+                    } else {
+                        Ok(())
+                    };
+                    let _ = ret.send(result);
+                }
+            }
+        });
+        sender
+    }
+
+    /// Imports blocks by bumping the height of the database.
+    fn importer(db: Arc<MockDb>) -> mpsc::Sender<ImportBlockMpsc> {
+        let (sender, mut receiver) = mpsc::channel(100);
+        tokio::spawn(async move {
+            while let Some(event) = receiver.recv().await {
+                if let ImportBlockMpsc::ImportSealedFuelBlock { block, response } = event
+                {
+                    *db.height.lock() = block.header.height;
+                    let _ = response.send(Ok(()));
+                }
+            }
+        });
+        sender
+    }
+
+    fn synchronizer(
+        chain: Vec<SealedFuelBlock>,
+        invalid_height: Option<BlockHeight>,
+        da_height: DaBlockHeight,
+    ) -> (Synchronizer, Arc<MockDb>) {
+        let db = Arc::new(MockDb {
+            height: Mutex::new(BlockHeight::default()),
+            da_height,
+        });
+        let config = Config {
+            max_parallel_requests: 10,
+            ..Default::default()
+        };
+        let synchronizer = Synchronizer::new(
+            config,
+            db.clone(),
+            p2p(chain),
+            bft(invalid_height),
+            importer(db.clone()),
+        );
+        (synchronizer, db)
+    }
+
+    #[tokio::test]
+    async fn syncs_missing_blocks_in_ranges() {
+        let (synchronizer, db) = synchronizer(peer_chain(25, 0), None, 0);
+
+        assert!(synchronizer.sync_next_range().await);
+        assert!(synchronizer.sync_next_range().await);
+        assert_eq!(synchronizer.status(), SyncStatus::InitialSync);
+        assert!(!synchronizer.sync_next_range().await);
+
+        assert_eq!(db.current_height().unwrap(), 25u32.into());
+        assert_eq!(synchronizer.peer_height(), 25u32.into());
+        assert_eq!(synchronizer.status(), SyncStatus::Synced);
+    }
+
+    #[tokio::test]
+    async fn reports_progress_while_catching_up() {
+        let (synchronizer, _db) = synchronizer(peer_chain(25, 0), None, 0);
+
+        synchronizer.sync_next_range().await;
+        let progress = synchronizer.progress();
+        assert_eq!(progress.current_height, 10u32.into());
+        assert_eq!(progress.peer_height, 25u32.into());
+        assert!(progress.blocks_per_second > 0.0);
+        assert!(progress.time_to_sync().is_some());
+
+        while synchronizer.sync_next_range().await {}
+        let progress = synchronizer.progress();
+        assert_eq!(progress.status, SyncStatus::Synced);
+        assert_eq!(progress.blocks_per_second, 0.0);
+        assert_eq!(progress.time_to_sync(), Some(Duration::ZERO));
+    }
+
+    #[tokio::test]
+    async fn stops_at_block_with_invalid_consensus() {
+        let (synchronizer, db) = synchronizer(peer_chain(5, 0), Some(3u32.into()), 0);
+
+        assert!(!synchronizer.sync_next_range().await);
+
+        assert_eq!(db.current_height().unwrap(), 2u32.into());
+        assert_eq!(synchronizer.status(), SyncStatus::InitialSync);
+    }
+
+    #[tokio::test]
+    async fn waits_for_da_height_to_be_finalized() {
+        let (synchronizer, db) = synchronizer(peer_chain(5, 8), None, 7);
+
+        assert!(!synchronizer.sync_next_range().await);
+
+        assert_eq!(db.current_height().unwrap(), 0u32.into());
+        assert_eq!(synchronizer.status(), SyncStatus::InitialSync);
+    }
+
+    #[tokio::test]
+    async fn no_peers_status_without_peers() {
+        let (synchronizer, db) = synchronizer(vec![], None, 0);
+        // drop all requests, like p2p does without connected peers
+        let (p2p_request, _) = mpsc::channel(100);
+        let synchronizer = Synchronizer {
+            p2p_request,
+            ..synchronizer
+        };
+
+        assert!(!synchronizer.sync_next_range().await);
+
+        assert_eq!(db.current_height().unwrap(), 0u32.into());
+        assert_eq!(synchronizer.status(), SyncStatus::NoPeers);
+    }
+
+    #[tokio::test]
+    async fn falls_behind_when_peers_gossip_higher_block() {
+        let (synchronizer, _db) = synchronizer(vec![], None, 0);
+        synchronizer.sync_next_range().await;
+        assert_eq!(synchronizer.status(), SyncStatus::Synced);
+
+        assert!(synchronizer.on_peer_block(&peer_chain(3, 0)[2]));
+
+        // the gossiped height isn't trusted until a sync verifies it
+        assert_eq!(synchronizer.peer_height(), 0u32.into());
+        assert_eq!(synchronizer.status(), SyncStatus::InitialSync);
+    }
+
+    #[tokio::test]
+    async fn drops_peer_height_without_block() {
+        let (synchronizer, _db) = synchronizer(vec![], None, 0);
+        // the peer claims a height it has no block for
+        let (p2p_request, mut receiver) = mpsc::channel(100);
+        tokio::spawn(async move {
+            while let Some(event) = receiver.recv().await {
+                if let P2pRequestEvent::RequestHeight { response } = event {
+                    let _ = response.send(u32::MAX.into());
+                }
+            }
+        });
+        let synchronizer = Synchronizer {
+            p2p_request,
+            ..synchronizer
+        };
+
+        assert!(!synchronizer.sync_next_range().await);
+
+        assert_eq!(synchronizer.peer_height(), 0u32.into());
+    }
+
+    #[tokio::test]
+    async fn drops_peer_height_with_invalid_consensus() {
+        let (synchronizer, db) = synchronizer(peer_chain(5, 0), Some(5u32.into()), 0);
+
+        assert!(!synchronizer.sync_next_range().await);
+
+        assert_eq!(synchronizer.peer_height(), 0u32.into());
+        assert_eq!(db.current_height().unwrap(), 0u32.into());
+    }
+}
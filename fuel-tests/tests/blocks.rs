@@ -3,6 +3,7 @@ use chrono::{
     Utc,
 };
 use fuel_core::{
+    chain_config::ProductionStrategy,
     database::Database,
     model::{
         FuelBlockDb,
@@ -13,8 +14,10 @@ use fuel_core::{
         Config,
         FuelService,
     },
+    signer::SignerConfig,
 };
 use fuel_core_interfaces::common::{
+    fuel_crypto::SecretKey,
     fuel_storage::Storage,
     fuel_tx,
     fuel_types,
@@ -29,6 +32,10 @@ use itertools::{
     rev,
     Itertools,
 };
+use rand::{
+    rngs::StdRng,
+    SeedableRng,
+};
 
 #[tokio::test]
 async fn block() {
@@ -95,6 +102,33 @@ async fn produce_block() {
     };
 }
 
+#[tokio::test]
+async fn produce_block_round_robin() {
+    let mut rng = StdRng::seed_from_u64(10);
+    let producers: Vec<_> = (0..2).map(|_| SecretKey::random(&mut rng)).collect();
+
+    let mut config = Config::local_node();
+    config.manual_blocks_enabled = true;
+    config.chain_conf.block_production = ProductionStrategy::RoundRobin {
+        producers: producers.iter().map(|key| key.public_key()).collect(),
+    };
+    config.signer = SignerConfig::Key(producers[1]);
+
+    let srv = FuelService::from_database(Database::default(), config)
+        .await
+        .unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    // the node is the leader of odd heights only
+    assert_eq!(1, client.produce_blocks(1).await.unwrap());
+    let result = client.produce_blocks(1).await;
+    assert!(result
+        .err()
+        .unwrap()
+        .to_string()
+        .contains("This node is not the leader of block 2"));
+}
+
 #[tokio::test]
 async fn produce_block_negative() {
     let db = Database::default();
@@ -18,3 +18,15 @@ async fn chain_info() {
         chain_info.consensus_parameters.into()
     );
 }
+
+#[tokio::test]
+async fn no_peers_without_network() {
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let chain_info = client.chain_info().await.unwrap();
+
+    assert_eq!(chain_info.peer_count, 0);
+    assert_eq!(chain_info.base_chain_height, 0u64.into());
+    assert!(client.peers().await.unwrap().is_empty());
+}
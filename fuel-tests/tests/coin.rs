@@ -12,17 +12,29 @@ use fuel_core::{
         Config,
         FuelService,
     },
+    signer::SignerConfig,
 };
-use fuel_core_interfaces::common::{
-    fuel_storage::Storage,
-    fuel_tx::{
-        AssetId,
-        UtxoId,
+use fuel_core_interfaces::{
+    common::{
+        fuel_crypto::SecretKey,
+        fuel_storage::Storage,
+        fuel_tx::{
+            AssetId,
+            Input,
+            Output,
+            Transaction,
+            UtxoId,
+            Witness,
+        },
+        fuel_vm::prelude::{
+            Address,
+            Bytes32,
+            Word,
+        },
     },
-    fuel_vm::prelude::{
-        Address,
-        Bytes32,
-        Word,
+    signer::{
+        InMemorySigner,
+        Signer,
     },
 };
 use fuel_gql_client::client::{
@@ -31,6 +43,10 @@ use fuel_gql_client::client::{
     PageDirection,
     PaginationRequest,
 };
+use rand::{
+    rngs::StdRng,
+    SeedableRng,
+};
 
 #[tokio::test]
 async fn coin() {
@@ -345,3 +361,48 @@ async fn coins_to_spend() {
         .await;
     assert!(coins.is_err());
 }
+
+#[tokio::test]
+async fn fees_are_minted_to_block_producer() {
+    let key = SecretKey::random(&mut StdRng::seed_from_u64(2322));
+    let signer = InMemorySigner::new(key);
+    let mut config = Config::local_node();
+    config.signer = SignerConfig::Key(key);
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let tx = Transaction::script(
+        1,
+        100,
+        0,
+        vec![],
+        vec![],
+        vec![Input::coin_signed(
+            Default::default(),
+            Default::default(),
+            1000,
+            Default::default(),
+            Default::default(),
+            0,
+            0,
+        )],
+        vec![Output::change(Default::default(), 0, Default::default())],
+        vec![Witness::default()],
+    );
+    client.submit(&tx).await.unwrap();
+
+    let coins = client
+        .coins(
+            format!("{:#x}", signer.address()).as_str(),
+            None,
+            PaginationRequest {
+                cursor: None,
+                results: 5,
+                direction: PageDirection::Forward,
+            },
+        )
+        .await
+        .unwrap();
+    assert_eq!(coins.results.len(), 1);
+    assert!(coins.results[0].amount.0 > 0);
+}
@@ -2,11 +2,23 @@ use crate::helpers::{
     TestContext,
     TestSetupBuilder,
 };
-use fuel_core_interfaces::common::fuel_vm::prelude::*;
+use fuel_core::service::{
+    Config,
+    FuelService,
+};
+use fuel_core_interfaces::common::{
+    fuel_tx::StorageSlot,
+    fuel_vm::prelude::*,
+};
 use fuel_gql_client::client::{
+    FuelClient,
     PageDirection,
     PaginationRequest,
 };
+use rand::{
+    Rng,
+    SeedableRng,
+};
 use rstest::rstest;
 
 const SEED: u64 = 2322;
@@ -87,3 +99,51 @@ async fn test_first_5_contract_balances(#[case] direction: PageDirection) {
     assert_eq!(contract_balances.results[1].amount.0, 400);
     assert_eq!(contract_balances.results[2].amount.0, 700);
 }
+
+#[tokio::test]
+async fn storage_proof_of_deployed_contract() {
+    let mut rng = rand::rngs::StdRng::seed_from_u64(SEED);
+    let slot = StorageSlot::new(rng.gen(), rng.gen());
+    let salt: Salt = rng.gen();
+    let contract = Contract::from(vec![]);
+    let state_root = Contract::initial_state_root([&slot].into_iter());
+    let contract_id = contract.id(&salt, &contract.root(), &state_root);
+    let tx = Transaction::create(
+        0,
+        0,
+        0,
+        0,
+        salt,
+        vec![slot.clone()],
+        vec![],
+        vec![Output::contract_created(contract_id, state_root)],
+        vec![vec![].into()],
+    );
+
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    client.submit(&tx).await.unwrap();
+
+    let proof = client
+        .storage_proof(
+            1,
+            format!("{:#x}", contract_id).as_str(),
+            format!("{:#x}", slot.key()).as_str(),
+        )
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(proof.value.0 .0, *slot.value());
+    assert_eq!(proof.state_root.0 .0, state_root);
+
+    // the contract isn't touched by the genesis block
+    let proof = client
+        .storage_proof(
+            0,
+            format!("{:#x}", contract_id).as_str(),
+            format!("{:#x}", slot.key()).as_str(),
+        )
+        .await
+        .unwrap();
+    assert!(proof.is_none());
+}
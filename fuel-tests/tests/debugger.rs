@@ -4,7 +4,10 @@ use fuel_core::service::{
     Config,
     FuelService,
 };
-use fuel_core_interfaces::common::fuel_vm::prelude::*;
+use fuel_core_interfaces::common::fuel_vm::{
+    consts::*,
+    prelude::*,
+};
 use fuel_gql_client::client::FuelClient;
 
 /// Tests that debugger doesn't produce any errors with a running local node,
@@ -43,3 +46,59 @@ async fn debugger_integration() {
     let result = client.end_session(session_id).await.unwrap();
     assert!(result);
 }
+
+#[tokio::test]
+async fn trace_transaction_records_steps_and_revert() {
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let script: Vec<u8> = [
+        Opcode::ADDI(0x10, REG_ZERO, 0xca),
+        Opcode::ADDI(0x11, REG_ZERO, 0xba),
+        Opcode::RVRT(0x10),
+    ]
+    .iter()
+    .flat_map(|op| u32::from(*op).to_be_bytes())
+    .collect();
+    let tx = Transaction::script(0, 1_000_000, 0, script, vec![], vec![], vec![], vec![]);
+    client.submit(&tx).await.unwrap();
+    // the replay starts from the state of the block of the transaction
+    client.submit(&Transaction::default()).await.unwrap();
+
+    let trace = client
+        .trace_transaction(&format!("{:#x}", tx.id()))
+        .await
+        .unwrap()
+        .expect("transaction is committed");
+
+    let opcodes: Vec<_> = trace.steps.iter().map(|s| s.opcode.as_str()).collect();
+    assert_eq!(
+        opcodes,
+        ["ADDI(16, 0, 202)", "ADDI(17, 0, 186)", "RVRT(16)"]
+    );
+    assert!(trace
+        .steps
+        .iter()
+        .all(|s| s.contract_id.is_none() && s.gas_used.0 > 0));
+    assert!(trace.calls.is_empty());
+    assert!(trace.storage.is_empty());
+    assert!(!trace.receipts.is_empty());
+
+    assert_eq!(trace.reverts.len(), 1);
+    let revert = &trace.reverts[0];
+    assert_eq!(revert.step.0, 2);
+    assert_eq!(revert.registers[0x10].0, 0xca);
+    assert_eq!(revert.registers[0x11].0, 0xba);
+}
+
+#[tokio::test]
+async fn trace_unknown_transaction() {
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let trace = client
+        .trace_transaction(&format!("{:#x}", Bytes32::zeroed()))
+        .await
+        .unwrap();
+    assert!(trace.is_none());
+}
@@ -10,4 +10,5 @@ mod helpers;
 mod messages;
 mod node_info;
 mod snapshot;
+mod sync;
 mod tx;
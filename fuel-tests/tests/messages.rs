@@ -200,3 +200,14 @@ async fn messages_empty_results_for_owner_with_no_messages() {
 
     assert_eq!(result.results.len(), 0);
 }
+
+#[tokio::test]
+async fn message_proof_of_unknown_message_is_none() {
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let message_id = format!("{:#x}", Address::new([1; 32]));
+    let proof = client.message_proof(&message_id).await.unwrap();
+
+    assert!(proof.is_none());
+}
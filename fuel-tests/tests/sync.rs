@@ -0,0 +1,24 @@
+use fuel_core::service::{
+    Config,
+    FuelService,
+};
+use fuel_gql_client::client::{
+    schema::sync::SyncState,
+    FuelClient,
+};
+
+#[tokio::test]
+async fn sync_status() {
+    let mut node_config = Config::local_node();
+    node_config.manual_blocks_enabled = true;
+    let srv = FuelService::new_node(node_config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    client.produce_blocks(2).await.unwrap();
+
+    let status = client.sync_status().await.unwrap();
+
+    // without peers there is nothing to catch up with
+    assert_eq!(status.state, SyncState::NoPeers);
+    assert_eq!(status.current_height, 2u64.into());
+    assert!(!client.is_synced().await.unwrap());
+}
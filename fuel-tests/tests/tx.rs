@@ -97,6 +97,50 @@ async fn dry_run() {
         } if val == 1));
 }
 
+#[tokio::test]
+async fn estimate_gas() {
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let script = vec![
+        Opcode::ADDI(0x10, REG_ZERO, 0xca),
+        Opcode::ADDI(0x11, REG_ZERO, 0xba),
+        Opcode::LOG(0x10, 0x11, REG_ZERO, REG_ZERO),
+        Opcode::RET(REG_ONE),
+    ];
+    let mut tx = fuel_tx::Transaction::script(
+        0,
+        1_000_000,
+        0,
+        script.into_iter().collect(),
+        vec![],
+        vec![],
+        vec![],
+        vec![],
+    );
+
+    let estimate = client.estimate_gas(&tx).await.unwrap();
+    assert!(estimate.gas_used.0 > 0);
+    assert!(estimate.gas_limit.0 >= estimate.gas_used.0);
+    assert_eq!(estimate.gas_price.0, 0);
+
+    client.fill_gas(&mut tx).await.unwrap();
+    assert_eq!(tx.gas_limit(), estimate.gas_limit.0);
+    let receipts = client.dry_run(&tx).await.unwrap();
+    assert!(matches!(
+        receipts.last(),
+        Some(Receipt::ScriptResult { result, .. }) if *result == ScriptExecutionResult::Success
+    ));
+
+    // the estimate is the smallest gas limit that succeeds
+    tx.set_gas_limit(estimate.gas_limit.0 - 1);
+    let receipts = client.dry_run(&tx).await.unwrap();
+    assert!(matches!(
+        receipts.last(),
+        Some(Receipt::ScriptResult { result, .. }) if *result != ScriptExecutionResult::Success
+    ));
+}
+
 #[tokio::test]
 async fn submit() {
     let srv = FuelService::new_node(Config::local_node()).await.unwrap();
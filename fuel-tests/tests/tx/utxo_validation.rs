@@ -9,11 +9,28 @@ use fuel_core_interfaces::common::{
     fuel_vm::{
         consts::*,
         prelude::*,
+        script_with_data_offset,
     },
 };
 use fuel_crypto::SecretKey;
 use fuel_gql_client::client::{
-    types::TransactionStatus,
+    schema::{
+        primitives::{
+            Bytes,
+            HexString,
+            U64,
+        },
+        tx::{
+            CoinOverride,
+            ContractBalanceOverride,
+            ContractOverride,
+            StateOverrides,
+        },
+    },
+    types::{
+        DryRunStatus,
+        TransactionStatus,
+    },
     PageDirection,
     PaginationRequest,
 };
@@ -218,6 +235,153 @@ async fn dry_run_no_utxo_validation_override() {
     assert!(res.is_err());
 }
 
+// verify that a dry run batch can spend the outputs of earlier transactions of the batch
+#[tokio::test]
+async fn dry_run_batch_spends_outputs_of_earlier_txs() {
+    let mut rng = StdRng::seed_from_u64(2322);
+    let alice = SecretKey::random(&mut rng);
+    let bob = SecretKey::random(&mut rng);
+
+    let tx1 = TransactionBuilder::script(
+        Opcode::RET(REG_ONE).to_bytes().into_iter().collect(),
+        vec![],
+    )
+    .gas_limit(1000)
+    .add_unsigned_coin_input(
+        alice,
+        rng.gen(),
+        1000,
+        Default::default(),
+        Default::default(),
+        0,
+    )
+    .add_output(Output::coin(
+        Input::owner(&bob.public_key()),
+        500,
+        Default::default(),
+    ))
+    .add_output(Output::change(rng.gen(), 0, Default::default()))
+    .finalize();
+    let tx2 = TransactionBuilder::script(
+        Opcode::RET(REG_ONE).to_bytes().into_iter().collect(),
+        vec![],
+    )
+    .gas_limit(1000)
+    .add_unsigned_coin_input(
+        bob,
+        UtxoId::new(tx1.id(), 0),
+        500,
+        Default::default(),
+        Default::default(),
+        0,
+    )
+    .add_output(Output::change(rng.gen(), 0, Default::default()))
+    .finalize();
+
+    let mut test_builder = TestSetupBuilder::new(2322);
+    test_builder.config_coin_inputs_from_transactions(&[&tx1]);
+    let client = test_builder.finalize().await.client;
+
+    let results = client
+        .dry_run_batch(&[tx1.clone(), tx2.clone()], None)
+        .await
+        .unwrap();
+    assert_eq!(results.len(), 2);
+    assert_eq!(results[0].id, format!("{:#x}", tx1.id()));
+    assert_eq!(results[1].id, format!("{:#x}", tx2.id()));
+    for result in &results {
+        assert!(matches!(result.status, DryRunStatus::Success { .. }));
+        assert!(matches!(result.receipts[0], Receipt::Return { val, .. } if val == 1));
+    }
+
+    // the output of tx1 doesn't exist outside of the batch
+    let results = client.dry_run_batch(&[tx2], None).await.unwrap();
+    assert!(matches!(results[0].status, DryRunStatus::Rejected { .. }));
+}
+
+// verify that dry run state overrides are visible to the transaction but never committed
+#[tokio::test]
+async fn dry_run_with_state_overrides() {
+    let mut rng = StdRng::seed_from_u64(2322);
+    let secret = SecretKey::random(&mut rng);
+    let utxo_id: UtxoId = rng.gen();
+    let asset_id: AssetId = rng.gen();
+    let contract_id: ContractId = rng.gen();
+
+    // log the balance of the contract
+    let (script, _) = script_with_data_offset!(
+        data_offset,
+        vec![
+            Opcode::MOVI(0x10, data_offset),
+            Opcode::ADDI(0x11, 0x10, AssetId::LEN as Immediate12),
+            Opcode::BAL(0x12, 0x10, 0x11),
+            Opcode::LOG(0x12, REG_ZERO, REG_ZERO, REG_ZERO),
+            Opcode::RET(REG_ONE),
+        ],
+        ConsensusParameters::DEFAULT.tx_offset()
+    );
+    let script_data = [asset_id.as_ref(), contract_id.as_ref()].concat();
+    let tx = TransactionBuilder::script(script.into_iter().collect(), script_data)
+        .gas_limit(1000)
+        .add_unsigned_coin_input(
+            secret,
+            utxo_id,
+            1000,
+            Default::default(),
+            Default::default(),
+            0,
+        )
+        .add_input(Input::Contract {
+            utxo_id: Default::default(),
+            balance_root: Default::default(),
+            state_root: Default::default(),
+            tx_pointer: Default::default(),
+            contract_id,
+        })
+        .add_output(Output::change(rng.gen(), 0, Default::default()))
+        .add_output(Output::Contract {
+            input_index: 1,
+            balance_root: Default::default(),
+            state_root: Default::default(),
+        })
+        .finalize();
+
+    let client = TestSetupBuilder::new(2322).finalize().await.client;
+    let overrides = StateOverrides {
+        coins: Some(vec![CoinOverride {
+            utxo_id: utxo_id.into(),
+            owner: Input::owner(&secret.public_key()).into(),
+            amount: U64(1000),
+            asset_id: AssetId::default().into(),
+            maturity: None,
+        }]),
+        messages: None,
+        contracts: Some(vec![ContractOverride {
+            contract_id: contract_id.into(),
+            bytecode: Some(HexString(Bytes(Opcode::RET(REG_ONE).to_bytes().to_vec()))),
+            balances: Some(vec![ContractBalanceOverride {
+                asset_id: asset_id.into(),
+                amount: U64(42),
+            }]),
+            storage: None,
+        }]),
+    };
+
+    let log = client
+        .dry_run_with_overrides(&tx, Some(true), Some(overrides))
+        .await
+        .unwrap();
+    assert!(matches!(log[0], Receipt::Log { ra, .. } if ra == 42));
+
+    // the overrides don't reach the node state
+    assert!(client.dry_run_opt(&tx, Some(true)).await.is_err());
+    let balance = client
+        .contract_balance(&contract_id.to_string(), Some(&asset_id.to_string()))
+        .await
+        .unwrap();
+    assert_eq!(balance, 0);
+}
+
 #[tokio::test(flavor = "multi_thread", worker_threads = 8)]
 async fn concurrent_tx_submission_produces_expected_blocks() {
     const TEST_TXS: usize = 10;
@@ -1,19 +1,52 @@
+use fuel_core_interfaces::common::fuel_tx::ConsensusParameters;
+use std::time::Duration;
+
 #[derive(Debug, Clone)]
 pub struct Config {
     /// Maximum number of transactions inside the pool
     pub max_tx: usize,
+    /// Maximum number of transactions inside the pool spending inputs of the same owner
+    pub max_tx_per_owner: usize,
+    /// Maximum total size in bytes of the transactions inside the pool spending inputs of
+    /// the same owner
+    pub max_bytes_per_owner: usize,
     /// max depth of connected UTXO excluding contracts
     pub max_depth: usize,
     /// The minimum allowed gas price
     pub min_gas_price: u64,
+    /// How much higher, in percent, the gas price of a transaction has to be to replace
+    /// a transaction spending the same inputs
+    pub replacement_bump_percent: u64,
+    /// Check that inputs of transactions exist in the database, and that their
+    /// signatures are valid
+    pub utxo_validation: bool,
+    /// Accept transactions with predicates, which are run before insertion
+    pub predicates: bool,
+    /// Limits transactions are checked against before insertion
+    pub consensus_params: ConsensusParameters,
+    /// Maximum serialized size of a transaction, in bytes
+    pub max_tx_size: usize,
+    /// How long a transaction may stay in the pool before it is evicted
+    pub transaction_ttl: Duration,
+    /// How often the pool is swept for expired transactions, must be non-zero
+    pub ttl_check_interval: Duration,
 }
 
 impl Default for Config {
     fn default() -> Self {
         Self {
             max_tx: 4064,
+            max_tx_per_owner: 256,
+            max_bytes_per_owner: 32 * 1024 * 1024,
             max_depth: 10,
             min_gas_price: 0,
+            replacement_bump_percent: 10,
+            utxo_validation: true,
+            predicates: false,
+            consensus_params: ConsensusParameters::DEFAULT,
+            max_tx_size: 16 * 1024 * 1024,
+            transaction_ttl: Duration::from_secs(60 * 10),
+            ttl_check_interval: Duration::from_secs(60),
         }
     }
 }
@@ -1,2 +1,3 @@
 pub mod dependency;
+pub mod owner_usage;
 pub mod price_sort;
@@ -38,6 +38,10 @@ pub struct Dependency {
     messages: HashMap<MessageId, MessageState>,
     /// max depth of dependency.
     max_depth: usize,
+    /// check that inputs which are not part of the txpool exist in the database.
+    utxo_validation: bool,
+    /// minimum increase of the gas price, in percent, for a tx to replace a colliding one.
+    replacement_bump_percent: u64,
 }
 
 #[derive(Debug, Clone)]
@@ -79,17 +83,44 @@ impl ContractState {
 #[derive(Debug, Clone)]
 pub struct MessageState {
     spent_by: TxId,
-    gas_price: GasPrice,
 }
 
 impl Dependency {
-    pub fn new(max_depth: usize) -> Self {
+    pub fn new(
+        max_depth: usize,
+        utxo_validation: bool,
+        replacement_bump_percent: u64,
+    ) -> Self {
         Self {
             coins: HashMap::new(),
             contracts: HashMap::new(),
             messages: HashMap::new(),
             max_depth,
+            utxo_validation,
+            replacement_bump_percent,
+        }
+    }
+
+    /// Check that `tx` can replace `existing`, which spends one of its inputs or creates
+    /// the same contract. `collision` is returned if `tx` is not priced higher at all.
+    fn check_replacement(
+        &self,
+        existing: &ArcTx,
+        tx: &ArcTx,
+        collision: Error,
+    ) -> anyhow::Result<()> {
+        if tx.gas_price() <= existing.gas_price() {
+            return Err(collision.into())
+        }
+        // rounded up, so any non-zero bump asks for at least one more unit of gas price
+        let bump = 100 + self.replacement_bump_percent as u128;
+        let min_gas_price = (existing.gas_price() as u128 * bump).div_ceil(100);
+        if (tx.gas_price() as u128) < min_gas_price
+            || total_fee(tx) <= total_fee(existing)
+        {
+            return Err(Error::NotInsertedReplacementUnderpriced(existing.id()).into())
         }
+        Ok(())
     }
 
     /// find all dependent Transactions that are inside txpool.
@@ -149,6 +180,67 @@ impl Dependency {
         }
     }
 
+    /// find all Transactions inside txpool that spend outputs of provided tx or use
+    /// contracts created by it, directly or through other txs. Does not modify the graph.
+    pub(crate) fn find_descendants(
+        &self,
+        tx: &ArcTx,
+        seen: &mut HashSet<TxId>,
+        txs: &HashMap<TxId, TxInfo>,
+    ) {
+        let mut check = vec![tx.clone()];
+        while let Some(parent) = check.pop() {
+            for (index, output) in parent.outputs().iter().enumerate() {
+                let children: Vec<TxId> = match output {
+                    Output::Message { .. } | Output::Contract { .. } => Vec::new(),
+                    Output::Coin { .. }
+                    | Output::Change { .. }
+                    | Output::Variable { .. } => {
+                        let utxo = UtxoId::new(parent.id(), index as u8);
+                        self.coins
+                            .get(&utxo)
+                            .and_then(|state| state.is_spend_by)
+                            .into_iter()
+                            .collect()
+                    }
+                    Output::ContractCreated { contract_id, .. } => self
+                        .contracts
+                        .get(contract_id)
+                        .map(|state| state.used_by.iter().copied().collect())
+                        .unwrap_or_default(),
+                };
+                for child in children {
+                    if seen.insert(child) {
+                        let child = txs.get(&child).expect("Tx should be present in txs");
+                        check.push(child.tx().clone());
+                    }
+                }
+            }
+        }
+    }
+
+    /// find Transactions inside txpool that created the coins or contracts used by the tx.
+    pub(crate) fn find_parents(
+        &self,
+        tx: &ArcTx,
+        txs: &HashMap<TxId, TxInfo>,
+    ) -> Vec<TxId> {
+        tx.inputs()
+            .iter()
+            .filter_map(|input| match input {
+                Input::CoinSigned { utxo_id, .. }
+                | Input::CoinPredicate { utxo_id, .. } => Some(*utxo_id.tx_id()),
+                Input::Contract { contract_id, .. } => self
+                    .contracts
+                    .get(contract_id)
+                    .and_then(|state| state.origin)
+                    .map(|origin| *origin.tx_id()),
+                Input::MessageSigned { .. } | Input::MessagePredicate { .. } => None,
+            })
+            .filter(|parent| txs.contains_key(parent))
+            .collect()
+    }
+
     fn check_if_coin_input_can_spend_db_coin(
         coin: &Coin,
         input: &Input,
@@ -349,13 +441,13 @@ impl Dependency {
                                 .get(spend_by)
                                 .expect("Tx should be always present in txpool");
                             // compare if tx has better price
-                            if txpool_tx.gas_price() > tx.gas_price() {
-                                return Err(Error::NotInsertedCollision(
-                                    *spend_by, *utxo_id,
-                                )
-                                .into())
-                            } else {
-                                if state.is_in_database() {
+                            self.check_replacement(
+                                txpool_tx,
+                                tx,
+                                Error::NotInsertedCollision(*spend_by, *utxo_id),
+                            )?;
+                            if state.is_in_database() {
+                                if self.utxo_validation {
                                     // this means it is loaded from db. Get tx to compare output.
                                     let coin = db.utxo(utxo_id)?.ok_or(
                                         Error::NotInsertedInputUtxoIdNotExisting(
@@ -365,27 +457,29 @@ impl Dependency {
                                     Self::check_if_coin_input_can_spend_db_coin(
                                         &coin, input,
                                     )?;
-                                } else {
-                                    // tx output is in pool
-                                    let output_tx = txs.get(utxo_id.tx_id()).unwrap();
-                                    let output = &output_tx.outputs()
-                                        [utxo_id.output_index() as usize];
-                                    Self::check_if_coin_input_can_spend_output(
-                                        output, input, false,
-                                    )?;
-                                };
-
-                                collided.push(*spend_by);
-                            }
+                                }
+                            } else {
+                                // tx output is in pool
+                                let output_tx = txs.get(utxo_id.tx_id()).unwrap();
+                                let output =
+                                    &output_tx.outputs()[utxo_id.output_index() as usize];
+                                Self::check_if_coin_input_can_spend_output(
+                                    output, input, false,
+                                )?;
+                            };
+
+                            collided.push(*spend_by);
                         }
                         // if coin is not spend, it will be spend later down the line
                     } else {
-                        // fetch from db and check if tx exist.
-                        let coin = db
-                            .utxo(utxo_id)?
-                            .ok_or(Error::NotInsertedInputUtxoIdNotExisting(*utxo_id))?;
+                        if self.utxo_validation {
+                            // fetch from db and check if tx exist.
+                            let coin = db.utxo(utxo_id)?.ok_or(
+                                Error::NotInsertedInputUtxoIdNotExisting(*utxo_id),
+                            )?;
 
-                        Self::check_if_coin_input_can_spend_db_coin(&coin, input)?;
+                            Self::check_if_coin_input_can_spend_db_coin(&coin, input)?;
+                        }
                         max_depth = core::cmp::max(1, max_depth);
                         db_coins.insert(
                             *utxo_id,
@@ -403,38 +497,38 @@ impl Dependency {
                     // verify message id integrity
                     Self::check_if_message_input_matches_id(input)?;
                     // since message id is derived, we don't need to double check all the fields
-                    if let Some(msg) = db.message(*message_id)? {
-                        // return an error if spent block is set
-                        if msg.fuel_block_spend.is_some() {
+                    if self.utxo_validation {
+                        if let Some(msg) = db.message(*message_id)? {
+                            // return an error if spent block is set
+                            if msg.fuel_block_spend.is_some() {
+                                return Err(Error::NotInsertedInputMessageIdSpent(
+                                    *message_id,
+                                )
+                                .into())
+                            }
+                        } else {
                             return Err(
-                                Error::NotInsertedInputMessageIdSpent(*message_id).into()
+                                Error::NotInsertedInputMessageUnknown(*message_id).into()
                             )
                         }
-                    } else {
-                        return Err(
-                            Error::NotInsertedInputMessageUnknown(*message_id).into()
-                        )
                     }
 
                     if let Some(state) = self.messages.get(message_id) {
                         // some other is already attempting to spend this message, compare gas price
-                        if state.gas_price >= tx.gas_price() {
-                            return Err(Error::NotInsertedCollisionMessageId(
+                        let txpool_tx = txs
+                            .get(&state.spent_by)
+                            .expect("Tx should be always present in txpool");
+                        self.check_replacement(
+                            txpool_tx,
+                            tx,
+                            Error::NotInsertedCollisionMessageId(
                                 state.spent_by,
                                 *message_id,
-                            )
-                            .into())
-                        } else {
-                            collided.push(state.spent_by);
-                        }
+                            ),
+                        )?;
+                        collided.push(state.spent_by);
                     }
-                    db_messages.insert(
-                        *message_id,
-                        MessageState {
-                            spent_by: tx.id(),
-                            gas_price: tx.gas_price(),
-                        },
-                    );
+                    db_messages.insert(*message_id, MessageState { spent_by: tx.id() });
                 }
                 Input::Contract { contract_id, .. } => {
                     // Does contract exist. We don't need to do any check here other then if contract_id exist or not.
@@ -452,7 +546,7 @@ impl Dependency {
                             return Err(Error::NotInsertedMaxDepth.into())
                         }
                     } else {
-                        if !db.contract_exist(*contract_id)? {
+                        if self.utxo_validation && !db.contract_exist(*contract_id)? {
                             return Err(Error::NotInsertedInputContractNotExisting(
                                 *contract_id,
                             )
@@ -488,17 +582,19 @@ impl Dependency {
                             Error::NotInsertedContractIdAlreadyTaken(*contract_id).into()
                         )
                     }
-                    // check who is priced more
-                    if contract.gas_price > tx.gas_price() {
-                        // new tx is priced less then current tx
-                        return Err(
-                            Error::NotInsertedCollisionContractId(*contract_id).into()
-                        )
-                    }
-                    // if we are prices more, mark current contract origin for removal.
                     let origin = contract.origin.expect(
                         "Only contract without origin are the ones that are inside DB. And we check depth for that, so we are okay to just unwrap"
                         );
+                    // check who is priced more
+                    let txpool_tx = txs
+                        .get(origin.tx_id())
+                        .expect("Tx should be always present in txpool");
+                    self.check_replacement(
+                        txpool_tx,
+                        tx,
+                        Error::NotInsertedCollisionContractId(*contract_id),
+                    )?;
+                    // if we are prices more, mark current contract origin for removal.
                     collided.push(*origin.tx_id());
                 }
             }
@@ -508,6 +604,27 @@ impl Dependency {
         Ok((max_depth, db_coins, db_contracts, db_messages, collided))
     }
 
+    /// Transactions that inserting `tx` would remove from the pool: the ones it collides
+    /// with and their dependents. Does not modify the graph.
+    pub(crate) fn replaced_by(
+        &self,
+        txs: &HashMap<TxId, TxInfo>,
+        db: &dyn TxPoolDb,
+        tx: &ArcTx,
+    ) -> anyhow::Result<Vec<ArcTx>> {
+        let (.., collided) = self.check_for_collision(txs, db, tx)?;
+        let mut replaced = HashSet::new();
+        for collided in collided {
+            if replaced.insert(collided) {
+                self.find_descendants(txs[&collided].tx(), &mut replaced, txs);
+            }
+        }
+        Ok(replaced
+            .into_iter()
+            .map(|tx_id| txs[&tx_id].tx().clone())
+            .collect())
+    }
+
     /// insert tx inside dependency
     /// return list of transactions that are removed from txpool
     pub(crate) async fn insert<'a>(
@@ -697,6 +814,11 @@ impl Dependency {
     }
 }
 
+/// Fee the transaction pays if it uses all of its gas, with its bytes priced like gas.
+fn total_fee(tx: &ArcTx) -> u128 {
+    tx.gas_price() as u128 * (tx.gas_limit() as u128 + tx.metered_bytes_size() as u128)
+}
+
 #[cfg(test)]
 mod tests {
 
@@ -0,0 +1,79 @@
+use fuel_core_interfaces::{
+    common::{
+        fuel_tx::Address,
+        fuel_types::bytes::SizedBytes,
+    },
+    model::ArcTx,
+};
+use std::collections::{
+    BTreeSet,
+    HashMap,
+};
+
+/// Number and total size of the pooled transactions of every input owner.
+#[derive(Debug, Default, Clone)]
+pub struct OwnerUsage {
+    by_owner: HashMap<Address, Usage>,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Usage {
+    pub txs: usize,
+    pub bytes: usize,
+}
+
+impl OwnerUsage {
+    pub fn get(&self, owner: &Address) -> Usage {
+        self.by_owner.get(owner).copied().unwrap_or_default()
+    }
+
+    /// First owner of `tx` that would go over one of the limits if `tx` was added and the
+    /// `replaced` transactions were removed.
+    pub fn over_limit(
+        &self,
+        tx: &ArcTx,
+        replaced: &[ArcTx],
+        max_txs: usize,
+        max_bytes: usize,
+    ) -> Option<Address> {
+        let size = tx.serialized_size();
+        owners(tx).into_iter().find(|owner| {
+            let mut usage = self.get(owner);
+            for replaced in replaced.iter().filter(|tx| owners(tx).contains(owner)) {
+                usage.txs = usage.txs.saturating_sub(1);
+                usage.bytes = usage.bytes.saturating_sub(replaced.serialized_size());
+            }
+            usage.txs >= max_txs || usage.bytes.saturating_add(size) > max_bytes
+        })
+    }
+
+    pub fn insert(&mut self, tx: &ArcTx) {
+        let size = tx.serialized_size();
+        for owner in owners(tx) {
+            let usage = self.by_owner.entry(owner).or_default();
+            usage.txs += 1;
+            usage.bytes += size;
+        }
+    }
+
+    pub fn remove(&mut self, tx: &ArcTx) {
+        let size = tx.serialized_size();
+        for owner in owners(tx) {
+            if let Some(usage) = self.by_owner.get_mut(&owner) {
+                usage.txs = usage.txs.saturating_sub(1);
+                usage.bytes = usage.bytes.saturating_sub(size);
+                if usage.txs == 0 {
+                    self.by_owner.remove(&owner);
+                }
+            }
+        }
+    }
+}
+
+/// A transaction counts once towards every owner of its inputs.
+fn owners(tx: &ArcTx) -> BTreeSet<Address> {
+    tx.inputs()
+        .iter()
+        .filter_map(|input| input.input_owner().copied())
+        .collect()
+}
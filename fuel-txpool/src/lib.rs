@@ -7,7 +7,10 @@ pub mod types;
 #[cfg(test)]
 mod mock_db;
 #[cfg(test)]
-pub(crate) use mock_db::MockDb;
+pub(crate) use mock_db::{
+    MockDb,
+    MockJournal,
+};
 
 pub use config::Config;
 pub use fuel_core_interfaces::txpool::Error;
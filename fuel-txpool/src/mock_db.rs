@@ -14,6 +14,8 @@ use fuel_core_interfaces::{
             Contract,
             ContractId,
             MessageId,
+            Transaction,
+            TxId,
             UtxoId,
         },
     },
@@ -25,7 +27,10 @@ use fuel_core_interfaces::{
         Coin,
         Message,
     },
-    txpool::TxPoolDb,
+    txpool::{
+        TxPoolDb,
+        TxPoolJournal,
+    },
 };
 
 #[derive(Default)]
@@ -141,3 +146,24 @@ impl Storage<MessageId, Message> for MockDb {
 }
 
 impl TxPoolDb for MockDb {}
+
+#[derive(Clone, Debug, Default)]
+pub(crate) struct MockJournal {
+    pub txs: Arc<Mutex<HashMap<TxId, Transaction>>>,
+}
+
+impl TxPoolJournal for MockJournal {
+    fn record(&self, tx: &Transaction) -> Result<(), KvStoreError> {
+        self.txs.lock().unwrap().insert(tx.id(), tx.clone());
+        Ok(())
+    }
+
+    fn forget(&self, tx_id: &TxId) -> Result<(), KvStoreError> {
+        self.txs.lock().unwrap().remove(tx_id);
+        Ok(())
+    }
+
+    fn transactions(&self) -> Result<Vec<Transaction>, KvStoreError> {
+        Ok(self.txs.lock().unwrap().values().cloned().collect())
+    }
+}
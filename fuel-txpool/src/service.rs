@@ -8,6 +8,7 @@ use fuel_core_interfaces::{
     txpool::{
         self,
         TxPoolDb,
+        TxPoolJournal,
         TxPoolMpsc,
         TxStatusBroadcast,
     },
@@ -15,13 +16,17 @@ use fuel_core_interfaces::{
 use std::sync::Arc;
 use tokio::{
     sync::{
-        broadcast,
+        broadcast::{
+            self,
+            error::RecvError,
+        },
         mpsc,
         Mutex,
         RwLock,
     },
     task::JoinHandle,
 };
+use tracing::warn;
 
 pub struct ServiceBuilder {
     sender: txpool::Sender,
@@ -29,6 +34,7 @@ pub struct ServiceBuilder {
     config: Config,
     broadcast: broadcast::Sender<TxStatusBroadcast>,
     db: Option<Box<dyn TxPoolDb>>,
+    journal: Option<Box<dyn TxPoolJournal>>,
     import_block_events: Option<broadcast::Receiver<ImportBlockBroadcast>>,
 }
 
@@ -46,6 +52,7 @@ impl ServiceBuilder {
             sender: txpool::Sender::new(sender),
             receiver,
             db: None,
+            journal: None,
             broadcast,
             config: Default::default(),
             import_block_events: None,
@@ -65,6 +72,13 @@ impl ServiceBuilder {
         self
     }
 
+    /// Record the transactions of the pool in `journal`, and restore the ones it already
+    /// contains when the service is built.
+    pub fn journal(&mut self, journal: Box<dyn TxPoolJournal>) -> &mut Self {
+        self.journal = Some(journal);
+        self
+    }
+
     pub fn import_block_event(
         &mut self,
         import_block_event: broadcast::Receiver<ImportBlockBroadcast>,
@@ -78,17 +92,25 @@ impl ServiceBuilder {
         self
     }
 
-    pub fn build(self) -> anyhow::Result<Service> {
+    pub async fn build(self) -> anyhow::Result<Service> {
         if self.db.is_none() || self.import_block_events.is_none() {
             return Err(anyhow!("One of context items are not set"))
         }
+        let db = Arc::new(self.db.unwrap());
+        let mut txpool = TxPool::new(self.config.clone());
+        if let Some(journal) = self.journal {
+            txpool
+                .restore(Arc::from(journal), db.as_ref().as_ref())
+                .await?;
+        }
         let service = Service::new(
             self.sender,
             self.broadcast.clone(),
             Context {
+                txpool: Arc::new(RwLock::new(txpool)),
                 receiver: self.receiver,
                 broadcast: self.broadcast,
-                db: Arc::new(self.db.unwrap()),
+                db,
                 import_block_events: self.import_block_events.unwrap(),
                 config: self.config,
             },
@@ -99,6 +121,7 @@ impl ServiceBuilder {
 
 pub struct Context {
     pub config: Config,
+    pub txpool: Arc<RwLock<TxPool>>,
     pub broadcast: broadcast::Sender<TxStatusBroadcast>,
     pub db: Arc<Box<dyn TxPoolDb>>,
     pub receiver: mpsc::Receiver<TxPoolMpsc>,
@@ -107,10 +130,38 @@ pub struct Context {
 
 impl Context {
     pub async fn run(mut self) -> Self {
-        let txpool = Arc::new(RwLock::new(TxPool::new(self.config.clone())));
+        let txpool = self.txpool.clone();
+        let mut import_block_events_closed = false;
+        let mut ttl_sweep = tokio::time::interval(self.config.ttl_check_interval);
 
         loop {
             tokio::select! {
+                // blocks are pruned before serving requests that are sent after the import,
+                // so the next produced block never includes transactions of imported blocks
+                biased;
+
+                block_updated = self.import_block_events.recv(), if !import_block_events_closed => {
+                    match block_updated {
+                        // pending blocks are not committed yet, so the pool keeps their transactions
+                        Ok(ImportBlockBroadcast::SealedFuelBlockImported { block, .. }) => {
+                            TxPool::block_update(
+                                txpool.as_ref(),
+                                self.db.as_ref().as_ref(),
+                                self.broadcast.clone(),
+                                &block,
+                            )
+                            .await
+                        }
+                        Ok(ImportBlockBroadcast::PendingFuelBlockImported { .. }) => {}
+                        Err(RecvError::Lagged(skipped)) => {
+                            warn!("TxPool missed {} imported blocks", skipped);
+                        }
+                        Err(RecvError::Closed) => import_block_events_closed = true,
+                    }
+                }
+                _ = ttl_sweep.tick() => {
+                    TxPool::prune_old_txs(txpool.as_ref(), self.broadcast.clone()).await
+                }
                 event = self.receiver.recv() => {
                     if matches!(event,Some(TxPoolMpsc::Stop) | None) {
                         break;
@@ -123,8 +174,8 @@ impl Context {
                     tokio::spawn( async move {
                         let txpool = txpool.as_ref();
                     match event.unwrap() {
-                        TxPoolMpsc::Includable { response } => {
-                            let _ = response.send(TxPool::includable(txpool).await);
+                        TxPoolMpsc::Includable { max_gas, max_size, response } => {
+                            let _ = response.send(TxPool::includable(txpool, max_gas, max_size).await);
                         }
                         TxPoolMpsc::Insert { txs, response } => {
                             let _ = response.send(TxPool::insert(txpool,db.as_ref().as_ref(),broadcast, txs).await);
@@ -144,15 +195,12 @@ impl Context {
                         TxPoolMpsc::Remove { ids, response } => {
                             let _ = response.send(TxPool::remove(txpool,broadcast,&ids).await);
                         }
+                        TxPoolMpsc::GasPrice { response } => {
+                            let _ = response.send(TxPool::gas_price(txpool).await);
+                        }
                         TxPoolMpsc::Stop => {}
                     }});
                 }
-                _block_updated = self.import_block_events.recv() => {
-                    let txpool = txpool.clone();
-                    tokio::spawn( async move {
-                        TxPool::block_update(txpool.as_ref()).await
-                    });
-                }
             }
         }
         self
@@ -221,9 +269,24 @@ impl Service {
 #[cfg(any(test))]
 pub mod tests {
     use super::*;
-    use crate::MockDb;
+    use crate::{
+        MockDb,
+        MockJournal,
+    };
     use fuel_core_interfaces::{
-        common::fuel_tx::TransactionBuilder,
+        common::{
+            fuel_tx::{
+                Input,
+                Transaction,
+                TransactionBuilder,
+                UtxoId,
+            },
+            fuel_types::Word,
+        },
+        model::{
+            FuelBlock,
+            SealedFuelBlock,
+        },
         txpool::{
             Error as TxpoolError,
             TxStatus,
@@ -231,9 +294,36 @@ pub mod tests {
     };
     use tokio::sync::oneshot;
 
+    /// Coins are not looked up in the database, so transactions can pay for gas without
+    /// any state.
+    fn test_config() -> Config {
+        Config {
+            utxo_validation: false,
+            ..Default::default()
+        }
+    }
+
+    fn funded_tx(gas_price: Word) -> Arc<Transaction> {
+        Arc::new(
+            TransactionBuilder::script(vec![], vec![])
+                .gas_price(gas_price)
+                .add_input(Input::coin_signed(
+                    UtxoId::new([gas_price as u8; 32].into(), 0),
+                    Default::default(),
+                    1_000,
+                    Default::default(),
+                    Default::default(),
+                    0,
+                    0,
+                ))
+                .add_witness(Default::default())
+                .finalize_without_signature(),
+        )
+    }
+
     #[tokio::test]
     async fn test_start_stop() {
-        let config = Config::default();
+        let config = test_config();
         let db = Box::new(MockDb::default());
         let (bs, _br) = broadcast::channel(10);
 
@@ -242,7 +332,7 @@ pub mod tests {
             .config(config)
             .db(db)
             .import_block_event(bs.subscribe());
-        let service = builder.build().unwrap();
+        let service = builder.build().await.unwrap();
 
         assert!(service.start().await.is_ok(), "start service");
 
@@ -258,30 +348,18 @@ pub mod tests {
 
     #[tokio::test]
     async fn test_filter_by_negative() {
-        let config = Config::default();
+        let config = test_config();
         let db = Box::new(MockDb::default());
         let (_bs, br) = broadcast::channel(10);
 
         let mut builder = ServiceBuilder::new();
         builder.config(config).db(db).import_block_event(br);
-        let service = builder.build().unwrap();
+        let service = builder.build().await.unwrap();
         service.start().await.ok();
 
-        let tx1 = Arc::new(
-            TransactionBuilder::script(vec![], vec![])
-                .gas_price(10)
-                .finalize(),
-        );
-        let tx2 = Arc::new(
-            TransactionBuilder::script(vec![], vec![])
-                .gas_price(20)
-                .finalize(),
-        );
-        let tx3 = Arc::new(
-            TransactionBuilder::script(vec![], vec![])
-                .gas_price(30)
-                .finalize(),
-        );
+        let tx1 = funded_tx(10);
+        let tx2 = funded_tx(20);
+        let tx3 = funded_tx(30);
 
         let (response, receiver) = oneshot::channel();
         let _ = service
@@ -314,29 +392,17 @@ pub mod tests {
 
     #[tokio::test]
     async fn test_find() {
-        let config = Config::default();
+        let config = test_config();
         let db = Box::new(MockDb::default());
         let (_bs, br) = broadcast::channel(10);
 
-        let tx1 = Arc::new(
-            TransactionBuilder::script(vec![], vec![])
-                .gas_price(10)
-                .finalize(),
-        );
-        let tx2 = Arc::new(
-            TransactionBuilder::script(vec![], vec![])
-                .gas_price(20)
-                .finalize(),
-        );
-        let tx3 = Arc::new(
-            TransactionBuilder::script(vec![], vec![])
-                .gas_price(30)
-                .finalize(),
-        );
+        let tx1 = funded_tx(10);
+        let tx2 = funded_tx(20);
+        let tx3 = funded_tx(30);
 
         let mut builder = ServiceBuilder::new();
         builder.config(config).db(db).import_block_event(br);
-        let service = builder.build().unwrap();
+        let service = builder.build().await.unwrap();
         service.start().await.ok();
 
         let (response, receiver) = oneshot::channel();
@@ -371,24 +437,16 @@ pub mod tests {
 
     #[tokio::test]
     async fn simple_insert_removal_subscription() {
-        let config = Config::default();
+        let config = test_config();
         let db = Box::new(MockDb::default());
         let (_bs, br) = broadcast::channel(10);
 
-        let tx1 = Arc::new(
-            TransactionBuilder::script(vec![], vec![])
-                .gas_price(10)
-                .finalize(),
-        );
-        let tx2 = Arc::new(
-            TransactionBuilder::script(vec![], vec![])
-                .gas_price(20)
-                .finalize(),
-        );
+        let tx1 = funded_tx(10);
+        let tx2 = funded_tx(20);
 
         let mut builder = ServiceBuilder::new();
         builder.config(config).db(db).import_block_event(br);
-        let service = builder.build().unwrap();
+        let service = builder.build().await.unwrap();
         service.start().await.ok();
 
         let mut subscribe = service.subscribe_ch();
@@ -459,4 +517,97 @@ pub mod tests {
             "Second removed should be tx2"
         );
     }
+
+    #[tokio::test]
+    async fn imported_block_evicts_included_tx() {
+        let config = test_config();
+        let db = Box::new(MockDb::default());
+        let (bs, br) = broadcast::channel(10);
+
+        let tx1 = funded_tx(10);
+
+        let mut builder = ServiceBuilder::new();
+        builder.config(config).db(db).import_block_event(br);
+        let service = builder.build().await.unwrap();
+        service.start().await.ok();
+
+        let mut subscribe = service.subscribe_ch();
+
+        let out = service.sender().insert(vec![tx1.clone()]).await.unwrap();
+        assert!(out[0].is_ok(), "Tx1 should be OK, got err:{:?}", out);
+        assert_eq!(subscribe.recv().await.unwrap().status, TxStatus::Submitted);
+
+        let block = SealedFuelBlock {
+            block: FuelBlock {
+                header: Default::default(),
+                transactions: vec![tx1.as_ref().clone()],
+            },
+            consensus: Default::default(),
+        };
+        bs.send(ImportBlockBroadcast::SealedFuelBlockImported {
+            block: Arc::new(block),
+            is_created_by_self: false,
+        })
+        .unwrap();
+
+        assert_eq!(
+            tokio::time::timeout(std::time::Duration::from_secs(2), subscribe.recv())
+                .await,
+            Ok(Ok(TxStatusBroadcast {
+                tx: tx1.clone(),
+                status: TxStatus::Executed,
+            })),
+            "Included tx1 should be executed"
+        );
+        let out = service.sender().find(vec![tx1.id()]).await.unwrap();
+        assert!(out[0].is_none(), "Tx1 should be evicted:{:?}", out);
+        service.stop().await.unwrap().await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn journal_restored_and_kept_up_to_date() {
+        let db = Box::new(MockDb::default());
+        let (_bs, br) = broadcast::channel(10);
+        let journal = MockJournal::default();
+
+        let tx1 = funded_tx(10);
+        let tx2 = funded_tx(20);
+        // can't pay for its gas anymore
+        let invalid = Arc::new(
+            TransactionBuilder::script(vec![], vec![])
+                .gas_price(30)
+                .finalize(),
+        );
+        for tx in [&tx1, &invalid] {
+            journal.record(tx).unwrap();
+        }
+
+        let mut builder = ServiceBuilder::new();
+        builder
+            .config(test_config())
+            .db(db)
+            .journal(Box::new(journal.clone()))
+            .import_block_event(br);
+        let service = builder.build().await.unwrap();
+        service.start().await.ok();
+
+        let out = service
+            .sender()
+            .find(vec![tx1.id(), invalid.id()])
+            .await
+            .unwrap();
+        assert!(out[0].is_some(), "Tx1 should be restored:{:?}", out);
+        assert!(out[1].is_none(), "Invalid tx should be dropped:{:?}", out);
+        let recorded = |tx_id| journal.txs.lock().unwrap().contains_key(&tx_id);
+        assert!(!recorded(invalid.id()));
+
+        let out = service.sender().insert(vec![tx2.clone()]).await.unwrap();
+        assert!(out[0].is_ok(), "Tx2 should be OK, got err:{:?}", out);
+        assert!(recorded(tx2.id()));
+
+        service.sender().remove(vec![tx1.id()]).await.unwrap();
+        assert!(!recorded(tx1.id()));
+        assert!(recorded(tx2.id()));
+        service.stop().await.unwrap().await.unwrap();
+    }
 }
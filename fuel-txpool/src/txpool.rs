@@ -1,47 +1,86 @@
 use crate::{
     containers::{
         dependency::Dependency,
+        owner_usage::OwnerUsage,
         price_sort::PriceSort,
     },
     types::*,
     Config,
     Error,
 };
+use chrono::{
+    DateTime,
+    Utc,
+};
 use fuel_core_interfaces::{
+    common::{
+        fuel_tx::{
+            CheckedTransaction,
+            Input,
+            Output,
+            UtxoId,
+        },
+        fuel_types::{
+            bytes::SizedBytes,
+            MessageId,
+            Word,
+        },
+        fuel_vm::prelude::{
+            Interpreter,
+            PredicateStorage,
+        },
+    },
     model::{
         ArcTx,
+        FuelBlock,
         TxInfo,
     },
     txpool::{
         TxPoolDb,
+        TxPoolJournal,
         TxStatus,
         TxStatusBroadcast,
     },
 };
 use std::{
     cmp::Reverse,
-    collections::HashMap,
+    collections::{
+        HashMap,
+        HashSet,
+    },
+    sync::Arc,
 };
 use tokio::sync::{
     broadcast,
     RwLock,
 };
+use tracing::warn;
 
 #[derive(Debug, Clone)]
 pub struct TxPool {
     by_hash: HashMap<TxId, TxInfo>,
     by_gas_price: PriceSort,
     by_dependency: Dependency,
+    by_owner: OwnerUsage,
+    journal: Option<Arc<dyn TxPoolJournal>>,
     config: Config,
 }
 
 impl TxPool {
     pub fn new(config: Config) -> Self {
         let max_depth = config.max_depth;
+        let utxo_validation = config.utxo_validation;
+        let replacement_bump_percent = config.replacement_bump_percent;
         Self {
             by_hash: HashMap::new(),
             by_gas_price: PriceSort::default(),
-            by_dependency: Dependency::new(max_depth),
+            by_dependency: Dependency::new(
+                max_depth,
+                utxo_validation,
+                replacement_bump_percent,
+            ),
+            by_owner: OwnerUsage::default(),
+            journal: None,
             config,
         }
     }
@@ -59,6 +98,17 @@ impl TxPool {
         tx: ArcTx,
         db: &dyn TxPoolDb,
     ) -> anyhow::Result<Vec<ArcTx>> {
+        self.insert_info(TxInfo::new(tx), db).await
+    }
+
+    /// Same as `insert_inner`, keeping the submission time of a transaction put back into
+    /// the pool.
+    async fn insert_info(
+        &mut self,
+        info: TxInfo,
+        db: &dyn TxPoolDb,
+    ) -> anyhow::Result<Vec<ArcTx>> {
+        let tx = info.tx().clone();
         if tx.metadata().is_none() {
             return Err(Error::NoMetadata.into())
         }
@@ -70,6 +120,25 @@ impl TxPool {
             return Err(Error::NotInsertedTxKnown.into())
         }
 
+        // a single owner can't take over the pool, the transactions replaced by `tx` don't
+        // count towards the limits
+        let (max_txs, max_bytes) = (
+            self.config.max_tx_per_owner,
+            self.config.max_bytes_per_owner,
+        );
+        if self
+            .by_owner
+            .over_limit(&tx, &[], max_txs, max_bytes)
+            .is_some()
+        {
+            let replaced = self.by_dependency.replaced_by(&self.by_hash, db, &tx)?;
+            if let Some(owner) =
+                self.by_owner.over_limit(&tx, &replaced, max_txs, max_bytes)
+            {
+                return Err(Error::NotInsertedOwnerLimitHit(owner).into())
+            }
+        }
+
         let mut max_limit_hit = false;
         // check if we are hitting limit of pool
         if self.by_hash.len() >= self.config.max_tx {
@@ -82,8 +151,10 @@ impl TxPool {
         }
         // check and insert dependency
         let rem = self.by_dependency.insert(&self.by_hash, db, &tx).await?;
-        self.by_hash.insert(tx.id(), TxInfo::new(tx.clone()));
+        self.by_hash.insert(tx.id(), info);
         self.by_gas_price.insert(&tx);
+        self.by_owner.insert(&tx);
+        self.journal_record(&tx);
 
         // if some transaction were removed so we don't need to check limit
         if rem.is_empty() {
@@ -101,12 +172,70 @@ impl TxPool {
                     .remove(&rem.id())
                     .expect("Expect to hash of tx to be present");
                 self.by_gas_price.remove(rem);
+                self.by_owner.remove(rem);
+                self.journal_forget(&rem.id());
             }
 
             Ok(rem)
         }
     }
 
+    /// Insert again the transactions recorded in `journal` before a restart, and keep
+    /// recording to it. Transactions that are no longer valid are dropped from the journal.
+    pub async fn restore(
+        &mut self,
+        journal: Arc<dyn TxPoolJournal>,
+        db: &dyn TxPoolDb,
+    ) -> anyhow::Result<()> {
+        let txs = journal
+            .transactions()?
+            .into_iter()
+            .map(|mut tx| {
+                tx.precompute_metadata();
+                Arc::new(tx)
+            })
+            .collect();
+        self.journal = Some(journal.clone());
+        for tx in parents_first(txs) {
+            let result = match Self::check_tx(&self.config, &tx) {
+                Ok(()) => self.insert_inner(tx.clone(), db).await,
+                Err(err) => Err(err.into()),
+            };
+            if let Err(err) = result {
+                warn!(
+                    "Dropping transaction {:#x} from the journal: {}",
+                    tx.id(),
+                    err
+                );
+                journal.forget(&tx.id())?;
+            }
+        }
+        Ok(())
+    }
+
+    fn journal_record(&self, tx: &Transaction) {
+        if let Some(journal) = &self.journal {
+            if let Err(err) = journal.record(tx) {
+                warn!(
+                    "Failed to record transaction {:#x} in the journal: {}",
+                    tx.id(),
+                    err
+                );
+            }
+        }
+    }
+
+    fn journal_forget(&self, tx_id: &TxId) {
+        if let Some(journal) = &self.journal {
+            if let Err(err) = journal.forget(tx_id) {
+                warn!(
+                    "Failed to remove transaction {:#x} from the journal: {}",
+                    tx_id, err
+                );
+            }
+        }
+    }
+
     /// Return all sorted transactions that are includable in next block.
     pub fn sorted_includable(&self) -> Vec<ArcTx> {
         self.by_gas_price
@@ -117,6 +246,54 @@ impl TxPool {
             .collect()
     }
 
+    /// Return sorted transactions that fit into next block. Transactions that would exceed
+    /// `max_gas` or `max_size` are skipped together with all txs that depend on them,
+    /// while smaller transactions further down the list can still be included.
+    /// Transactions are only included after all of their parents inside the txpool,
+    /// so a child priced above its parent waits for the parent to be included.
+    pub fn sorted_includable_within(&self, max_gas: Word, max_size: usize) -> Vec<ArcTx> {
+        let mut gas = 0;
+        let mut size = 0;
+        let mut included = HashSet::new();
+        let mut skipped = HashSet::new();
+        // children waiting for one of their parents to be included
+        let mut waiting: HashMap<TxId, Vec<ArcTx>> = HashMap::new();
+        let mut includable = Vec::new();
+        for (_, tx) in self.by_gas_price.sort.iter().rev() {
+            let mut ready = vec![tx.clone()];
+            while let Some(tx) = ready.pop() {
+                if skipped.contains(&tx.id()) {
+                    continue
+                }
+                if let Some(parent) = self
+                    .by_dependency
+                    .find_parents(&tx, &self.by_hash)
+                    .into_iter()
+                    .find(|parent| !included.contains(parent))
+                {
+                    waiting.entry(parent).or_default().push(tx);
+                    continue
+                }
+                let tx_gas = gas + tx.gas_limit();
+                let tx_size = size + tx.serialized_size();
+                if tx_gas <= max_gas && tx_size <= max_size {
+                    gas = tx_gas;
+                    size = tx_size;
+                    included.insert(tx.id());
+                    // keep the children ordered by price
+                    if let Some(children) = waiting.remove(&tx.id()) {
+                        ready.extend(children.into_iter().rev());
+                    }
+                    includable.push(tx);
+                } else {
+                    self.by_dependency
+                        .find_descendants(&tx, &mut skipped, &self.by_hash);
+                }
+            }
+        }
+        includable
+    }
+
     pub fn remove_inner(&mut self, tx: &ArcTx) -> Vec<ArcTx> {
         self.remove_by_tx_id(&tx.id())
     }
@@ -129,6 +306,8 @@ impl TxPool {
                 .recursively_remove_all_dependencies(&self.by_hash, tx.tx().clone());
             for remove in removed.iter() {
                 self.by_gas_price.remove(remove);
+                self.by_owner.remove(remove);
+                self.journal_forget(&remove.id());
                 self.by_hash.remove(&remove.id());
             }
             return removed
@@ -136,6 +315,125 @@ impl TxPool {
         Vec::new()
     }
 
+    /// Evict transactions that stayed in the pool longer than the configured TTL at `now`,
+    /// together with their dependents. Returns the removed transactions.
+    pub fn prune_old_txs_inner(&mut self, now: DateTime<Utc>) -> Vec<ArcTx> {
+        let ttl = match chrono::Duration::from_std(self.config.transaction_ttl) {
+            Ok(ttl) => ttl,
+            // too long to ever expire
+            Err(_) => return Vec::new(),
+        };
+        let expired: Vec<TxId> = self
+            .by_hash
+            .values()
+            .filter(|info| now - info.submitted_time() > ttl)
+            .map(|info| info.id())
+            .collect();
+        expired
+            .iter()
+            .flat_map(|tx_id| self.remove_by_tx_id(tx_id))
+            .collect()
+    }
+
+    /// Evict transactions included in the block and transactions that conflict with it.
+    /// Transactions that depend on included transactions are inserted again, so they
+    /// spend the outputs of the block from the database, the rest of the pool is left
+    /// untouched. Returns the status of every transaction that left the pool.
+    pub async fn block_update_inner(
+        &mut self,
+        block: &FuelBlock,
+        db: &dyn TxPoolDb,
+    ) -> Vec<TxStatusBroadcast> {
+        let spends = BlockSpends::new(block);
+        let mut statuses = Vec::new();
+
+        // conflicting transactions are removed together with all of their dependents
+        let conflicting: Vec<(TxId, Error)> = self
+            .by_hash
+            .values()
+            .filter(|info| !spends.included.contains(&info.id()))
+            .filter_map(|info| spends.conflict(info.tx()).map(|err| (info.id(), err)))
+            .collect();
+        for (tx_id, reason) in conflicting {
+            for removed in self.remove_by_tx_id(&tx_id) {
+                let reason = if removed.id() == tx_id {
+                    reason.clone()
+                } else {
+                    Error::Removed
+                };
+                statuses.push(TxStatusBroadcast {
+                    tx: removed,
+                    status: TxStatus::SqueezedOut { reason },
+                });
+            }
+        }
+
+        // take out included transactions with their dependents, the outputs they
+        // depend on are now in the database
+        let mut affected = HashSet::new();
+        for tx_id in spends.included.iter() {
+            if let Some(info) = self.by_hash.get(tx_id) {
+                affected.insert(*tx_id);
+                self.by_dependency.find_descendants(
+                    info.tx(),
+                    &mut affected,
+                    &self.by_hash,
+                );
+            }
+        }
+        let infos: HashMap<TxId, TxInfo> = affected
+            .iter()
+            .map(|tx_id| (*tx_id, self.by_hash[tx_id].clone()))
+            .collect();
+        for tx_id in infos.keys() {
+            self.remove_by_tx_id(tx_id);
+        }
+        for tx in parents_first(infos.values().map(|info| info.tx().clone()).collect()) {
+            if spends.included.contains(&tx.id()) {
+                statuses.push(TxStatusBroadcast {
+                    tx,
+                    status: TxStatus::Executed,
+                });
+                continue
+            }
+            match self.insert_info(infos[&tx.id()].clone(), db).await {
+                Ok(removed) => {
+                    for removed in removed {
+                        statuses.push(TxStatusBroadcast {
+                            tx: removed,
+                            status: TxStatus::SqueezedOut {
+                                reason: Error::Removed,
+                            },
+                        });
+                    }
+                }
+                Err(err) => {
+                    let reason = err.downcast::<Error>().unwrap_or(Error::Removed);
+                    statuses.push(TxStatusBroadcast {
+                        tx,
+                        status: TxStatus::SqueezedOut { reason },
+                    });
+                }
+            }
+        }
+        statuses
+    }
+
+    /// Gas price for a new transaction. While the pool is less than half full the minimum
+    /// gas price is enough, after that the transaction should match the cheapest one, and
+    /// once the pool is full it has to outbid the cheapest one to push it out.
+    pub fn suggested_gas_price(&self) -> GasPrice {
+        let len = self.by_hash.len();
+        let price = if len >= self.config.max_tx {
+            self.by_gas_price.lowest_price().saturating_add(1)
+        } else if len * 2 >= self.config.max_tx {
+            self.by_gas_price.lowest_price()
+        } else {
+            0
+        };
+        price.max(self.config.min_gas_price)
+    }
+
     fn verify_tx_min_gas_price(&mut self, tx: &Transaction) -> Result<(), Error> {
         if tx.gas_price() < self.config.min_gas_price {
             return Err(Error::NotInsertedGasPriceTooLow)
@@ -143,6 +441,47 @@ impl TxPool {
         Ok(())
     }
 
+    /// Validate a transaction without the pool or the database, before taking the write lock.
+    pub fn check_tx(config: &Config, tx: &Transaction) -> Result<(), Error> {
+        if tx.gas_price() < config.min_gas_price {
+            return Err(Error::NotInsertedGasPriceTooLow)
+        }
+        let params = &config.consensus_params;
+        if tx.gas_limit() > params.max_gas_per_tx {
+            return Err(Error::NotInsertedMaxGasLimit(tx.gas_limit()))
+        }
+        let size = tx.serialized_size();
+        if size > config.max_tx_size {
+            return Err(Error::NotInsertedMaxSize(size))
+        }
+
+        // maturity depends on the block that includes the transaction, so it is left
+        // to the executor
+        let checked_tx =
+            CheckedTransaction::check_unsigned(tx.clone(), Word::MAX, params)
+                .map_err(Error::NotInsertedInvalid)?;
+        if config.utxo_validation {
+            tx.validate_input_signature()
+                .map_err(Error::NotInsertedInvalidSignature)?;
+        }
+
+        let has_predicate = tx.inputs().iter().any(|input| {
+            matches!(
+                input,
+                Input::CoinPredicate { .. } | Input::MessagePredicate { .. }
+            )
+        });
+        if has_predicate {
+            if !config.predicates {
+                return Err(Error::NotInsertedPredicateExecutionDisabled)
+            }
+            if !Interpreter::<PredicateStorage>::check_predicates(checked_tx, *params) {
+                return Err(Error::NotInsertedInvalidPredicate)
+            }
+        }
+        Ok(())
+    }
+
     /// Import a set of transactions from network gossip or GraphQL endpoints.
     pub async fn insert(
         txpool: &RwLock<Self>,
@@ -150,12 +489,18 @@ impl TxPool {
         broadcast: broadcast::Sender<TxStatusBroadcast>,
         txs: Vec<ArcTx>,
     ) -> Vec<anyhow::Result<Vec<ArcTx>>> {
-        // Check if that data is okay (witness match input/output, and if recovered signatures ara valid).
-        // should be done before transaction comes to txpool, or before it enters RwLocked region.
+        let config = txpool.read().await.config.clone();
         let mut res = Vec::new();
         for tx in txs.iter() {
-            let mut pool = txpool.write().await;
-            res.push(pool.insert_inner(tx.clone(), db).await)
+            // stateless checks don't need the pool, so they run before taking the lock
+            let result = match Self::check_tx(&config, tx) {
+                Ok(()) => {
+                    let mut pool = txpool.write().await;
+                    pool.insert_inner(tx.clone(), db).await
+                }
+                Err(err) => Err(err.into()),
+            };
+            res.push(result);
         }
         // announce to subscribers
         for (ret, tx) in res.iter().zip(txs.into_iter()) {
@@ -196,6 +541,10 @@ impl TxPool {
         txpool.read().await.txs().get(hash).cloned()
     }
 
+    pub async fn gas_price(txpool: &RwLock<Self>) -> GasPrice {
+        txpool.read().await.suggested_gas_price()
+    }
+
     /// find all dependent tx and return them with requested dependencies in one list sorted by Price.
     pub async fn find_dependent(txpool: &RwLock<Self>, hashes: &[TxId]) -> Vec<ArcTx> {
         let mut seen = HashMap::new();
@@ -230,19 +579,45 @@ impl TxPool {
         res
     }
 
-    /// Return all sorted transactions that are includable in next block.
+    /// Return sorted transactions that are includable in next block within given limits.
     /// This is going to be heavy operation, use it only when needed.
-    pub async fn includable(txpool: &RwLock<Self>) -> Vec<ArcTx> {
+    pub async fn includable(
+        txpool: &RwLock<Self>,
+        max_gas: Word,
+        max_size: usize,
+    ) -> Vec<ArcTx> {
         let pool = txpool.read().await;
-        pool.sorted_includable()
+        pool.sorted_includable_within(max_gas, max_size)
     }
 
-    /// When block is updated we need to receive all spend outputs and remove them from txpool.
+    /// When block is imported we need to remove its transactions from txpool together
+    /// with all transactions that can't be included anymore.
     pub async fn block_update(
-        txpool: &RwLock<Self>, // spend_outputs: [Input], added_outputs: [AddedOutputs]
+        txpool: &RwLock<Self>,
+        db: &dyn TxPoolDb,
+        broadcast: broadcast::Sender<TxStatusBroadcast>,
+        block: &FuelBlock,
+    ) {
+        let statuses = txpool.write().await.block_update_inner(block, db).await;
+        for status in statuses {
+            let _ = broadcast.send(status);
+        }
+    }
+
+    /// Evict expired transactions and their dependents.
+    pub async fn prune_old_txs(
+        txpool: &RwLock<Self>,
+        broadcast: broadcast::Sender<TxStatusBroadcast>,
     ) {
-        txpool.write().await;
-        // TODO https://github.com/FuelLabs/fuel-core/issues/465
+        let removed = txpool.write().await.prune_old_txs_inner(Utc::now());
+        for tx in removed {
+            let _ = broadcast.send(TxStatusBroadcast {
+                tx,
+                status: TxStatus::SqueezedOut {
+                    reason: Error::TTLReason,
+                },
+            });
+        }
     }
 
     /// remove transaction from pool needed on user demand. Low priority
@@ -268,6 +643,124 @@ impl TxPool {
     }
 }
 
+/// Inputs spent and contracts created by the transactions of an imported block.
+struct BlockSpends {
+    included: HashSet<TxId>,
+    coins: HashSet<UtxoId>,
+    messages: HashSet<MessageId>,
+    contracts: HashSet<ContractId>,
+}
+
+impl BlockSpends {
+    fn new(block: &FuelBlock) -> Self {
+        let mut spends = Self {
+            included: HashSet::new(),
+            coins: HashSet::new(),
+            messages: HashSet::new(),
+            contracts: HashSet::new(),
+        };
+        for tx in block.transactions.iter() {
+            spends.included.insert(tx.id());
+            for input in tx.inputs() {
+                match input {
+                    Input::CoinSigned { utxo_id, .. }
+                    | Input::CoinPredicate { utxo_id, .. } => {
+                        spends.coins.insert(*utxo_id);
+                    }
+                    Input::MessageSigned { message_id, .. }
+                    | Input::MessagePredicate { message_id, .. } => {
+                        spends.messages.insert(*message_id);
+                    }
+                    Input::Contract { .. } => {}
+                }
+            }
+            for output in tx.outputs() {
+                if let Output::ContractCreated { contract_id, .. } = output {
+                    spends.contracts.insert(*contract_id);
+                }
+            }
+        }
+        spends
+    }
+
+    /// Reason why the pool transaction can't be included after the block, if any.
+    fn conflict(&self, tx: &Transaction) -> Option<Error> {
+        for input in tx.inputs() {
+            match input {
+                Input::CoinSigned { utxo_id, .. }
+                | Input::CoinPredicate { utxo_id, .. }
+                    if self.coins.contains(utxo_id) =>
+                {
+                    return Some(Error::RemovedCoinSpentByBlock(*utxo_id))
+                }
+                Input::MessageSigned { message_id, .. }
+                | Input::MessagePredicate { message_id, .. }
+                    if self.messages.contains(message_id) =>
+                {
+                    return Some(Error::RemovedMessageSpentByBlock(*message_id))
+                }
+                _ => {}
+            }
+        }
+        tx.outputs().iter().find_map(|output| match output {
+            Output::ContractCreated { contract_id, .. }
+                if self.contracts.contains(contract_id) =>
+            {
+                Some(Error::RemovedContractCreatedByBlock(*contract_id))
+            }
+            _ => None,
+        })
+    }
+}
+
+/// Order transactions so that each one comes after the transactions it depends on.
+fn parents_first(txs: Vec<ArcTx>) -> Vec<ArcTx> {
+    let creators: HashMap<ContractId, TxId> = txs
+        .iter()
+        .flat_map(|tx| {
+            tx.outputs().iter().filter_map(|output| match output {
+                Output::ContractCreated { contract_id, .. } => {
+                    Some((*contract_id, tx.id()))
+                }
+                _ => None,
+            })
+        })
+        .collect();
+    let txs: HashMap<TxId, ArcTx> = txs.into_iter().map(|tx| (tx.id(), tx)).collect();
+
+    fn visit(
+        tx_id: TxId,
+        txs: &HashMap<TxId, ArcTx>,
+        creators: &HashMap<ContractId, TxId>,
+        visited: &mut HashSet<TxId>,
+        ordered: &mut Vec<ArcTx>,
+    ) {
+        if !visited.insert(tx_id) {
+            return
+        }
+        let tx = &txs[&tx_id];
+        for input in tx.inputs() {
+            let parent = match input {
+                Input::CoinSigned { utxo_id, .. }
+                | Input::CoinPredicate { utxo_id, .. } => Some(*utxo_id.tx_id()),
+                Input::Contract { contract_id, .. } => creators.get(contract_id).copied(),
+                Input::MessageSigned { .. } | Input::MessagePredicate { .. } => None,
+            };
+            if let Some(parent) = parent.filter(|parent| txs.contains_key(parent)) {
+                visit(parent, txs, creators, visited, ordered);
+            }
+        }
+        ordered.push(tx.clone());
+    }
+
+    let mut visited = HashSet::new();
+    let mut ordered = Vec::with_capacity(txs.len());
+    for tx_id in txs.keys() {
+        visit(*tx_id, &txs, &creators, &mut visited, &mut ordered);
+    }
+    ordered
+}
+
 #[cfg(test)]
 pub mod tests {
     use crate::MockDb;
@@ -348,11 +841,16 @@ pub mod tests {
     };
     use fuel_core_interfaces::{
         common::{
+            fuel_asm::Opcode,
             fuel_storage::Storage,
             fuel_tx::{
+                Address,
+                ConsensusParameters,
                 TransactionBuilder,
                 UtxoId,
+                ValidationError,
             },
+            fuel_vm::consts::REG_ZERO,
         },
         model::{
             BlockHeight,
@@ -568,6 +1066,31 @@ pub mod tests {
         ));
     }
 
+    #[tokio::test]
+    async fn tx_with_missing_utxo_inserted_when_utxo_validation_disabled() {
+        let mut txpool = TxPool::new(Config {
+            utxo_validation: false,
+            ..Default::default()
+        });
+        let db = MockDb::default();
+
+        let nonexistent_id = TxId::from_str(
+            "0x0000000000000000000000000000000000000000000000000000000000000011",
+        )
+        .unwrap();
+        let tx = Arc::new(
+            TransactionBuilder::script(vec![], vec![])
+                .add_input(create_coin_input(nonexistent_id, 0))
+                .add_input(create_contract_input(nonexistent_id, 1))
+                .finalize(),
+        );
+
+        txpool
+            .insert_inner(tx, &db)
+            .await
+            .expect("Tx should be Ok, got Err");
+    }
+
     #[tokio::test]
     async fn tx_try_to_use_spent_coin() {
         let mut txpool = TxPool::new(Default::default());
@@ -653,6 +1176,124 @@ pub mod tests {
         assert_eq!(vec[0].id(), tx1.id(), "Tx1 id should be removed");
     }
 
+    #[tokio::test]
+    async fn replacement_below_minimum_bump_not_inserted() {
+        let mut txpool = TxPool::new(Config {
+            utxo_validation: false,
+            ..Default::default()
+        });
+        let db = MockDb::default();
+
+        let tx_spending_coin = |gas_price| {
+            Arc::new(
+                TransactionBuilder::script(vec![], vec![])
+                    .gas_price(gas_price)
+                    .add_input(create_coin_input(TxId::zeroed(), 0))
+                    .finalize(),
+            )
+        };
+        let tx1 = tx_spending_coin(100);
+        txpool
+            .insert_inner(tx1.clone(), &db)
+            .await
+            .expect("Tx1 should be Ok, got Err");
+
+        // the default bump is 10%
+        let err = txpool
+            .insert_inner(tx_spending_coin(109), &db)
+            .await
+            .expect_err("Tx2 should be Err, got Ok");
+        assert!(matches!(
+            err.downcast_ref::<Error>(),
+            Some(Error::NotInsertedReplacementUnderpriced(id)) if id == &tx1.id()
+        ));
+
+        let removed = txpool
+            .insert_inner(tx_spending_coin(110), &db)
+            .await
+            .expect("Tx3 should be Ok, got Err");
+        assert_eq!(removed[0].id(), tx1.id(), "Tx1 id should be removed");
+    }
+
+    #[tokio::test]
+    async fn replacement_with_lower_total_fee_not_inserted() {
+        let mut txpool = TxPool::new(Config {
+            utxo_validation: false,
+            ..Default::default()
+        });
+        let db = MockDb::default();
+
+        let tx1 = Arc::new(
+            TransactionBuilder::script(vec![], vec![])
+                .gas_price(10)
+                .gas_limit(1000)
+                .add_input(create_coin_input(TxId::zeroed(), 0))
+                .finalize(),
+        );
+        let tx2 = Arc::new(
+            TransactionBuilder::script(vec![], vec![])
+                .gas_price(20)
+                .gas_limit(100)
+                .add_input(create_coin_input(TxId::zeroed(), 0))
+                .finalize(),
+        );
+        txpool
+            .insert_inner(tx1.clone(), &db)
+            .await
+            .expect("Tx1 should be Ok, got Err");
+
+        let err = txpool
+            .insert_inner(tx2, &db)
+            .await
+            .expect_err("Tx2 should be Err, got Ok");
+        assert!(matches!(
+            err.downcast_ref::<Error>(),
+            Some(Error::NotInsertedReplacementUnderpriced(id)) if id == &tx1.id()
+        ));
+    }
+
+    #[tokio::test]
+    async fn contract_id_replacement_below_minimum_bump_not_inserted() {
+        let mut txpool = TxPool::new(Config {
+            replacement_bump_percent: 50,
+            utxo_validation: false,
+            ..Default::default()
+        });
+        let db = MockDb::default();
+        let contract_id = ContractId::from([1u8; 32]);
+
+        // the transactions spend different coins, they only collide on the contract
+        let tx_creating_contract = |gas_price| {
+            Arc::new(
+                TransactionBuilder::script(vec![], vec![])
+                    .gas_price(gas_price)
+                    .add_input(create_coin_input([gas_price as u8; 32].into(), 0))
+                    .add_output(create_contract_output(contract_id))
+                    .finalize(),
+            )
+        };
+        let tx1 = tx_creating_contract(10);
+        txpool
+            .insert_inner(tx1.clone(), &db)
+            .await
+            .expect("Tx1 should be Ok, got Err");
+
+        let err = txpool
+            .insert_inner(tx_creating_contract(14), &db)
+            .await
+            .expect_err("Tx2 should be Err, got Ok");
+        assert!(matches!(
+            err.downcast_ref::<Error>(),
+            Some(Error::NotInsertedReplacementUnderpriced(id)) if id == &tx1.id()
+        ));
+
+        let removed = txpool
+            .insert_inner(tx_creating_contract(15), &db)
+            .await
+            .expect("Tx3 should be Ok, got Err");
+        assert_eq!(removed[0].id(), tx1.id(), "Tx1 id should be removed");
+    }
+
     #[tokio::test]
     async fn underpriced_tx1_not_included_coin_collision() {
         let mut txpool = TxPool::new(Default::default());
@@ -858,20 +1499,179 @@ pub mod tests {
         ));
     }
 
+    fn tx_spending_coin_of(owner: Address, utxo_id: UtxoId) -> ArcTx {
+        Arc::new(
+            TransactionBuilder::script(vec![], vec![])
+                .gas_price(10)
+                .add_input(Input::coin_signed(
+                    utxo_id,
+                    owner,
+                    Default::default(),
+                    Default::default(),
+                    Default::default(),
+                    0,
+                    0,
+                ))
+                .finalize(),
+        )
+    }
+
     #[tokio::test]
-    async fn tx_depth_hit() {
+    async fn owner_at_tx_limit_can_replace_own_tx() {
         let mut txpool = TxPool::new(Config {
-            max_depth: 1,
+            max_tx_per_owner: 1,
+            utxo_validation: false,
             ..Default::default()
         });
         let db = MockDb::default();
+        let owner = Address::from([1u8; 32]);
+        let utxo_id = UtxoId::new([1u8; 32].into(), 0);
 
-        let tx1 = Arc::new(
+        let tx1 = tx_spending_coin_of(owner, utxo_id);
+        let tx2 = Arc::new(
             TransactionBuilder::script(vec![], vec![])
-                .add_output(create_coin_output())
+                .gas_price(20)
+                .add_input(Input::coin_signed(
+                    utxo_id,
+                    owner,
+                    Default::default(),
+                    Default::default(),
+                    Default::default(),
+                    0,
+                    0,
+                ))
                 .finalize(),
         );
-        let tx2 = Arc::new(
+
+        txpool
+            .insert_inner(tx1.clone(), &db)
+            .await
+            .expect("Tx1 should be OK, got Err");
+        let removed = txpool
+            .insert_inner(tx2.clone(), &db)
+            .await
+            .expect("Tx2 replacing Tx1 should be OK, got Err");
+
+        assert_eq!(removed, vec![tx1]);
+        assert_eq!(txpool.txs().len(), 1);
+        assert!(txpool.txs().contains_key(&tx2.id()));
+    }
+
+    #[tokio::test]
+    async fn owner_tx_limit_hit() {
+        let mut txpool = TxPool::new(Config {
+            max_tx_per_owner: 1,
+            utxo_validation: false,
+            ..Default::default()
+        });
+        let db = MockDb::default();
+        let owner = Address::from([1u8; 32]);
+
+        let tx1 = tx_spending_coin_of(owner, UtxoId::new([1u8; 32].into(), 0));
+        let tx2 = tx_spending_coin_of(owner, UtxoId::new([2u8; 32].into(), 0));
+        let tx3 = tx_spending_coin_of(
+            Address::from([2u8; 32]),
+            UtxoId::new([3u8; 32].into(), 0),
+        );
+
+        txpool
+            .insert_inner(tx1.clone(), &db)
+            .await
+            .expect("Tx1 should be OK, got Err");
+        let err = txpool
+            .insert_inner(tx2.clone(), &db)
+            .await
+            .expect_err("Tx2 should be Err, got Ok");
+        assert!(matches!(
+            err.downcast_ref::<Error>(),
+            Some(Error::NotInsertedOwnerLimitHit(o)) if o == &owner
+        ));
+        txpool
+            .insert_inner(tx3, &db)
+            .await
+            .expect("Tx3 of another owner should be OK, got Err");
+
+        // the quota is freed once the transaction leaves the pool
+        txpool.remove_by_tx_id(&tx1.id());
+        txpool
+            .insert_inner(tx2, &db)
+            .await
+            .expect("Tx2 should be OK, got Err");
+    }
+
+    #[tokio::test]
+    async fn owner_size_limit_hit() {
+        let owner = Address::from([1u8; 32]);
+        let tx1 = tx_spending_coin_of(owner, UtxoId::new([1u8; 32].into(), 0));
+        let tx2 = tx_spending_coin_of(owner, UtxoId::new([2u8; 32].into(), 0));
+        let mut txpool = TxPool::new(Config {
+            max_bytes_per_owner: tx1.serialized_size() + tx2.serialized_size() - 1,
+            utxo_validation: false,
+            ..Default::default()
+        });
+        let db = MockDb::default();
+
+        txpool
+            .insert_inner(tx1, &db)
+            .await
+            .expect("Tx1 should be OK, got Err");
+        let err = txpool
+            .insert_inner(tx2, &db)
+            .await
+            .expect_err("Tx2 should be Err, got Ok");
+        assert!(matches!(
+            err.downcast_ref::<Error>(),
+            Some(Error::NotInsertedOwnerLimitHit(o)) if o == &owner
+        ));
+    }
+
+    #[tokio::test]
+    async fn suggested_gas_price_follows_fill_level() {
+        let mut txpool = TxPool::new(Config {
+            max_tx: 4,
+            min_gas_price: 2,
+            ..Default::default()
+        });
+        let db = MockDb::default();
+
+        assert_eq!(txpool.suggested_gas_price(), 2);
+        for (i, gas_price) in [10, 5, 20, 30].into_iter().enumerate() {
+            let tx = Arc::new(
+                TransactionBuilder::script(vec![], vec![])
+                    .gas_price(gas_price)
+                    .add_output(create_coin_output())
+                    .finalize(),
+            );
+            txpool
+                .insert_inner(tx, &db)
+                .await
+                .expect("Tx should be Ok, got Err");
+            let expected = match i {
+                // less than half full
+                0 => 2,
+                // at least half full, the cheapest tx pays 5
+                1 | 2 => 5,
+                // full, has to outbid the cheapest tx
+                _ => 6,
+            };
+            assert_eq!(txpool.suggested_gas_price(), expected);
+        }
+    }
+
+    #[tokio::test]
+    async fn tx_depth_hit() {
+        let mut txpool = TxPool::new(Config {
+            max_depth: 1,
+            ..Default::default()
+        });
+        let db = MockDb::default();
+
+        let tx1 = Arc::new(
+            TransactionBuilder::script(vec![], vec![])
+                .add_output(create_coin_output())
+                .finalize(),
+        );
+        let tx2 = Arc::new(
             TransactionBuilder::script(vec![], vec![])
                 .add_input(create_coin_input(tx1.id(), 0))
                 .add_output(create_coin_output())
@@ -944,6 +1744,133 @@ pub mod tests {
         assert_eq!(txs[2].id(), tx2.id(), "Third should be tx2");
     }
 
+    #[tokio::test]
+    async fn tx1_over_gas_limit_skipped_with_dependent_tx2() {
+        let mut txpool = TxPool::new(Default::default());
+        let db = MockDb::default();
+
+        let tx1 = Arc::new(
+            TransactionBuilder::script(vec![], vec![])
+                .gas_price(10)
+                .gas_limit(2000)
+                .add_output(create_coin_output())
+                .finalize(),
+        );
+        let tx2 = Arc::new(
+            TransactionBuilder::script(vec![], vec![])
+                .gas_price(9)
+                .gas_limit(100)
+                .add_input(create_coin_input(tx1.id(), 0))
+                .finalize(),
+        );
+        let tx3 = Arc::new(
+            TransactionBuilder::script(vec![], vec![])
+                .gas_price(5)
+                .gas_limit(600)
+                .finalize(),
+        );
+        let tx4 = Arc::new(
+            TransactionBuilder::script(vec![], vec![])
+                .gas_price(1)
+                .gas_limit(600)
+                .finalize(),
+        );
+
+        for tx in [&tx1, &tx2, &tx3, &tx4] {
+            txpool
+                .insert_inner(tx.clone(), &db)
+                .await
+                .expect("Tx should be Ok, got Err");
+        }
+
+        let txs = txpool.sorted_includable_within(1000, usize::MAX);
+
+        assert_eq!(txs.len(), 1, "Should have 1 tx");
+        assert_eq!(txs[0].id(), tx3.id(), "Only tx3 should fit");
+    }
+
+    #[tokio::test]
+    async fn higher_priced_tx2_included_after_dependency_tx1() {
+        let mut txpool = TxPool::new(Default::default());
+        let db = MockDb::default();
+
+        let tx1 = Arc::new(
+            TransactionBuilder::script(vec![], vec![])
+                .gas_price(5)
+                .gas_limit(100)
+                .add_output(create_coin_output())
+                .finalize(),
+        );
+        let tx2 = Arc::new(
+            TransactionBuilder::script(vec![], vec![])
+                .gas_price(20)
+                .gas_limit(100)
+                .add_input(create_coin_input(tx1.id(), 0))
+                .finalize(),
+        );
+        let tx3 = Arc::new(
+            TransactionBuilder::script(vec![], vec![])
+                .gas_price(10)
+                .gas_limit(100)
+                .finalize(),
+        );
+
+        for tx in [&tx1, &tx2, &tx3] {
+            txpool
+                .insert_inner(tx.clone(), &db)
+                .await
+                .expect("Tx should be Ok, got Err");
+        }
+
+        let txs = txpool.sorted_includable_within(Word::MAX, usize::MAX);
+        let ids: Vec<_> = txs.iter().map(|tx| tx.id()).collect();
+        assert_eq!(
+            ids,
+            vec![tx3.id(), tx1.id(), tx2.id()],
+            "tx2 should follow tx1"
+        );
+
+        let txs = txpool.sorted_includable_within(200, usize::MAX);
+        let ids: Vec<_> = txs.iter().map(|tx| tx.id()).collect();
+        assert_eq!(ids, vec![tx3.id(), tx1.id()], "tx2 doesn't fit after tx1");
+
+        // tx1 doesn't fit after tx3, so its child tx2 is skipped as well
+        let txs = txpool.sorted_includable_within(100, usize::MAX);
+        let ids: Vec<_> = txs.iter().map(|tx| tx.id()).collect();
+        assert_eq!(ids, vec![tx3.id()], "tx2 must not be included without tx1");
+    }
+
+    #[tokio::test]
+    async fn tx1_over_size_limit_skipped() {
+        let mut txpool = TxPool::new(Default::default());
+        let db = MockDb::default();
+
+        let tx1 = Arc::new(
+            TransactionBuilder::script(vec![], vec![0; 1024])
+                .gas_price(10)
+                .finalize(),
+        );
+        let tx2 = Arc::new(
+            TransactionBuilder::script(vec![], vec![])
+                .gas_price(5)
+                .finalize(),
+        );
+
+        txpool
+            .insert_inner(tx1.clone(), &db)
+            .await
+            .expect("Tx1 should be Ok, got Err");
+        txpool
+            .insert_inner(tx2.clone(), &db)
+            .await
+            .expect("Tx2 should be Ok, got Err");
+
+        let txs = txpool.sorted_includable_within(Word::MAX, tx2.serialized_size());
+
+        assert_eq!(txs.len(), 1, "Should have 1 tx");
+        assert_eq!(txs[0].id(), tx2.id(), "Only tx2 should fit");
+    }
+
     #[tokio::test]
     async fn find_dependent_tx1_tx2() {
         let mut txpool = TxPool::new(Default::default());
@@ -995,6 +1922,64 @@ pub mod tests {
         assert_eq!(list[1].id(), tx3.id(), "Tx3 should be second.");
     }
 
+    #[tokio::test]
+    async fn expired_tx1_evicted_with_dependent_tx2() {
+        let mut txpool = TxPool::new(Config {
+            transaction_ttl: std::time::Duration::from_secs(1),
+            ..Default::default()
+        });
+        let db = MockDb::default();
+
+        let tx1 = Arc::new(
+            TransactionBuilder::script(vec![], vec![])
+                .gas_price(10)
+                .add_output(create_coin_output())
+                .finalize(),
+        );
+        let tx2 = Arc::new(
+            TransactionBuilder::script(vec![], vec![])
+                .gas_price(10)
+                .add_input(create_coin_input(tx1.id(), 0))
+                .finalize(),
+        );
+        let tx3 = Arc::new(
+            TransactionBuilder::script(vec![], vec![])
+                .gas_price(9)
+                .finalize(),
+        );
+
+        txpool
+            .insert_inner(tx1.clone(), &db)
+            .await
+            .expect("Tx1 should be Ok, got Err");
+        std::thread::sleep(std::time::Duration::from_millis(10));
+        txpool
+            .insert_inner(tx2.clone(), &db)
+            .await
+            .expect("Tx2 should be Ok, got Err");
+        txpool
+            .insert_inner(tx3.clone(), &db)
+            .await
+            .expect("Tx3 should be Ok, got Err");
+
+        // only tx1 is older than the ttl
+        let now = txpool.txs()[&tx2.id()].submitted_time() + chrono::Duration::seconds(1);
+        let mut removed: Vec<TxId> = txpool
+            .prune_old_txs_inner(now)
+            .iter()
+            .map(|tx| tx.id())
+            .collect();
+        removed.sort();
+        let mut expected = vec![tx1.id(), tx2.id()];
+        expected.sort();
+        assert_eq!(
+            removed, expected,
+            "Tx1 and its dependent Tx2 should be evicted"
+        );
+        assert_eq!(txpool.txs().len(), 1);
+        assert!(txpool.txs().contains_key(&tx3.id()));
+    }
+
     #[tokio::test]
     async fn tx_at_least_min_gas_price_is_insertable() {
         let mut txpool = TxPool::new(Config {
@@ -1033,6 +2018,104 @@ pub mod tests {
         ));
     }
 
+    #[test]
+    fn tx_over_gas_and_size_limits_is_not_insertable() {
+        let config = Config {
+            consensus_params: ConsensusParameters::DEFAULT.with_max_gas_per_tx(100),
+            max_tx_size: 100,
+            ..Default::default()
+        };
+        let tx = TransactionBuilder::script(vec![], vec![])
+            .gas_limit(101)
+            .finalize();
+        assert_eq!(
+            TxPool::check_tx(&config, &tx),
+            Err(Error::NotInsertedMaxGasLimit(101))
+        );
+
+        let tx = TransactionBuilder::script(vec![], vec![0; 100])
+            .gas_limit(100)
+            .finalize();
+        assert!(matches!(
+            TxPool::check_tx(&config, &tx),
+            Err(Error::NotInsertedMaxSize(size)) if size == tx.serialized_size()
+        ));
+    }
+
+    #[test]
+    fn tx_with_missing_witness_is_not_insertable() {
+        let tx = TransactionBuilder::script(vec![], vec![])
+            .add_input(create_coin_input(TxId::zeroed(), 0))
+            .finalize_without_signature();
+        assert_eq!(
+            TxPool::check_tx(&Default::default(), &tx),
+            Err(Error::NotInsertedInvalid(
+                ValidationError::InputWitnessIndexBounds { index: 0 }
+            ))
+        );
+    }
+
+    #[tokio::test]
+    async fn tx_with_invalid_signature_is_not_inserted() {
+        let txpool = RwLock::new(TxPool::new(Default::default()));
+        let db = MockDb::default();
+        let (broadcast, _) = broadcast::channel(10);
+        let tx = TransactionBuilder::script(vec![], vec![])
+            .add_input(create_coin_input(TxId::zeroed(), 0))
+            .add_witness(vec![0; 64].into())
+            .finalize_without_signature();
+
+        let result = TxPool::insert(&txpool, &db, broadcast, vec![Arc::new(tx.clone())])
+            .await
+            .pop()
+            .unwrap();
+        assert!(matches!(
+            result
+                .expect_err("expected insertion failure")
+                .downcast_ref::<Error>(),
+            Some(Error::NotInsertedInvalidSignature(_))
+        ));
+        assert!(txpool.read().await.txs().is_empty());
+
+        // signatures are checked together with the inputs
+        let config = Config {
+            utxo_validation: false,
+            ..Default::default()
+        };
+        assert_eq!(TxPool::check_tx(&config, &tx), Ok(()));
+    }
+
+    #[test]
+    fn tx_with_predicate_checked_only_when_predicates_enabled() {
+        // returns false
+        let predicate: Vec<u8> = vec![Opcode::RET(REG_ZERO)].into_iter().collect();
+        let tx = TransactionBuilder::script(vec![], vec![])
+            .add_input(Input::coin_predicate(
+                UtxoId::new(TxId::zeroed(), 0),
+                Input::predicate_owner(&predicate),
+                0,
+                Default::default(),
+                Default::default(),
+                0,
+                predicate,
+                vec![],
+            ))
+            .finalize_without_signature();
+
+        assert_eq!(
+            TxPool::check_tx(&Default::default(), &tx),
+            Err(Error::NotInsertedPredicateExecutionDisabled)
+        );
+        let config = Config {
+            predicates: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            TxPool::check_tx(&config, &tx),
+            Err(Error::NotInsertedInvalidPredicate)
+        );
+    }
+
     #[tokio::test]
     async fn tx_inserted_into_pool_when_input_message_id_exists_in_db() {
         let message = Message {
@@ -1211,8 +2294,9 @@ pub mod tests {
     #[tokio::test]
     async fn message_of_squeezed_out_tx_can_be_resubmitted_at_lower_gas_price() {
         // tx1 (message 1, message 2) gas_price 2
-        // tx2 (message 1) gas_price 3
-        //   squeezes tx1 with higher gas price
+        // tx2 (message 1) gas_price 6
+        //   squeezes tx1 with higher gas price, and a higher total fee even though it is
+        //   smaller
         // tx3 (message 2) gas_price 1
         //   works since tx1 is no longer part of txpool state even though gas price is less
 
@@ -1236,7 +2320,7 @@ pub mod tests {
             .finalize();
 
         let tx_2 = TransactionBuilder::script(vec![], vec![])
-            .gas_price(3)
+            .gas_price(6)
             .add_input(message_input_1.clone())
             .finalize();
 
@@ -1265,4 +2349,251 @@ pub mod tests {
             .await
             .expect("should succeed");
     }
+
+    fn block_with(transactions: Vec<Transaction>) -> FuelBlock {
+        FuelBlock {
+            header: Default::default(),
+            transactions,
+        }
+    }
+
+    fn unspent_coin() -> Coin {
+        Coin {
+            owner: Default::default(),
+            amount: Default::default(),
+            asset_id: Default::default(),
+            maturity: Default::default(),
+            status: CoinStatus::Unspent,
+            block_created: BlockHeight::default(),
+        }
+    }
+
+    #[tokio::test]
+    async fn included_tx1_evicted_and_dependent_tx2_kept() {
+        let mut txpool = TxPool::new(Default::default());
+        let mut db = MockDb::default();
+
+        let tx1 = Arc::new(
+            TransactionBuilder::script(vec![], vec![])
+                .gas_price(10)
+                .add_output(create_coin_output())
+                .finalize(),
+        );
+        let tx2 = Arc::new(
+            TransactionBuilder::script(vec![], vec![])
+                .gas_price(9)
+                .add_input(create_coin_input(tx1.id(), 0))
+                .finalize(),
+        );
+        txpool
+            .insert_inner(tx1.clone(), &db)
+            .await
+            .expect("Tx1 should be Ok, got Err");
+        txpool
+            .insert_inner(tx2.clone(), &db)
+            .await
+            .expect("Tx2 should be Ok, got Err");
+
+        // output of tx1 is committed together with the block
+        db.insert(&UtxoId::new(tx1.id(), 0), &unspent_coin())
+            .unwrap();
+        let statuses = txpool
+            .block_update_inner(&block_with(vec![tx1.as_ref().clone()]), &db)
+            .await;
+
+        assert_eq!(
+            statuses,
+            vec![TxStatusBroadcast {
+                tx: tx1.clone(),
+                status: TxStatus::Executed,
+            }]
+        );
+        assert!(!txpool.txs().contains_key(&tx1.id()));
+        assert!(txpool.txs().contains_key(&tx2.id()));
+        // tx2 now spends a coin from the database
+        let mut seen = HashMap::new();
+        txpool
+            .dependency()
+            .find_dependent(tx2.clone(), &mut seen, txpool.txs());
+        assert_eq!(seen.len(), 1, "Tx2 should not have parents in txpool");
+    }
+
+    #[tokio::test]
+    async fn dependent_tx2_keeps_ttl_across_block_import() {
+        let mut txpool = TxPool::new(Config {
+            transaction_ttl: std::time::Duration::from_secs(1),
+            ..Default::default()
+        });
+        let mut db = MockDb::default();
+
+        let tx1 = Arc::new(
+            TransactionBuilder::script(vec![], vec![])
+                .gas_price(10)
+                .add_output(create_coin_output())
+                .finalize(),
+        );
+        let tx2 = Arc::new(
+            TransactionBuilder::script(vec![], vec![])
+                .gas_price(9)
+                .add_input(create_coin_input(tx1.id(), 0))
+                .finalize(),
+        );
+        txpool
+            .insert_inner(tx1.clone(), &db)
+            .await
+            .expect("Tx1 should be Ok, got Err");
+        txpool
+            .insert_inner(tx2.clone(), &db)
+            .await
+            .expect("Tx2 should be Ok, got Err");
+        let submitted_time = txpool.txs()[&tx2.id()].submitted_time();
+
+        // tx2 is put back into the pool after the block
+        std::thread::sleep(std::time::Duration::from_millis(10));
+        db.insert(&UtxoId::new(tx1.id(), 0), &unspent_coin())
+            .unwrap();
+        txpool
+            .block_update_inner(&block_with(vec![tx1.as_ref().clone()]), &db)
+            .await;
+        assert_eq!(txpool.txs()[&tx2.id()].submitted_time(), submitted_time);
+
+        let now = submitted_time + chrono::Duration::milliseconds(1001);
+        let removed: Vec<TxId> = txpool
+            .prune_old_txs_inner(now)
+            .iter()
+            .map(|tx| tx.id())
+            .collect();
+        assert_eq!(removed, vec![tx2.id()], "Tx2 should be evicted");
+        assert!(txpool.txs().is_empty());
+    }
+
+    #[tokio::test]
+    async fn tx1_and_dependent_tx2_squeezed_out_when_block_spends_coin() {
+        let mut txpool = TxPool::new(Default::default());
+        let mut db = MockDb::default();
+        let utxo_id = UtxoId::new(TxId::zeroed(), 0);
+        db.insert(&utxo_id, &unspent_coin()).unwrap();
+
+        let tx1 = Arc::new(
+            TransactionBuilder::script(vec![], vec![])
+                .gas_price(10)
+                .add_input(create_coin_input(TxId::zeroed(), 0))
+                .add_output(create_coin_output())
+                .finalize(),
+        );
+        let tx2 = Arc::new(
+            TransactionBuilder::script(vec![], vec![])
+                .gas_price(9)
+                .add_input(create_coin_input(tx1.id(), 0))
+                .finalize(),
+        );
+        let block_tx = TransactionBuilder::script(vec![], vec![])
+            .gas_price(1)
+            .add_input(create_coin_input(TxId::zeroed(), 0))
+            .finalize();
+        txpool
+            .insert_inner(tx1.clone(), &db)
+            .await
+            .expect("Tx1 should be Ok, got Err");
+        txpool
+            .insert_inner(tx2.clone(), &db)
+            .await
+            .expect("Tx2 should be Ok, got Err");
+
+        let statuses = txpool
+            .block_update_inner(&block_with(vec![block_tx]), &db)
+            .await;
+
+        assert_eq!(
+            statuses,
+            vec![
+                TxStatusBroadcast {
+                    tx: tx1,
+                    status: TxStatus::SqueezedOut {
+                        reason: Error::RemovedCoinSpentByBlock(utxo_id),
+                    },
+                },
+                TxStatusBroadcast {
+                    tx: tx2,
+                    status: TxStatus::SqueezedOut {
+                        reason: Error::Removed,
+                    },
+                },
+            ]
+        );
+        assert!(txpool.txs().is_empty());
+    }
+
+    #[tokio::test]
+    async fn tx_squeezed_out_when_block_creates_same_contract() {
+        let mut txpool = TxPool::new(Default::default());
+        let db = MockDb::default();
+        let contract_id = ContractId::from([1u8; 32]);
+
+        let tx = Arc::new(
+            TransactionBuilder::script(vec![], vec![])
+                .gas_price(10)
+                .add_output(create_contract_output(contract_id))
+                .finalize(),
+        );
+        let block_tx = TransactionBuilder::script(vec![], vec![])
+            .gas_price(1)
+            .add_output(create_contract_output(contract_id))
+            .finalize();
+        txpool
+            .insert_inner(tx.clone(), &db)
+            .await
+            .expect("Tx should be Ok, got Err");
+
+        let statuses = txpool
+            .block_update_inner(&block_with(vec![block_tx]), &db)
+            .await;
+
+        assert_eq!(
+            statuses,
+            vec![TxStatusBroadcast {
+                tx,
+                status: TxStatus::SqueezedOut {
+                    reason: Error::RemovedContractCreatedByBlock(contract_id),
+                },
+            }]
+        );
+    }
+
+    #[tokio::test]
+    async fn tx_squeezed_out_when_block_spends_message() {
+        let message = Message::default();
+        let mut db = MockDb::default();
+        db.insert(&message.id(), &message).unwrap();
+        let mut txpool = TxPool::new(Default::default());
+
+        let tx = Arc::new(
+            TransactionBuilder::script(vec![], vec![])
+                .gas_price(10)
+                .add_input(helpers::create_message_predicate_from_message(&message))
+                .finalize(),
+        );
+        let block_tx = TransactionBuilder::script(vec![], vec![])
+            .gas_price(1)
+            .add_input(helpers::create_message_predicate_from_message(&message))
+            .finalize();
+        txpool
+            .insert_inner(tx.clone(), &db)
+            .await
+            .expect("Tx should be Ok, got Err");
+
+        let statuses = txpool
+            .block_update_inner(&block_with(vec![block_tx]), &db)
+            .await;
+
+        assert_eq!(
+            statuses,
+            vec![TxStatusBroadcast {
+                tx,
+                status: TxStatus::SqueezedOut {
+                    reason: Error::RemovedMessageSpentByBlock(message.id()),
+                },
+            }]
+        );
+    }
 }